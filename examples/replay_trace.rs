@@ -0,0 +1,86 @@
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+//! Replays a trace file (made with `gles30::trace_file::TraceWriter`) into a
+//! window.
+//!
+//! `cargo run --example replay_trace --features struct_loader,trace_file --
+//! frame.gltrace`
+
+use fermium::*;
+use gles30::{trace_file::*, *};
+
+fn main() {
+  let path = std::env::args().nth(1).expect("give a trace file to replay");
+  let file = std::fs::File::open(&path).expect("couldn't open the trace");
+  unsafe {
+    SDL_Init(SDL_INIT_VIDEO);
+    // 0 for success, negative for error
+    assert_eq!(0, SDL_GL_SetAttribute(SDL_GL_CONTEXT_MAJOR_VERSION, 3));
+    assert_eq!(0, SDL_GL_SetAttribute(SDL_GL_CONTEXT_MINOR_VERSION, 0));
+    assert_eq!(
+      0,
+      SDL_GL_SetAttribute(
+        SDL_GL_CONTEXT_PROFILE_MASK,
+        SDL_GL_CONTEXT_PROFILE_ES as _
+      )
+    );
+    // make window
+    let win = SDL_CreateWindow(
+      b"gles30 trace replay\0".as_ptr().cast(),
+      50,
+      50,
+      800,
+      600,
+      (SDL_WINDOW_SHOWN | SDL_WINDOW_OPENGL) as _,
+    );
+    if win.is_null() {
+      let mut p = SDL_GetError();
+      while *p != 0 {
+        print!("{}", *p as u8 as char);
+        p = p.add(1);
+      }
+      println!();
+      panic!();
+    }
+    // make context the window will use
+    let ctx = SDL_GL_CreateContext(win);
+    if ctx.is_null() {
+      let mut p = SDL_GetError();
+      while *p != 0 {
+        print!("{}", *p as u8 as char);
+        p = p.add(1);
+      }
+      println!();
+      panic!();
+    }
+    //
+    let gl = GlFns::load_with(|c_char_ptr| SDL_GL_GetProcAddress(c_char_ptr));
+    let mut replayer =
+      Replayer::new(std::io::BufReader::new(file)).expect("not a trace");
+    let mut count = 0;
+    loop {
+      match replayer.replay_next(&gl) {
+        Ok(Some(_)) => count += 1,
+        Ok(None) => break,
+        // skip over commands that this build can't make
+        Err(TraceError::UnsupportedCommand(name)) => {
+          println!("skipped {}", name)
+        }
+        Err(e) => panic!("{}", e),
+      }
+    }
+    println!("replayed {} calls from {}", count, path);
+    SDL_GL_SwapWindow(win);
+    let mut event: SDL_Event = core::mem::zeroed();
+    loop {
+      if SDL_PollEvent(&mut event) != 0 && event.common.type_ == SDL_QUIT as _ {
+        break;
+      } else {
+        SDL_Delay(10);
+      }
+    }
+    //
+    SDL_DestroyWindow(win);
+    SDL_Quit();
+  }
+}
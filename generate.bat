@@ -1,3 +1,5 @@
-cargo install phosphorus
-phosphorus ../gl.xml gles2 3 0 core GL_KHR_debug >src/lib.rs
+python generator/exts.py ../gl.xml
+python generator/enums_emit.py
+python generator/gen.py
+python generator/tracegen.py
 cargo fmt
@@ -0,0 +1,33 @@
+"""Rewrites the consts in `pub mod enums` from enums.json."""
+import json
+import paths
+
+LIB = paths.LIB
+HEAD = '''  pub use crate::enum_names::{
+    format_bitfield, name_of, name_of_in_group, FormattedBitfield,
+  };
+'''
+
+def cfg_lines(cfg):
+    feats = ', '.join(f'feature = "{f}"' for f in cfg)
+    return [f'  #[cfg(any({feats}))]', f'  #[cfg_attr(docs_rs, doc(cfg(any({feats}))))]']
+
+def emit(ens):
+    out = []
+    for e in sorted(ens, key=lambda e: e['name']):
+        out += ['  ' + d for d in e['docs']]
+        if e['cfg']:
+            out += cfg_lines(e['cfg'])
+        out.append(f"  pub const {e['name']}: {e['ty']} = {e['value']};")
+    return '\n'.join(out) + '\n'
+
+def splice(ens):
+    src = open(LIB).read()
+    a = src.index('pub mod enums {')
+    a = src.index(HEAD, a) + len(HEAD)
+    b = src.index('\n}\n', a) + 1
+    src = src[:a] + emit(ens) + src[b:]
+    open(LIB, 'w').write(src)
+
+if __name__ == '__main__':
+    splice(json.load(open(paths.ENUMS)))
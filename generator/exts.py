@@ -0,0 +1,178 @@
+"""Adds the enums and commands of extra extensions (from gl.xml) into
+enums.json and commands.json, tagged with the extension's cargo feature.
+
+Running this again is harmless: anything already present only gets the
+feature added to its cfg list.
+"""
+import json, re, sys
+import paths
+import xml.etree.ElementTree as ET
+
+EXTS = [
+    'GL_EXT_buffer_storage',
+    'GL_EXT_color_buffer_float',
+    'GL_EXT_color_buffer_half_float',
+    'GL_EXT_disjoint_timer_query',
+    'GL_EXT_multisampled_render_to_texture',
+    'GL_EXT_texture_filter_anisotropic',
+    'GL_OES_EGL_image',
+    'GL_OES_EGL_image_external',
+    'GL_OES_texture_float_linear',
+    'GL_OVR_multiview',
+    'GL_OVR_multiview2',
+]
+# Core API levels past 3.0, by the cargo feature that enables them.
+VERSIONS = {
+    'GL_ES_VERSION_3_1': 'gles31',
+    'GL_ES_VERSION_3_2': 'gles32',
+}
+if len(sys.argv) < 2:
+    sys.exit('usage: exts.py path/to/gl.xml [extension...]')
+if len(sys.argv) > 2:
+    EXTS = sys.argv[2:]
+
+root = ET.parse(sys.argv[1]).getroot()
+
+# enum name -> (value, type, groups)
+xml_enums = {}
+for block in root.findall('enums'):
+    bitmask = block.get('type') == 'bitmask'
+    for e in block.findall('enum'):
+        if e.get('api') not in (None, 'gles2'):
+            continue
+        ty = 'GLbitfield' if bitmask else 'GLenum'
+        if e.get('type') == 'ull':
+            ty = 'u64'
+        groups = [g for g in (e.get('group') or '').split(',') if g]
+        xml_enums.setdefault(e.get('name'), (e.get('value'), ty, groups))
+
+xml_cmds = {}
+for c in root.find('commands').findall('command'):
+    xml_cmds[c.find('proto').find('name').text] = c
+
+RUST_KEYWORDS = {'type': 'type_', 'ref': 'ref_', 'in': 'in_'}
+POINTER_TYPEDEFS = {'GLsync', 'GLeglImageOES', 'GLeglClientBufferEXT'}
+
+def rust_type(elem):
+    """The Rust type of a <proto> or <param> element."""
+    ptype = elem.find('ptype')
+    base = ptype.text if ptype is not None else 'void'
+    text = ''.join(elem.itertext())
+    name = elem.find('name').text
+    decl = text[: text.rindex(name)]
+    stars = decl.count('*')
+    if base == 'void':
+        base = 'c_void'
+    if stars == 0:
+        return base
+    assert stars in (1, 2), text
+    if stars == 2:
+        # The const before each `*` is the constness of what it points at.
+        parts = decl.split('*')
+        inner = ('*const ' if 'const' in parts[0] else '*mut ') + base
+        return ('*const ' if 'const' in parts[1] else '*mut ') + inner
+    return ('*const ' if 'const' in decl else '*mut ') + base
+
+def trace_fmt(ty):
+    if ty.startswith('*') or ty in POINTER_TYPEDEFS:
+        return '{:p}'
+    if ty == 'GLenum':
+        return '{:#X}'
+    return '{:?}'
+
+def trace_arg(pname, ty):
+    if ty in ('GLDEBUGPROC', 'GLDEBUGPROCKHR'):
+        return 'transmute::<_, Option<fn()>>(%s)' % pname
+    return pname
+
+def command_entry(name, feature):
+    c = xml_cmds[name]
+    proto = c.find('proto')
+    ret = rust_type(proto)
+    ret = None if ret == 'c_void' else ret
+    params, groups, lens, lines = [], {}, {}, []
+    for p in c.findall('param'):
+        pname = p.find('name').text
+        pname = RUST_KEYWORDS.get(pname, pname)
+        params.append([pname, rust_type(p)])
+        if p.get('group'):
+            groups[pname] = p.get('group')
+            lines.append(f'/// * `{pname}` group: {p.get("group")}')
+        if p.get('len'):
+            lens[pname] = p.get('len')
+            lines.append(f'/// * `{pname}` len: {p.get("len")}')
+    ret_group = proto.get('group')
+    if ret_group:
+        lines.append(f'/// * return value group: {ret_group}')
+    alias = c.find('alias')
+    if alias is not None:
+        lines.append(f'/// * alias of: [`{alias.get("name")}`]')
+    args = ', '.join(p for p, _ in params)
+    docs = [f'/// [{name}](http://docs.gl/es3/{name})({args})'] + lines
+    return dict(
+        name=name,
+        docs=docs,
+        cfg=[feature],
+        params=params,
+        ret=ret,
+        trace_fmts=[trace_fmt(t) for _, t in params],
+        trace_args=[trace_arg(p, t) for p, t in params],
+        groups=groups,
+        lens=lens,
+        ret_group=ret_group,
+    )
+
+def enum_entry(name, feature):
+    value, ty, groups = xml_enums[name]
+    docs = [f'#[doc = "`{name}: {ty} = {value}`"]']
+    if len(groups) == 1:
+        docs.append(f'#[doc = "* **Group:** {groups[0]}"]')
+    elif groups:
+        docs.append(f'#[doc = "* **Groups:** {", ".join(groups)}"]')
+    return dict(name=name, ty=ty, value=value, docs=docs, cfg=[feature], groups=groups)
+
+def add_feature(entry, feature):
+    # Core items stay core, extension items get one more feature.
+    if entry['cfg'] is not None and feature not in entry['cfg']:
+        entry['cfg'].append(feature)
+
+def main():
+    enums = json.load(open(paths.ENUMS))
+    cmds = json.load(open(paths.COMMANDS))
+    enum_by_name = {e['name']: e for e in enums}
+    cmd_by_name = {c['name']: c for c in cmds}
+    nodes = []
+    for ext in EXTS:
+        if ext in VERSIONS:
+            node = root.find(f"feature[@name='{ext}']")
+            assert node is not None and node.get('api') == 'gles2', ext
+            nodes.append((VERSIONS[ext], node))
+            continue
+        node = root.find(f"extensions/extension[@name='{ext}']")
+        assert node is not None, ext
+        assert 'gles2' in node.get('supported').split('|'), ext
+        nodes.append((ext, node))
+    for ext, node in nodes:
+        for req in node.findall('require'):
+            if req.get('api') not in (None, 'gles2'):
+                continue
+            for e in req.findall('enum'):
+                n = e.get('name')
+                if n in enum_by_name:
+                    add_feature(enum_by_name[n], ext)
+                else:
+                    enum_by_name[n] = enum_entry(n, ext)
+            for c in req.findall('command'):
+                n = c.get('name')
+                if n in cmd_by_name:
+                    add_feature(cmd_by_name[n], ext)
+                else:
+                    cmd_by_name[n] = command_entry(n, ext)
+    enums = sorted(enum_by_name.values(), key=lambda e: e['name'])
+    cmds = sorted(cmd_by_name.values(), key=lambda c: c['name'])
+    json.dump(enums, open(paths.ENUMS, 'w'), indent=1)
+    json.dump(cmds, open(paths.COMMANDS, 'w'), indent=1)
+    print(len(enums), 'enums,', len(cmds), 'commands')
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,763 @@
+import json, re, subprocess, sys
+import paths
+import sections
+import groups
+
+# when the automatic glGetError check is made
+CHECK = 'any(feature = "automatic_glGetError", all(debug_assertions, feature = "debug_automatic_glGetError"))'
+CHECK_FEATURES = 'any(feature = "automatic_glGetError", feature = "debug_automatic_glGetError")'
+CMDS = json.load(open(paths.COMMANDS))
+for _i, _c in enumerate(CMDS): _c['index'] = _i
+LIB = paths.LIB
+
+def wrap_doc(text, indent):
+    """greedy wrap a doc comment paragraph to 80 cols"""
+    words = text.split(' ')
+    out = []; cur = ''
+    prefix = ' '*indent + '/// '
+    for w in words:
+        cand = w if not cur else cur + ' ' + w
+        if len(prefix) + len(cand) > 80 and cur:
+            out.append(prefix + cur); cur = w
+        else:
+            cur = cand
+    if cur: out.append(prefix + cur)
+    return '\n'.join(out)
+
+def cfg_attrs(c, indent):
+    if not c['cfg']: return ''
+    feats = ', '.join('feature = "%s"' % f for f in c['cfg'])
+    sp = ' '*indent
+    return f'{sp}#[cfg(any({feats}))]\n{sp}#[cfg_attr(docs_rs, doc(cfg(any({feats}))))]\n'
+
+def short(c): return c['name'][2:]
+
+def trace_fmt(t):
+    if t in ('GLenum', 'GLbitfield'): return '{:?}'
+    if t.startswith('*') or t == 'GLsync': return '{:p}'
+    return '{:?}'
+
+def trace_arg(c, p, t):
+    if t == 'GLenum':
+        return 'enum_names::EnumName(%s, "%s")' % (p, c['groups'].get(p, ''))
+    if t == 'GLbitfield':
+        return 'enum_names::format_bitfield("%s", %s)' % (c['groups'].get(p, ''), p)
+    if t in ('GLDEBUGPROC', 'GLDEBUGPROCKHR'): return 'transmute::<_, Option<fn()>>(%s)' % p
+    return p
+
+def sig_params(c):
+    return ', '.join(f'{p}: {t}' for p, t in c['params'])
+
+def ret_s(c):
+    return f' -> {c["ret"]}' if c['ret'] else ''
+
+def global_err_check(c):
+    if c['name'] == 'glGetError': return ''
+    return f'''    #[cfg({CHECK})]
+    {{
+      global_automatic_glGetError("{c['name']}");
+    }}'''
+
+def struct_err_check(c):
+    if c['name'] == 'glGetError': return ''
+    return f'''      #[cfg({CHECK})]
+      {{
+        self.automatic_glGetError("{c['name']}");
+      }}'''
+
+DRAWS = {
+    'glDrawArrays': ('mode', 'count', '1'),
+    'glDrawArraysInstanced': ('mode', 'count', 'instancecount'),
+    'glDrawElements': ('mode', 'count', '1'),
+    'glDrawElementsInstanced': ('mode', 'count', 'instancecount'),
+    'glDrawRangeElements': ('mode', 'count', '1'),
+    'glDrawElementsBaseVertex': ('mode', 'count', '1'),
+    'glDrawElementsInstancedBaseVertex': ('mode', 'count', 'instancecount'),
+    'glDrawRangeElementsBaseVertex': ('mode', 'count', '1'),
+}
+
+# The counts of these are in a buffer on the GPU, so only the call is counted.
+INDIRECT_DRAWS = {'glDrawArraysIndirect', 'glDrawElementsIndirect'}
+
+def stats_start(indent):
+    sp = ' '*indent
+    return f'{sp}#[cfg(feature = "call_stats")]\n{sp}let call_start = stats::start();\n'
+
+def stats_record(c, counters, indent):
+    sp = ' '*indent
+    lines = [f'{sp}  {counters}.record({c["index"]}, call_start);']
+    if c['name'] in DRAWS:
+        mode, count, inst = DRAWS[c['name']]
+        lines.append(f'{sp}  {counters}.record_draw({mode}, {count}, {inst});')
+    elif c['name'] in INDIRECT_DRAWS:
+        lines.append(f'{sp}  {counters}.record_indirect_draw();')
+    body = '\n'.join(lines)
+    return f'{sp}#[cfg(feature = "call_stats")]\n{sp}{{\n{body}\n{sp}}}\n'
+
+def hooks_before(c, get, indent):
+    sp = ' '*indent
+    fmt = ', '.join(trace_fmt(t) for _, t in c['params'])
+    targs = ''.join(', ' + trace_arg(c, p, t) for p, t in c['params'])
+    return f'''{sp}#[cfg(feature = "call_hooks")]
+{sp}let call_hooks = {get};
+{sp}#[cfg(feature = "call_hooks")]
+{sp}{{
+{sp}  if let Some(call_hooks) = &call_hooks {{
+{sp}    call_hooks.before("{c['name']}", &format_args!("{fmt}"{targs}));
+{sp}  }}
+{sp}}}
+'''
+
+def hooks_after(c, indent):
+    sp = ' '*indent
+    return f'''{sp}#[cfg(feature = "call_hooks")]
+{sp}{{
+{sp}  if let Some(call_hooks) = &call_hooks {{
+{sp}    call_hooks.after("{c['name']}", &out);
+{sp}  }}
+{sp}}}
+'''
+
+def gl_call_expr(c):
+    fields = [
+        f'{p}: {p}.map_or(core::ptr::null(), |f| f as *const c_void)'
+        if sections.call_field_type(t) != t else p
+        for p, t in c['params']
+    ]
+    if c['ret']: fields.append('ret: out')
+    if not fields: return f'GlCall::{short(c)}'
+    return f'GlCall::{short(c)} {{ {", ".join(fields)} }}'
+
+def global_cmd(c):
+    n = c['name']; s = []
+    s.append('\n'.join('  ' + d for d in c['docs']))
+    s.append('  #[cfg_attr(feature = "inline", inline)]\n  #[cfg_attr(feature = "inline_always", inline(always))]')
+    ca = cfg_attrs(c, 2)
+    body = f'''{ca}  pub unsafe fn {n}({sig_params(c)}){ret_s(c)} {{
+    #[cfg(all(debug_assertions, feature = "debug_trace_calls"))]
+    {{
+      trace!("calling {n}({', '.join(trace_fmt(t) for _, t in c['params'])});"{'' if c['params'] else ','}{''.join(', ' + trace_arg(c, p, t) for p, t in c['params'])});
+    }}
+{hooks_before(c, 'hooks::global_hooks()', 4)}{stats_start(4)}    let out = call_atomic_ptr_{len(c['params'])}arg("{n}", &{n}_p{''.join(', ' + p for p, _ in c['params'])});
+{stats_record(c, 'stats::GLOBAL_STATS', 4)}{hooks_after(c, 4)}    #[cfg(feature = "record_calls")]
+    {{
+      record::record_global_call({gl_call_expr(c)});
+    }}
+{global_err_check(c)}
+    out
+  }}
+{ca}  pub(crate) static {n}_p: APcv = ap_null();
+{wrap_doc(f"Tries to load [`{n}`], returns if a non-null pointer was obtained.", 2)}
+  #[doc(hidden)]
+{ca}  pub unsafe fn {n}_load_with_dyn(get_proc_address: &mut dyn FnMut(*const c_char) -> *mut c_void) -> bool {{
+    load_dyn_name_atomic_ptr(get_proc_address, b"{n}\\0", &{n}_p)
+  }}
+{wrap_doc(f"Checks if the pointer for [`{n}`] is loaded (non-null).", 2)}
+  #[inline]
+  #[doc(hidden)]
+{ca}  pub fn {n}_is_loaded() -> bool {{
+    !{n}_p.load(RELAX).is_null()
+  }}'''
+    s.append(body)
+    return '\n'.join(s) + '\n'
+
+def struct_cmd(c):
+    n = c['name']; m = short(c); s = []
+    s.append('\n'.join('    ' + d for d in c['docs']))
+    s.append('    #[cfg_attr(feature = "inline", inline)]\n    #[cfg_attr(feature = "inline_always", inline(always))]')
+    ca = cfg_attrs(c, 4)
+    sp = sig_params(c)
+    body = f'''{ca}    pub unsafe fn {m}(&self{', ' + sp if sp else ''}){ret_s(c)} {{
+      #[cfg(all(debug_assertions, feature = "debug_trace_calls"))]
+      {{
+        trace!("calling gl.{m}({', '.join(trace_fmt(t) for _, t in c['params'])});"{'' if c['params'] else ','}{''.join(', ' + trace_arg(c, p, t) for p, t in c['params'])});
+      }}
+{hooks_before(c, 'self.hooks()', 6)}{stats_start(6)}      let out = call_atomic_ptr_{len(c['params'])}arg("{n}", &self.{n}_p{''.join(', ' + p for p, _ in c['params'])});
+{stats_record(c, 'self.stats', 6)}{hooks_after(c, 6)}      #[cfg(feature = "record_calls")]
+      {{
+        self.record_call({gl_call_expr(c)});
+      }}
+{struct_err_check(c)}
+      out
+    }}
+{ca}    #[doc(hidden)]
+    pub unsafe fn {m}_load_with_dyn(&self, get_proc_address: &mut dyn FnMut(*const c_char) -> *mut c_void) -> bool {{
+      load_dyn_name_atomic_ptr(get_proc_address, b"{n}\\0", &self.{n}_p)
+    }}
+    #[inline]
+    #[doc(hidden)]
+{ca}    pub fn {m}_is_loaded(&self) -> bool {{
+      !self.{n}_p.load(RELAX).is_null()
+    }}'''
+    s.append(body)
+    return '\n'.join(s) + '\n'
+
+def cfg_block(c, indent, stmt):
+    sp = ' '*indent
+    if not c['cfg']: return f'{sp}{stmt}\n'
+    return cfg_attrs(c, indent) + f'{sp}{{\n{sp}  {stmt}\n{sp}}}\n'
+
+def global_module():
+    loads = ''.join(cfg_block(c, 4, f'report.set({i}, {c["name"]}_load_with_dyn(&mut get_proc_address));') for i, c in enumerate(CMDS))
+    cmds = '\n'.join(global_cmd(c) for c in CMDS)
+    global_gates = sections.extension_gates(CMDS, lambda c: f'{c["name"]}_p', 6)
+    return f'''#[cfg(feature = "global_loader")]
+pub use global_commands::*;
+#[cfg(feature = "global_loader")]
+pub mod global_commands {{
+  //! Contains functions for using the global GL loader.
+  use super::*;
+  #[cfg({CHECK})]
+  #[inline(never)]
+  unsafe fn global_automatic_glGetError(name: &'static str) {{
+    // This skips the `glGetError` wrapper, so that the check itself isn't
+    // traced, recorded, hooked, or counted.
+    let get_error = || call_atomic_ptr_0arg::<GLenum>("glGetError", &glGetError_p);
+    let mut err = get_error();
+    while err != GL_NO_ERROR {{
+      error_check::report_global_error(name, err);
+      err = get_error();
+    }}
+  }}
+
+  /// Loads all global functions using the `get_proc_address` given.
+  ///
+  /// The closure should, when given a null-terminated name of a function,
+  /// return a pointer to that function. If the function isn't available, then
+  /// a null pointer should be returned instead.
+  ///
+  /// This allows you to call [SDL_GL_GetProcAddress](https://wiki.libsdl.org/SDL_GL_GetProcAddress),
+  /// [wglGetProcAddress](https://docs.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-wglgetprocaddress),
+  /// or some similar function, depending on your OS.
+  ///
+  /// The returned [`LoadReport`] lists which commands were obtained and which
+  /// are missing, so that you can log what a driver doesn't support.
+  pub unsafe fn load_global_gl_with<F>(mut get_proc_address: F) -> LoadReport
+  where
+    F: FnMut(*const c_char) -> *mut c_void,
+  {{
+    let mut report = LoadReport::new();
+{loads}    if let Some(extensions) =
+      ExtensionSet::scan(&glGetString_p, &glGetIntegerv_p, &glGetStringi_p)
+    {{
+      extensions.store(&GLOBAL_EXTENSIONS);
+{global_gates}    }}
+    report
+  }}
+
+  static GLOBAL_EXTENSIONS: [AtomicU32; EXTENSION_WORDS] = NO_EXTENSIONS;
+
+  /// The extensions that the context reported when the global functions were
+  /// last loaded.
+  ///
+  /// Only the [`KNOWN_EXTENSIONS`] are recorded. If the list couldn't be read
+  /// while loading (such as when no context was current), this is empty.
+  pub fn global_extensions() -> ExtensionSet {{
+    ExtensionSet::load(&GLOBAL_EXTENSIONS)
+  }}
+
+  /// If the context reported the extension named, such as `"GL_KHR_debug"`,
+  /// when the global functions were last loaded.
+  ///
+  /// This works like [`GlFns::has_extension`].
+  pub fn has_global_extension(name: &str) -> bool {{
+    global_extensions().contains(name)
+  }}
+
+  /// Loads all global functions, failing if any core command is missing.
+  ///
+  /// This works like [`load_global_gl_with`], but if any core command can't
+  /// be loaded you get an error instead of a partially working GL. Missing
+  /// extension commands don't cause an error.
+  ///
+  /// Even when this fails, all of the commands that *could* be loaded are
+  /// still loaded.
+  pub unsafe fn try_load_global_gl_with<F>(get_proc_address: F) -> Result<LoadReport, MissingCommands>
+  where
+    F: FnMut(*const c_char) -> *mut c_void,
+  {{
+    MissingCommands::check(load_global_gl_with(get_proc_address))
+  }}
+
+{cmds}}}
+'''
+
+def struct_module():
+    loads = ''.join(cfg_block(c, 6, f'report.set({i}, self.{short(c)}_load_with_dyn(get_proc_address));') for i, c in enumerate(CMDS))
+    cmds = ''.join(struct_cmd(c) for c in CMDS)
+    struct_gates = sections.extension_gates(CMDS, lambda c: f'self.{c["name"]}_p', 8)
+    fields = ''.join(cfg_attrs(c, 4) + f'    pub(crate) {c["name"]}_p: APcv,\n' for c in CMDS)
+    fields += '    #[cfg(feature = "record_calls")]\n    pub(crate) call_sink: APcv,\n'
+    fields += '    #[cfg(feature = "call_stats")]\n    pub(crate) stats: stats::CallCounters,\n'
+    fields += '    #[cfg(feature = "call_hooks")]\n    pub(crate) hooks: APcv,\n'
+    fields += f'    #[cfg({CHECK_FEATURES})]\n    pub(crate) error_check_policy: APcv,\n'
+    fields += '    pub(crate) extensions: [AtomicU32; EXTENSION_WORDS],\n'
+    return f'''#[cfg(feature = "struct_loader")]
+pub use struct_commands::*;
+#[cfg(feature = "struct_loader")]
+pub mod struct_commands {{
+  //! Contains the [`GlFns`] type for using the struct GL loader.
+  use super::*;
+  impl GlFns {{
+    /// Constructs a new struct with all pointers loaded by the
+    /// `get_proc_address` given.
+    pub unsafe fn load_with<F>(mut get_proc_address: F) -> Self
+    where
+      F: FnMut(*const c_char) -> *mut c_void,
+    {{
+      // Safety: The `GlFns` struct is nothing but atomic fields (pointers,
+      // and counters with `call_stats`), which can be safely constructed
+      // with `zeroed`.
+      let out: Self = core::mem::zeroed();
+      out.load_all_with_dyn(&mut get_proc_address);
+      out
+    }}
+
+    /// Like [`load_with`](GlFns::load_with), but also returns a [`LoadReport`]
+    /// that lists which commands were obtained and which are missing.
+    pub unsafe fn load_with_report<F>(mut get_proc_address: F) -> (Self, LoadReport)
+    where
+      F: FnMut(*const c_char) -> *mut c_void,
+    {{
+      // Safety: The `GlFns` struct is nothing but atomic fields (pointers,
+      // and counters with `call_stats`), which can be safely constructed
+      // with `zeroed`.
+      let out: Self = core::mem::zeroed();
+      let report = out.load_all_with_dyn(&mut get_proc_address);
+      (out, report)
+    }}
+
+    /// Like [`load_with`](GlFns::load_with), but fails if any core command
+    /// can't be loaded.
+    ///
+    /// Missing extension commands don't cause an error.
+    pub unsafe fn try_load_with<F>(get_proc_address: F) -> Result<Self, MissingCommands>
+    where
+      F: FnMut(*const c_char) -> *mut c_void,
+    {{
+      let (out, report) = Self::load_with_report(get_proc_address);
+      MissingCommands::check(report).map(|_| out)
+    }}
+
+    #[cfg({CHECK})]
+    #[inline(never)]
+    unsafe fn automatic_glGetError(&self, name: &'static str) {{
+      let policy = self.error_check_policy();
+      // This skips the `GetError` wrapper, so that the check itself isn't
+      // traced, recorded, hooked, or counted.
+      let get_error = || call_atomic_ptr_0arg::<GLenum>("glGetError", &self.glGetError_p);
+      let mut err = get_error();
+      while err != GL_NO_ERROR {{
+        error_check::report_error(policy, name, err);
+        err = get_error();
+      }}
+    }}
+
+    /// Loads all pointers using the `get_proc_address` given.
+    #[doc(hidden)]
+    #[inline(never)]
+    pub unsafe fn load_all_with_dyn(&self, get_proc_address: &mut dyn FnMut(*const c_char) -> *mut c_void) -> LoadReport {{
+      let mut report = LoadReport::new();
+{loads}      if let Some(extensions) =
+        ExtensionSet::scan(&self.glGetString_p, &self.glGetIntegerv_p, &self.glGetStringi_p)
+      {{
+        extensions.store(&self.extensions);
+{struct_gates}      }}
+      report
+    }}
+
+    /// The extensions that the context reported when this was loaded.
+    ///
+    /// Only the [`KNOWN_EXTENSIONS`] are recorded. If the list couldn't be
+    /// read while loading (such as when no context was current), this is
+    /// empty.
+    pub fn extensions(&self) -> ExtensionSet {{
+      ExtensionSet::load(&self.extensions)
+    }}
+
+    /// If the context reported the extension named, such as
+    /// `"GL_KHR_debug"`, when this was loaded.
+    ///
+    /// The commands of an extension that the context doesn't report are
+    /// treated as not loaded, even if the driver gave a pointer for them,
+    /// because some drivers give non-null stubs for everything. When the list
+    /// couldn't be read at all, the commands are left as they were loaded.
+    pub fn has_extension(&self, name: &str) -> bool {{
+      self.extensions().contains(name)
+    }}
+{cmds}  }}
+  /// This holds the many, many function pointers for GL.
+  ///
+  /// It's typically quite large (hundreds of pointers), depending on what API
+  /// level and extensions you selected during the generation.
+  #[repr(C)]
+  pub struct GlFns {{
+{fields}  }}
+  #[cfg(feature = "bytemuck")]
+  unsafe impl bytemuck::Zeroable for GlFns {{}}
+  impl core::fmt::Debug for GlFns {{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+      write!(f, "GlFns")
+    }}
+  }}
+  impl Drop for GlFns {{
+    /// Frees the hooks and the call sink, if they're set.
+    fn drop(&mut self) {{
+      #[cfg(feature = "call_hooks")]
+      self.clear_hooks();
+      #[cfg(feature = "record_calls")]
+      self.clear_call_sink();
+    }}
+  }}
+}}
+// end of module
+'''
+
+def checked_cmd(c, struct):
+    n = c['name']; m = short(c)
+    ind = '    ' if struct else '  '
+    ret = f'Result<{c["ret"] or "()"}, GlError>'
+    sp = sig_params(c)
+    if struct:
+        head = f'pub unsafe fn {m}(&self{", " + sp if sp else ""}) -> {ret}'
+        who = f'gl.{m}'; ptr = f'&self.0.{n}_p'
+        record = f'self.0.record_call({gl_call_expr(c)});'
+        drain = 'first_error(&self.0.glGetError_p).map(|_| out)'
+        counters = 'self.0.stats'
+        get_hooks = 'self.0.hooks()'
+        loader = ''
+    else:
+        head = f'pub unsafe fn {n}({sp}) -> {ret}'
+        who = n; ptr = f'&global_commands::{n}_p'
+        record = f'record::record_global_call({gl_call_expr(c)});'
+        drain = 'first_error(&global_commands::glGetError_p).map(|_| out)'
+        counters = 'stats::GLOBAL_STATS'
+        get_hooks = 'hooks::global_hooks()'
+        loader = f'{ind}#[cfg(feature = "global_loader")]\n'
+    fmt = ', '.join(trace_fmt(t) for _, t in c['params'])
+    targs = ''.join(', ' + trace_arg(c, p, t) for p, t in c['params'])
+    args = ''.join(', ' + p for p, _ in c['params'])
+    return f'''{ind}{c['docs'][0]}
+{ind}#[cfg_attr(feature = "inline", inline)]
+{ind}#[cfg_attr(feature = "inline_always", inline(always))]
+{cfg_attrs(c, len(ind))}{loader}{ind}{head} {{
+{ind}  #[cfg(all(debug_assertions, feature = "debug_trace_calls"))]
+{ind}  {{
+{ind}    trace!("calling checked {who}({fmt});"{'' if c['params'] else ','}{targs});
+{ind}  }}
+{hooks_before(c, get_hooks, len(ind) + 2)}{stats_start(len(ind) + 2)}{ind}  let out = call_atomic_ptr_{len(c['params'])}arg("{n}", {ptr}{args});
+{stats_record(c, counters, len(ind) + 2)}{hooks_after(c, len(ind) + 2)}{ind}  #[cfg(feature = "record_calls")]
+{ind}  {{
+{ind}    {record}
+{ind}  }}
+{ind}  {drain}
+{ind}}}'''
+
+def checked_section():
+    cmds = [c for c in CMDS if c['name'] != 'glGetError']
+    globals_ = '\n'.join(checked_cmd(c, False) for c in cmds)
+    methods = '\n'.join(checked_cmd(c, True) for c in cmds)
+    return f'''
+#[cfg(all(
+  feature = "checked_commands",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+#[cfg_attr(docs_rs, doc(cfg(feature = "checked_commands")))]
+pub mod checked {{
+  //! The GL commands, returning a [`Result`] with any error from the call.
+  //!
+  //! After making the call, each command here calls `glGetError` until it
+  //! gives `GL_NO_ERROR`. If there was an error then the *first* one is
+  //! returned as a [`GlError`] (later errors are usually fallout from the
+  //! first one), so errors can be passed along with `?`:
+  //!
+  //! ```no_run
+  //! # #[cfg(feature = "struct_loader")]
+  //! # unsafe fn f(gl: &gles30::GlFns, data: &[u8]) -> Result<(), gles30::checked::GlError> {{
+  //! use gles30::*;
+  //! let gl = gl.checked();
+  //! gl.BindBuffer(GL_ARRAY_BUFFER, 1)?;
+  //! let ptr = data.as_ptr() as *const _;
+  //! gl.BufferData(GL_ARRAY_BUFFER, data.len() as _, ptr, GL_STATIC_DRAW)?;
+  //! # Ok(())
+  //! # }}
+  //! ```
+  //!
+  //! * With the global loader, use the functions in this module. They have
+  //!   the same names as the ones in [`global_commands`].
+  //! * With the struct loader, use [`GlFns::checked`] to get a
+  //!   [`CheckedGlFns`].
+  //!
+  //! Call tracing and call recording work the same as with the normal
+  //! commands. The automatic `glGetError` check is *not* made for these
+  //! commands, since any error goes into the return value instead. There's no
+  //! checked version of `glGetError` itself.
+  #![allow(clippy::too_many_arguments)]
+  use super::*;
+
+  /// An error code from `glGetError`.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum GlError {{
+    /// `GL_INVALID_ENUM`: an enum argument was out of range.
+    InvalidEnum,
+    /// `GL_INVALID_VALUE`: a numeric argument was out of range.
+    InvalidValue,
+    /// `GL_INVALID_OPERATION`: the call isn't allowed in the current state.
+    InvalidOperation,
+    /// `GL_INVALID_FRAMEBUFFER_OPERATION`: the framebuffer isn't complete.
+    InvalidFramebufferOperation,
+    /// `GL_OUT_OF_MEMORY`: there's not enough memory left to do the call.
+    OutOfMemory,
+    /// `GL_STACK_OVERFLOW`: a push would overflow a stack (`GL_KHR_debug`).
+    StackOverflow,
+    /// `GL_STACK_UNDERFLOW`: a pop would underflow a stack (`GL_KHR_debug`).
+    StackUnderflow,
+    /// Some other error code, which the GL shouldn't normally give.
+    Other(GLenum),
+  }}
+  impl GlError {{
+    /// Converts a `glGetError` value, giving `None` for `GL_NO_ERROR`.
+    #[inline]
+    pub fn from_code(code: GLenum) -> Option<Self> {{
+      Some(match code {{
+        GL_NO_ERROR => return None,
+        GL_INVALID_ENUM => GlError::InvalidEnum,
+        GL_INVALID_VALUE => GlError::InvalidValue,
+        GL_INVALID_OPERATION => GlError::InvalidOperation,
+        GL_INVALID_FRAMEBUFFER_OPERATION => GlError::InvalidFramebufferOperation,
+        GL_OUT_OF_MEMORY => GlError::OutOfMemory,
+        GL_STACK_OVERFLOW => GlError::StackOverflow,
+        GL_STACK_UNDERFLOW => GlError::StackUnderflow,
+        other => GlError::Other(other),
+      }})
+    }}
+
+    /// The `glGetError` value of this error.
+    #[inline]
+    pub fn code(self) -> GLenum {{
+      match self {{
+        GlError::InvalidEnum => GL_INVALID_ENUM,
+        GlError::InvalidValue => GL_INVALID_VALUE,
+        GlError::InvalidOperation => GL_INVALID_OPERATION,
+        GlError::InvalidFramebufferOperation => GL_INVALID_FRAMEBUFFER_OPERATION,
+        GlError::OutOfMemory => GL_OUT_OF_MEMORY,
+        GlError::StackOverflow => GL_STACK_OVERFLOW,
+        GlError::StackUnderflow => GL_STACK_UNDERFLOW,
+        GlError::Other(code) => code,
+      }}
+    }}
+  }}
+  impl core::fmt::Display for GlError {{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+      match name_of_in_group("ErrorCode", self.code()) {{
+        Some(name) => f.write_str(name),
+        None => write!(f, "Unknown error code {{:#X}}", self.code()),
+      }}
+    }}
+  }}
+  with_std! {{
+    impl std::error::Error for GlError {{}}
+  }}
+  impl From<GlError> for GLenum {{
+    #[inline]
+    fn from(err: GlError) -> Self {{
+      err.code()
+    }}
+  }}
+
+  /// Calls `glGetError` until there's no error, giving the first one.
+  ///
+  /// This uses the loaded pointer directly rather than the `glGetError`
+  /// wrapper, so that the check itself isn't traced, recorded, hooked, or
+  /// counted.
+  #[inline(never)]
+  unsafe fn first_error(get_error_p: &APcv) -> Result<(), GlError> {{
+    let get_error = || call_atomic_ptr_0arg::<GLenum>("glGetError", get_error_p);
+    match GlError::from_code(get_error()) {{
+      None => Ok(()),
+      Some(err) => {{
+        while get_error() != GL_NO_ERROR {{}}
+        Err(err)
+      }}
+    }}
+  }}
+
+{globals_}
+
+  /// A [`GlFns`] with checked methods, from [`GlFns::checked`].
+  #[cfg(feature = "struct_loader")]
+  #[derive(Clone, Copy)]
+  pub struct CheckedGlFns<'a>(pub &'a GlFns);
+  #[cfg(feature = "struct_loader")]
+  impl GlFns {{
+    /// Gets this `GlFns` as a [`CheckedGlFns`], which has the checked
+    /// versions of the commands.
+    #[inline]
+    pub fn checked(&self) -> CheckedGlFns<'_> {{
+      CheckedGlFns(self)
+    }}
+  }}
+  #[cfg(feature = "struct_loader")]
+  impl core::fmt::Debug for CheckedGlFns<'_> {{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+      write!(f, "CheckedGlFns")
+    }}
+  }}
+  #[cfg(feature = "struct_loader")]
+  impl CheckedGlFns<'_> {{
+{methods}
+  }}
+}}
+'''
+
+def origin_expr(c):
+    from es20 import ES20
+    from sections import API_LEVELS
+    if c['cfg'] and c['cfg'][0] in API_LEVELS:
+        major, minor = API_LEVELS[c['cfg'][0]]
+        return f'CommandOrigin::Core {{ major: {major}, minor: {minor} }}'
+    if c['cfg']: return f'CommandOrigin::Extension("{c["cfg"][0]}")'
+    if c['name'] in ES20: return 'CommandOrigin::Core { major: 2, minor: 0 }'
+    return 'CommandOrigin::Core { major: 3, minor: 0 }'
+
+def docs_url(c):
+    return re.search(r'\]\((http[^)]*)\)', c['docs'][0]).group(1)
+
+def commands_section():
+    variants = '\n'.join(f'  /// `{c["name"]}`\n  {short(c)},' for c in CMDS)
+    all_ = '\n'.join(f'    CommandId::{short(c)},' for c in CMDS)
+    arms = '\n'.join(f'      "{c["name"]}" => CommandId::{short(c)},' for c in CMDS)
+    rows = []
+    for c in CMDS:
+        params = ', '.join(
+            f'ParamInfo {{ name: "{p}", ty: "{t}", group: {opt(c["groups"].get(p))} }}'
+            for p, t in c['params'])
+        enabled = 'true' if not c['cfg'] else f'cfg!({" ".join("feature = %s" % json.dumps(f) for f in c["cfg"]) if len(c["cfg"]) == 1 else "any(" + ", ".join("feature = %s" % json.dumps(f) for f in c["cfg"]) + ")"})'
+        rows.append(f'''  CommandInfo {{
+    id: CommandId::{short(c)},
+    name: "{c['name']}",
+    origin: {origin_expr(c)},
+    params: &[{params}],
+    ret: {opt(c['ret'])},
+    ret_group: {opt(c['ret_group'])},
+    docs_url: "{docs_url(c)}",
+    enabled: {enabled},
+  }},''')
+    rows = '\n'.join(rows)
+    return f'''
+/// Names one GL command.
+///
+/// Every command that this crate knows about has a variant, including the
+/// extension commands of extensions that aren't enabled. The variants are
+/// in the same order as the commands in a [`LoadReport`], and
+/// [`info`](CommandId::info) gives the [`CommandInfo`] of the command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u16)]
+pub enum CommandId {{
+{variants}
+}}
+impl CommandId {{
+  /// Every command, in order.
+  pub const ALL: &'static [CommandId] = &[
+{all_}
+  ];
+
+  /// The info of this command.
+  #[inline]
+  pub fn info(self) -> &'static CommandInfo {{
+    &COMMAND_INFO[self as usize]
+  }}
+
+  /// The name of this command, such as `"glActiveTexture"`.
+  #[inline]
+  pub fn name(self) -> &'static str {{
+    self.info().name
+  }}
+
+  /// Finds a command by its name, such as `"glActiveTexture"`.
+  pub fn from_name(name: &str) -> Option<Self> {{
+    Some(match name {{
+{arms}
+      _ => return None,
+    }})
+  }}
+}}
+impl core::fmt::Display for CommandId {{
+  /// Shows the command's name.
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+    f.write_str(self.name())
+  }}
+}}
+
+/// Where a GL command comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommandOrigin {{
+  /// The command is part of this version of core GLES.
+  Core {{
+    /// The major version, such as the 3 in 3.0.
+    major: u8,
+    /// The minor version, such as the 0 in 3.0.
+    minor: u8,
+  }},
+  /// The command is from an extension, such as `"GL_KHR_debug"`.
+  Extension(&'static str),
+}}
+
+/// Info about one parameter of a GL command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParamInfo {{
+  /// The name of the parameter, as it is in this crate.
+  pub name: &'static str,
+  /// The Rust type of the parameter, such as `"GLenum"` or
+  /// `"*const c_void"`.
+  pub ty: &'static str,
+  /// The group of the parameter, for `GLenum` and `GLbitfield` parameters
+  /// that are in one. These are the names of the types in [`groups`].
+  pub group: Option<&'static str>,
+}}
+
+/// Info about one GL command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CommandInfo {{
+  /// The ID of the command.
+  pub id: CommandId,
+  /// The C name of the command, such as `"glActiveTexture"`.
+  pub name: &'static str,
+  /// The core version or extension that the command comes from.
+  pub origin: CommandOrigin,
+  /// The parameters of the command.
+  pub params: &'static [ParamInfo],
+  /// The Rust return type of the command, if it returns a value.
+  pub ret: Option<&'static str>,
+  /// The group of the return value, if it's a `GLenum` in one.
+  pub ret_group: Option<&'static str>,
+  /// A link to the docs of the command.
+  pub docs_url: &'static str,
+  /// If the command can be loaded, which is always true for GLES 3.0
+  /// commands, and depends on the cargo feature of the API level or
+  /// extension otherwise.
+  pub enabled: bool,
+}}
+
+/// The info of every command, in the same order as [`CommandId`].
+pub static COMMAND_INFO: [CommandInfo; {len(CMDS)}] = [
+{rows}
+];
+'''
+
+def opt(v):
+    return f'Some("{v}")' if v else 'None'
+
+def pre_loader_sections():
+    return '\n' + sections.load_report_section(CMDS) + sections.extensions_section(CMDS) + commands_section() + sections.gl_call_section(CMDS) + groups.groups_section() + groups.names_section()
+
+def splice():
+    src = open(LIB).read()
+    start = src.index('unsafe fn call_atomic_ptr_15arg')
+    start = src.index('\n}\n', start) + 3
+    out = src[:start] + pre_loader_sections() + global_module() + '\n' + struct_module() + groups.typed_section(CMDS) + checked_section()
+    open(LIB, 'w').write(out)
+    subprocess.run(['rustfmt', '--edition', '2018', LIB], cwd=paths.ROOT, stderr=subprocess.DEVNULL)
+
+if __name__ == '__main__':
+    splice()
@@ -0,0 +1,425 @@
+# The `groups` module (GLenum newtypes) and the `typed` command layer.
+import json
+import paths
+
+from collections import OrderedDict
+
+ENUMS = json.load(open(paths.ENUMS))
+
+def enum_groups():
+    """group name -> list of enum entries, for the GLenum (non-bitfield) groups"""
+    groups = {}
+    for e in ENUMS:
+        if e['ty'] != 'GLenum':
+            continue
+        for g in e['groups'] or []:
+            groups.setdefault(g, []).append(e)
+    return OrderedDict(sorted(groups.items()))
+
+GROUPS = enum_groups()
+
+def bit_groups():
+    """group name -> list of enum entries, for the GLbitfield groups"""
+    groups = {}
+    for e in ENUMS:
+        if e['ty'] != 'GLbitfield':
+            continue
+        for g in e['groups'] or []:
+            groups.setdefault(g, []).append(e)
+    return OrderedDict(sorted(groups.items()))
+
+BIT_GROUPS = bit_groups()
+
+def cfg_attr(cfg, indent):
+    if not cfg: return ''
+    sp = ' ' * indent
+    feats = ', '.join('feature = "%s"' % f for f in cfg)
+    if len(cfg) > 1: feats = f'any({feats})'
+    return f'{sp}#[cfg({feats})]\n{sp}#[cfg_attr(docs_rs, doc(cfg({feats})))]\n'
+
+def groups_section():
+    out = []
+    for g, members in GROUPS.items():
+        consts = []
+        for e in members:
+            consts.append(f'{cfg_attr(e["cfg"], 4)}    #[doc = "`{e["name"]}`"]\n    pub const {e["name"][3:]}: Self = Self({e["name"]});')
+        consts = '\n'.join(consts)
+        out.append(f'''  /// The `{g}` group.
+  #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+  #[repr(transparent)]
+  pub struct {g}(pub GLenum);
+  impl {g} {{
+{consts}
+  }}
+  group_impls!({g});
+''')
+    for g, members in BIT_GROUPS.items():
+        consts = []
+        for e in members:
+            consts.append(f'{cfg_attr(e["cfg"], 4)}    #[doc = "`{e["name"]}`"]\n    pub const {e["name"][3:]}: Self = Self({e["name"]});')
+        consts = '\n'.join(consts)
+        out.append(f'''  /// The `{g}` group of bit flags.
+  #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+  #[repr(transparent)]
+  pub struct {g}(pub GLbitfield);
+  impl {g} {{
+{consts}
+  }}
+  bitfield_impls!({g});
+''')
+    body = '\n'.join(out)
+    return f'''pub mod groups {{
+  //! Newtypes over `GLenum` for each of the enum groups in the GL registry.
+  //!
+  //! Each group has an associated const for every value in the group, named
+  //! like the value's const in [`enums`] without the `GL_` prefix (eg:
+  //! [`BufferTargetARB::ARRAY_BUFFER`]). With the `typed_commands` feature
+  //! these are used by the [`typed`] commands, so that passing a value from
+  //! the wrong group doesn't compile.
+  //!
+  //! The registry's groups aren't always complete, so the inner value is
+  //! public. If a group is missing a value that you need you can make it
+  //! yourself, eg: `TextureTarget(GL_TEXTURE_EXTERNAL_OES)`.
+  //!
+  //! The groups of `GLbitfield` flags (such as [`ClearBufferMask`]) can be
+  //! combined with `|`, and their `Debug` output lists the flags that are
+  //! set, eg: `COLOR_BUFFER_BIT | DEPTH_BUFFER_BIT`.
+  use super::*;
+
+  macro_rules! group_impls {{
+    ($name:ident) => {{
+      impl core::fmt::Debug for $name {{
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+          match enum_names::name_of_in_group(stringify!($name), self.0) {{
+            Some(name) => {{
+              write!(f, concat!(stringify!($name), "::{{}}"), &name[3..])
+            }}
+            None => write!(f, concat!(stringify!($name), "({{:#X}})"), self.0),
+          }}
+        }}
+      }}
+      impl From<$name> for GLenum {{
+        #[inline]
+        fn from(value: $name) -> Self {{
+          value.0
+        }}
+      }}
+    }};
+  }}
+
+  macro_rules! bitfield_impls {{
+    ($name:ident) => {{
+      impl $name {{
+        /// No flags set.
+        #[inline]
+        pub const fn empty() -> Self {{
+          Self(0)
+        }}
+        /// If all of the flags in `other` are also set in `self`.
+        #[inline]
+        pub const fn contains(self, other: Self) -> bool {{
+          self.0 & other.0 == other.0
+        }}
+      }}
+      impl core::ops::BitOr for $name {{
+        type Output = Self;
+        #[inline]
+        fn bitor(self, rhs: Self) -> Self {{
+          Self(self.0 | rhs.0)
+        }}
+      }}
+      impl core::ops::BitOrAssign for $name {{
+        #[inline]
+        fn bitor_assign(&mut self, rhs: Self) {{
+          self.0 |= rhs.0
+        }}
+      }}
+      impl core::ops::BitAnd for $name {{
+        type Output = Self;
+        #[inline]
+        fn bitand(self, rhs: Self) -> Self {{
+          Self(self.0 & rhs.0)
+        }}
+      }}
+      impl core::fmt::Debug for $name {{
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+          enum_names::write_bitfield(f, stringify!($name), self.0, "GL_".len())
+        }}
+      }}
+      impl From<$name> for GLbitfield {{
+        #[inline]
+        fn from(value: $name) -> Self {{
+          value.0
+        }}
+      }}
+    }};
+  }}
+
+{body}}}
+
+'''
+
+def typed_param(c, p, t):
+    """the typed layer's type for a parameter, or None to keep the GL type"""
+    g = c['groups'].get(p)
+    if t == 'GLbitfield' and g in BIT_GROUPS: return f'groups::{g}'
+    if g not in GROUPS: return None
+    if t == 'GLenum': return f'groups::{g}'
+    if t == '*const GLenum': return f'*const groups::{g}'
+    if t == '*mut GLenum': return f'*mut groups::{g}'
+    return None
+
+def typed_ret(c):
+    g = c['ret_group']
+    if c['ret'] == 'GLenum' and g in GROUPS: return f'groups::{g}'
+    return None
+
+def typed_parts(c):
+    params = []; args = []
+    for p, t in c['params']:
+        tt = typed_param(c, p, t)
+        params.append(f'{p}: {tt or t}')
+        if tt is None: args.append(p)
+        elif t in ('GLenum', 'GLbitfield'): args.append(f'{p}.0')
+        else: args.append(f'{p} as {t}')
+    rt = typed_ret(c)
+    ret = f' -> {rt or c["ret"]}' if c['ret'] else ''
+    return ', '.join(params), ', '.join(args), ret, rt
+
+def typed_section(CMDS):
+    globals_ = []; methods = []
+    for c in CMDS:
+        n = c['name']; m = n[2:]
+        params, args, ret, rt = typed_parts(c)
+        doc = c['docs'][0]
+        g_call = f'global_commands::{n}({args})'
+        s_call = f'self.0.{m}({args})'
+        if rt:
+            g_call = f'{rt}({g_call})'; s_call = f'{rt}({s_call})'
+        inl = '#[cfg_attr(feature = "inline", inline)]\n  #[cfg_attr(feature = "inline_always", inline(always))]'
+        globals_.append(f'''  {doc}
+  {inl}
+{cfg_attr(c['cfg'], 2)}  #[cfg(feature = "global_loader")]
+  pub unsafe fn {n}({params}){ret} {{
+    {g_call}
+  }}''')
+        methods.append(f'''    {doc}
+    {inl.replace(chr(10) + '  ', chr(10) + '    ')}
+{cfg_attr(c['cfg'], 4)}    pub unsafe fn {m}(&self{', ' + params if params else ''}){ret} {{
+      {s_call}
+    }}''')
+    globals_ = '\n'.join(globals_)
+    methods = '\n'.join(methods)
+    return f'''
+#[cfg(all(
+  feature = "typed_commands",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+#[cfg_attr(docs_rs, doc(cfg(feature = "typed_commands")))]
+pub mod typed {{
+  //! The GL commands, taking [`groups`] newtypes instead of plain `GLenum`.
+  //!
+  //! Any `GLenum` argument (or return value) that the GL registry puts in a
+  //! group uses that group's type here, so passing a value from the wrong
+  //! group is a compile error instead of a `GL_INVALID_ENUM` at runtime:
+  //!
+  //! ```compile_fail
+  //! # #[cfg(feature = "struct_loader")]
+  //! # unsafe fn f(gl: &gles30::GlFns) {{
+  //! use gles30::groups::*;
+  //! gl.typed().BindBuffer(TextureTarget::TEXTURE_2D, 0);
+  //! # }}
+  //! # #[cfg(not(feature = "struct_loader"))]
+  //! # compile_error!("needs struct_loader");
+  //! ```
+  //!
+  //! * With the global loader, use the functions in this module. They have
+  //!   the same names as the ones in [`global_commands`].
+  //! * With the struct loader, use [`GlFns::typed`] to get a [`TypedGlFns`].
+  //!
+  //! All of these just call the normal command, so call tracing, error
+  //! checking, and so on all work the same.
+  #![allow(clippy::too_many_arguments)]
+  use super::*;
+
+{globals_}
+
+  /// A [`GlFns`] with typed methods, from [`GlFns::typed`].
+  #[cfg(feature = "struct_loader")]
+  #[derive(Clone, Copy)]
+  pub struct TypedGlFns<'a>(pub &'a GlFns);
+  #[cfg(feature = "struct_loader")]
+  impl GlFns {{
+    /// Gets this `GlFns` as a [`TypedGlFns`], which has the typed versions
+    /// of the commands.
+    #[inline]
+    pub fn typed(&self) -> TypedGlFns<'_> {{
+      TypedGlFns(self)
+    }}
+  }}
+  #[cfg(feature = "struct_loader")]
+  impl core::fmt::Debug for TypedGlFns<'_> {{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+      write!(f, "TypedGlFns")
+    }}
+  }}
+  #[cfg(feature = "struct_loader")]
+  impl TypedGlFns<'_> {{
+{methods}
+  }}
+}}
+'''
+
+def enum_value(e):
+    v = e['value']
+    return int(v, 16) if v.startswith('0x') else int(v)
+
+def canonical(names):
+    """the name to give for a value that several names share"""
+    suffixed = lambda n: n.endswith(('_KHR', '_EXT', '_OES', '_ARB'))
+    return min(names, key=lambda n: (suffixed(n), len(n), n))
+
+def sorted_table(entries):
+    by_value = {}
+    for e in entries:
+        by_value.setdefault(enum_value(e), []).append(e['name'])
+    return [(v, canonical(ns)) for v, ns in sorted(by_value.items())]
+
+def table_rows(rows, indent):
+    sp = ' ' * indent
+    return '\n'.join(f'{sp}(0x{v:04X}, "{n}"),' for v, n in rows)
+
+def names_section():
+    all_rows = sorted_table([e for e in ENUMS if e['ty'] == 'GLenum'])
+    tables = []; arms = []
+    for g, members in GROUPS.items():
+        const = 'GROUP_' + ''.join('_' + ch if ch.isupper() and i and not g[i-1].isupper() else ch for i, ch in enumerate(g)).upper()
+        tables.append(f'const {const}: &[(GLenum, &str)] = &[\n{table_rows(sorted_table(members), 2)}\n];')
+        arms.append(f'    "{g}" => {const},')
+    bit_tables = []; bit_arms = []
+    for g, members in BIT_GROUPS.items():
+        const = 'BITS_' + ''.join('_' + ch if ch.isupper() and i and not g[i-1].isupper() else ch for i, ch in enumerate(g)).upper()
+        rows = [(enum_value(e), e['name']) for e in members]
+        bit_tables.append(f'const {const}: &[(GLbitfield, &str)] = &[\n{table_rows(rows, 2)}\n];')
+        bit_arms.append(f'    "{g}" => {const},')
+    tables = '\n'.join(tables + bit_tables)
+    arms = '\n'.join(arms)
+    bit_arms = '\n'.join(bit_arms)
+    return f'''mod enum_names {{
+  //! Tables for [`name_of`], [`name_of_in_group`], and [`format_bitfield`].
+  //!
+  //! The enum tables are sorted by value so that they can be searched, the
+  //! bitfield tables are in the same order as the consts.
+  use super::*;
+
+  /// Gets the name of a `GLenum` value, eg `name_of(0x8B89)` is
+  /// `Some("GL_ACTIVE_ATTRIBUTES")`.
+  ///
+  /// A few values have more than one name (eg: `0` is `GL_NONE`, `GL_ZERO`,
+  /// `GL_POINTS`, and others). For these you get the shortest name, and
+  /// [`name_of_in_group`] can give the name that fits how the value was used.
+  pub fn name_of(value: GLenum) -> Option<&'static str> {{
+    lookup(ALL, value)
+  }}
+
+  /// Gets the name of a `GLenum` value within one of the GL registry's enum
+  /// groups, eg `name_of_in_group("PrimitiveType", 0)` is
+  /// `Some("GL_POINTS")`.
+  ///
+  /// The group is given by name, the same as the [`groups`] types. If the
+  /// group doesn't exist or the value isn't in the group you get `None`.
+  pub fn name_of_in_group(group: &str, value: GLenum) -> Option<&'static str> {{
+    let table = match group {{
+{arms}
+      _ => return None,
+    }};
+    lookup(table, value)
+  }}
+
+  fn lookup(table: &[(GLenum, &'static str)], value: GLenum) -> Option<&'static str> {{
+    table.binary_search_by_key(&value, |&(v, _)| v).ok().map(|i| table[i].1)
+  }}
+
+  /// Formats a `GLbitfield` as the names of the flags that are set, eg:
+  /// `format_bitfield("ClearBufferMask", 0x4100)` formats as
+  /// `GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT`.
+  ///
+  /// The group is given by name, the same as the [`groups`] types. Any bits
+  /// that don't have a name in the group (including all of them, if the
+  /// group doesn't exist) are shown in hex at the end.
+  pub fn format_bitfield(group: &str, bits: GLbitfield) -> FormattedBitfield<'_> {{
+    FormattedBitfield {{ group, bits }}
+  }}
+
+  /// A `GLbitfield` formatted as flag names, from [`format_bitfield`].
+  #[derive(Clone, Copy)]
+  pub struct FormattedBitfield<'a> {{
+    group: &'a str,
+    bits: GLbitfield,
+  }}
+  impl core::fmt::Display for FormattedBitfield<'_> {{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+      write_bitfield(f, self.group, self.bits, 0)
+    }}
+  }}
+  impl core::fmt::Debug for FormattedBitfield<'_> {{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+      core::fmt::Display::fmt(self, f)
+    }}
+  }}
+
+  /// Writes the names of the set flags, skipping `skip` bytes of each name.
+  pub(crate) fn write_bitfield(
+    f: &mut core::fmt::Formatter, group: &str, bits: GLbitfield, skip: usize,
+  ) -> core::fmt::Result {{
+    let table = match group {{
+{bit_arms}
+      _ => &[],
+    }};
+    let mut rest = bits;
+    let mut sep = "";
+    for &(bit, name) in table.iter() {{
+      if bits & bit == bit {{
+        write!(f, "{{}}{{}}", sep, &name[skip..])?;
+        rest &= !bit;
+        sep = " | ";
+      }}
+    }}
+    if rest != 0 || bits == 0 {{
+      write!(f, "{{}}{{:#X}}", sep, rest)?;
+    }}
+    Ok(())
+  }}
+
+  /// Formats a `GLenum` by name if it has one, or in hex if it doesn't.
+  #[cfg(any(
+    all(debug_assertions, feature = "debug_trace_calls"),
+    all(
+      feature = "call_hooks",
+      any(feature = "global_loader", feature = "struct_loader")
+    )
+  ))]
+  pub(crate) struct EnumName(pub GLenum, pub &'static str);
+  #[cfg(any(
+    all(debug_assertions, feature = "debug_trace_calls"),
+    all(
+      feature = "call_hooks",
+      any(feature = "global_loader", feature = "struct_loader")
+    )
+  ))]
+  impl core::fmt::Debug for EnumName {{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+      match name_of_in_group(self.1, self.0).or_else(|| name_of(self.0)) {{
+        Some(name) => f.write_str(name),
+        None => write!(f, "{{:#X}}", self.0),
+      }}
+    }}
+  }}
+
+  const ALL: &[(GLenum, &str)] = &[
+{table_rows(all_rows, 4)}
+  ];
+{tables}
+}}
+
+'''
@@ -0,0 +1,11 @@
+"""Where the generator reads its data from and writes its output to."""
+import os
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+ROOT = os.path.dirname(HERE)
+
+COMMANDS = os.path.join(HERE, 'commands.json')
+ENUMS = os.path.join(HERE, 'enums.json')
+
+LIB = os.path.join(ROOT, 'src', 'lib.rs')
+CALLS = os.path.join(ROOT, 'src', 'trace_file', 'calls.rs')
@@ -0,0 +1,495 @@
+# Hand-maintained Rust sections emitted by gen.py
+import json
+import paths
+
+def cfg_expr(c):
+    return 'any(%s)' % ', '.join('feature = "%s"' % f for f in c['cfg'])
+
+# Cargo features for the core API levels past GLES 3.0. Their commands count
+# as core commands, not extension commands.
+API_LEVELS = {
+    'gles31': (3, 1),
+    'gles32': (3, 2),
+}
+
+def load_report_section(CMDS):
+    rows = []
+    for c in CMDS:
+        if c['cfg'] and c['cfg'][0] in API_LEVELS:
+            major, minor = API_LEVELS[c['cfg'][0]]
+            rows.append(f'  LoadableCommand::core_if("{c["name"]}", ({major}, {minor}), cfg!({cfg_expr(c)})),')
+        elif c['cfg']:
+            rows.append(f'  LoadableCommand::ext("{c["name"]}", "{c["cfg"][0]}", cfg!({cfg_expr(c)})),')
+        else:
+            rows.append(f'  LoadableCommand::core("{c["name"]}"),')
+    rows = '\n'.join(rows)
+    return f'''/// How many commands the loaders know about, including extension commands
+/// that are disabled by cargo features.
+#[allow(dead_code)]
+const COMMAND_COUNT: usize = {len(CMDS)};
+
+/// Zeroed per-command counters for the global loader's [`stats`], written out
+/// since a repeat expression needs a `Copy` value.
+#[cfg(all(feature = "call_stats", feature = "global_loader"))]
+#[allow(clippy::declare_interior_mutable_const)]
+const ZEROED_COUNTERS: [core::sync::atomic::AtomicU64; COMMAND_COUNT] = [
+{chr(10).join(['  core::sync::atomic::AtomicU64::new(0),'] * len(CMDS))}
+];
+
+/// How many `u32` values a [`LoadReport`] needs for one bit per command.
+const LOAD_REPORT_WORDS: usize = {(len(CMDS) + 31) // 32};
+
+/// The GLES versions that the loaders know about, lowest first.
+const API_VERSIONS: [(u8, u8); 3] = [(3, 0), (3, 1), (3, 2)];
+
+#[allow(dead_code)]
+struct LoadableCommand {{
+  name: &'static str,
+  extension: Option<&'static str>,
+  version: (u8, u8),
+  enabled: bool,
+}}
+impl LoadableCommand {{
+  const fn core(name: &'static str) -> Self {{
+    Self {{ name, extension: None, version: (3, 0), enabled: true }}
+  }}
+  const fn core_if(name: &'static str, version: (u8, u8), enabled: bool) -> Self {{
+    Self {{ name, extension: None, version, enabled }}
+  }}
+  const fn ext(name: &'static str, extension: &'static str, enabled: bool) -> Self {{
+    Self {{ name, extension: Some(extension), version: (3, 0), enabled }}
+  }}
+}}
+
+/// Every command the loaders know about, in the same order that the loaders
+/// try to load them. The index of a command here is its index within a
+/// [`LoadReport`].
+#[allow(dead_code)]
+static LOADABLE_COMMANDS: [LoadableCommand; COMMAND_COUNT] = [
+{rows}
+];
+
+/// The load status of a single command within a [`LoadReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandLoadStatus {{
+  /// The GL name of the command, eg `glGetError`.
+  pub name: &'static str,
+  /// The extension that provides this command, or `None` if the command is
+  /// part of the core API.
+  pub extension: Option<&'static str>,
+  /// The GLES version (major, minor) that the command is loaded for.
+  ///
+  /// For core commands this is the version that added the command to the
+  /// core API: `(3, 0)`, or `(3, 1)` and `(3, 2)` with the `gles31` and
+  /// `gles32` features. Extension commands are always `(3, 0)`.
+  pub version: (u8, u8),
+  /// If a usable function pointer was obtained.
+  pub loaded: bool,
+}}
+
+/// Describes which GL commands a loading pass obtained.
+///
+/// A command is counted as missing if `get_proc_address` returned null, or if
+/// it returned an address that the loader rejected as likely being an error
+/// code (such as `-1`, or some other very small value).
+///
+/// Extension commands are only part of the report when the cargo feature for
+/// their extension is enabled, because otherwise the loader doesn't try to load
+/// them at all. They're also counted as missing when the context doesn't report
+/// their extension, even if a pointer was obtained.
+///
+/// The same goes for the GLES 3.1 and 3.2 commands, which are only part of the
+/// report when the `gles31` or `gles32` feature is enabled. Those count as
+/// core commands, so a fallible load fails if the context doesn't have them.
+/// Each command's [`version`](CommandLoadStatus::version) says which API
+/// level it's from, and [`MissingCommands::versions`] gives the levels that
+/// are missing commands.
+#[derive(Clone, Copy)]
+pub struct LoadReport {{
+  loaded_bits: [u32; LOAD_REPORT_WORDS],
+}}
+impl LoadReport {{
+  #[allow(dead_code)]
+  const fn new() -> Self {{
+    Self {{ loaded_bits: [0; LOAD_REPORT_WORDS] }}
+  }}
+
+  #[allow(dead_code)]
+  fn set(&mut self, index: usize, loaded: bool) {{
+    if loaded {{
+      self.loaded_bits[index / 32] |= 1 << (index % 32);
+    }} else {{
+      self.loaded_bits[index / 32] &= !(1 << (index % 32));
+    }}
+  }}
+
+  fn get(&self, index: usize) -> bool {{
+    (self.loaded_bits[index / 32] & (1 << (index % 32))) != 0
+  }}
+
+  /// Iterates the status of every command that the loader tried to load.
+  pub fn iter(&self) -> impl Iterator<Item = CommandLoadStatus> + '_ {{
+    LOADABLE_COMMANDS.iter().enumerate().filter(|(_, c)| c.enabled).map(
+      move |(i, c)| CommandLoadStatus {{
+        name: c.name,
+        extension: c.extension,
+        version: c.version,
+        loaded: self.get(i),
+      }},
+    )
+  }}
+
+  /// Names of all commands that were loaded.
+  pub fn loaded(&self) -> impl Iterator<Item = &'static str> + '_ {{
+    self.iter().filter(|s| s.loaded).map(|s| s.name)
+  }}
+
+  /// Names of all commands that were *not* loaded.
+  pub fn missing(&self) -> impl Iterator<Item = &'static str> + '_ {{
+    self.iter().filter(|s| !s.loaded).map(|s| s.name)
+  }}
+
+  /// Names of all core API commands that were not loaded.
+  pub fn missing_core(&self) -> impl Iterator<Item = &'static str> + '_ {{
+    self.iter().filter(|s| !s.loaded && s.extension.is_none()).map(|s| s.name)
+  }}
+
+  /// Names of all extension commands that were not loaded.
+  ///
+  /// Use [`iter`](LoadReport::iter) if you need to know which extension each
+  /// command comes from.
+  pub fn missing_extension(&self) -> impl Iterator<Item = &'static str> + '_ {{
+    self.iter().filter(|s| !s.loaded && s.extension.is_some()).map(|s| s.name)
+  }}
+
+  /// If every core API command was loaded.
+  pub fn core_is_complete(&self) -> bool {{
+    self.missing_core().next().is_none()
+  }}
+
+  /// If every command (core and enabled extensions) was loaded.
+  pub fn is_complete(&self) -> bool {{
+    self.missing().next().is_none()
+  }}
+}}
+impl core::fmt::Debug for LoadReport {{
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+    struct List<F>(F);
+    impl<F, I> core::fmt::Debug for List<F>
+    where
+      F: Fn() -> I,
+      I: Iterator<Item = &'static str>,
+    {{
+      fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+        f.debug_list().entries((self.0)()).finish()
+      }}
+    }}
+    f.debug_struct("LoadReport")
+      .field("loaded", &self.loaded().count())
+      .field("missing_core", &List(|| self.missing_core()))
+      .field("missing_extension", &List(|| self.missing_extension()))
+      .finish()
+  }}
+}}
+/// The error from a fallible load when core commands are missing.
+///
+/// This is returned by [`try_load_global_gl_with`] and
+/// [`GlFns::try_load_with`]. Missing *extension* commands never cause this
+/// error, you're expected to check for those yourself before using them.
+#[derive(Clone, Copy)]
+pub struct MissingCommands {{
+  report: LoadReport,
+}}
+impl MissingCommands {{
+  #[allow(dead_code)]
+  fn check(report: LoadReport) -> Result<LoadReport, Self> {{
+    if report.core_is_complete() {{
+      Ok(report)
+    }} else {{
+      Err(Self {{ report }})
+    }}
+  }}
+
+  /// Names of the core commands that couldn't be loaded.
+  pub fn missing(&self) -> impl Iterator<Item = &'static str> + '_ {{
+    self.report.missing_core()
+  }}
+
+  /// Names of the core commands of a GLES version, eg `(3, 1)`, that couldn't
+  /// be loaded.
+  pub fn missing_in(&self, version: (u8, u8)) -> impl Iterator<Item = &'static str> + '_ {{
+    self
+      .report
+      .iter()
+      .filter(move |s| !s.loaded && s.extension.is_none() && s.version == version)
+      .map(|s| s.name)
+  }}
+
+  /// The GLES versions that have core commands which couldn't be loaded,
+  /// lowest first.
+  ///
+  /// A context that only supports GLES 3.0 will be missing every command in
+  /// `(3, 1)` and `(3, 2)`, if the `gles31` and `gles32` features are enabled.
+  pub fn versions(&self) -> impl Iterator<Item = (u8, u8)> + '_ {{
+    API_VERSIONS.iter().cloned().filter(move |&v| self.missing_in(v).next().is_some())
+  }}
+
+  /// The full report from the loading attempt.
+  pub fn report(&self) -> &LoadReport {{
+    &self.report
+  }}
+}}
+impl core::fmt::Debug for MissingCommands {{
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+    struct Version<'a>(&'a MissingCommands, (u8, u8));
+    impl core::fmt::Debug for Version<'_> {{
+      fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+        f.debug_list().entries(self.0.missing_in(self.1)).finish()
+      }}
+    }}
+    f.debug_map().entries(self.versions().map(|v| (v, Version(self, v)))).finish()
+  }}
+}}
+impl core::fmt::Display for MissingCommands {{
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+    write!(f, "failed to load {{}} core GL commands", self.missing().count())?;
+    for (major, minor) in self.versions() {{
+      write!(f, "; GLES {{}}.{{}}:", major, minor)?;
+      for name in self.missing_in((major, minor)) {{
+        write!(f, " {{}}", name)?;
+      }}
+    }}
+    Ok(())
+  }}
+}}
+
+'''
+
+def call_field_type(t):
+    """The type that a `GlCall` field has for a parameter of type `t`."""
+    # Function pointers of the system ABI aren't `Debug` or `PartialEq` on
+    # older compilers, so the callbacks are kept as addresses.
+    if t in ('GLDEBUGPROC', 'GLDEBUGPROCKHR'): return '*const c_void'
+    return t
+
+def gl_call_section(CMDS):
+    variants = []
+    names = []
+    for c in CMDS:
+        n = c['name']; m = n[2:]
+        attrs = ''
+        if c['cfg']:
+            attrs = f'  #[cfg({cfg_expr(c)})]\n  #[cfg_attr(docs_rs, doc(cfg({cfg_expr(c)})))]\n'
+        fields = [f'    {p}: {call_field_type(t)},' for p, t in c['params']]
+        if c['ret']:
+            fields.append(f'    ret: {c["ret"]},')
+        doc = f'  /// A call to [`{n}`](http://docs.gl/es3/{n}).\n'
+        if fields:
+            variants.append(f'{doc}{attrs}  {m} {{\n' + '\n'.join(fields) + '\n  },')
+        else:
+            variants.append(f'{doc}{attrs}  {m},')
+        pat = f'GlCall::{m} {{ .. }}' if fields else f'GlCall::{m}'
+        cfg_arm = f'      #[cfg({cfg_expr(c)})]\n' if c['cfg'] else ''
+        names.append(f'{cfg_arm}      {pat} => "{n}",')
+    variants = '\n'.join(variants)
+    names = '\n'.join(names)
+    return f'''/// A single recorded GL call, with all of its arguments.
+///
+/// There's one variant per GL command, named after the command without its
+/// `gl` prefix. Fields are named after the command's parameters, and
+/// commands that return a value also have a `ret` field with the value that
+/// GL returned.
+///
+/// Pointer arguments are recorded as the pointer value only, the data that
+/// they point to isn't copied. Debug message callbacks are recorded as the
+/// callback's address.
+///
+/// Calls are recorded into a [`CallSink`](record::CallSink), see the
+/// [`record`] module.
+#[cfg(feature = "record_calls")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "record_calls")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub enum GlCall {{
+{variants}
+}}
+#[cfg(feature = "record_calls")]
+impl GlCall {{
+  /// The GL name of the command that was called, eg `glDrawArrays`.
+  pub fn name(&self) -> &'static str {{
+    match self {{
+{names}
+    }}
+  }}
+}}
+// Safety: the pointers in a `GlCall` are only ever used as address values, a
+// `GlCall` never reads or writes through them.
+#[cfg(feature = "record_calls")]
+unsafe impl Send for GlCall {{}}
+#[cfg(feature = "record_calls")]
+unsafe impl Sync for GlCall {{}}
+
+'''
+
+# Extensions that have a cargo feature but no commands or enums of their own
+# (so they aren't found by looking at those).
+EXTRA_EXTENSIONS = [
+    'GL_EXT_color_buffer_float',
+    'GL_OES_texture_float_linear',
+    'GL_OVR_multiview2',
+]
+
+def known_extensions(CMDS):
+    enums = json.load(open(paths.ENUMS))
+    names = {f for c in CMDS if c['cfg'] for f in c['cfg']}
+    names |= {f for e in enums if e['cfg'] for f in e['cfg']}
+    names |= set(EXTRA_EXTENSIONS)
+    return sorted(names - set(API_LEVELS))
+
+def extension_gates(CMDS, ptr, indent):
+    """Statements that null out the commands of each extension that the
+    context doesn't report. `ptr` formats a command into its pointer path."""
+    sp = ' ' * indent
+    out = ''
+    for ext in known_extensions(CMDS):
+        cmds = [(i, c) for i, c in enumerate(CMDS) if c['cfg'] and c['cfg'][0] == ext]
+        if not cmds: continue
+        # A block, since `#[cfg]` on an `if` needs Rust 1.43.
+        out += f'{sp}#[cfg(feature = "{ext}")]\n'
+        out += f'{sp}{{\n'
+        out += f'{sp}  if !extensions.contains("{ext}") {{\n'
+        for i, c in cmds:
+            out += f'{sp}    {ptr(c)}.store(null_mut(), RELAX);\n'
+            out += f'{sp}    report.set({i}, false);\n'
+        out += f'{sp}  }}\n'
+        out += f'{sp}}}\n'
+    return out
+
+def extensions_section(CMDS):
+    exts = known_extensions(CMDS)
+    names = ''.join(f'  "{e}",\n' for e in exts)
+    return f'''/// The extensions that the loaders check for, in the same order as their bits
+/// within an [`ExtensionSet`].
+///
+/// These are the extensions that this crate has a cargo feature for. Other
+/// extensions that a context reports aren't recorded, use
+/// `glGetStringi(GL_EXTENSIONS, i)` to see all of them.
+pub const KNOWN_EXTENSIONS: [&str; {len(exts)}] = [
+{names}];
+
+/// How many `u32` values an [`ExtensionSet`] needs for one bit per extension.
+const EXTENSION_WORDS: usize = {(len(exts) + 31) // 32};
+
+/// The starting value of the global loader's extension bits, written out since
+/// a repeat expression needs a `Copy` value.
+#[cfg(feature = "global_loader")]
+#[allow(clippy::declare_interior_mutable_const)]
+const NO_EXTENSIONS: [AtomicU32; EXTENSION_WORDS] =
+  [{', '.join(['AtomicU32::new(0)'] * ((len(exts) + 31) // 32))}];
+
+/// Which of the [`KNOWN_EXTENSIONS`] a context reports.
+///
+/// The loaders record this when loading, see [`GlFns::extensions`] and
+/// [`global_extensions`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ExtensionSet {{
+  bits: [u32; EXTENSION_WORDS],
+}}
+impl ExtensionSet {{
+  /// A set with no extensions in it.
+  pub const fn new() -> Self {{
+    Self {{ bits: [0; EXTENSION_WORDS] }}
+  }}
+
+  fn index_of(name: &[u8]) -> Option<usize> {{
+    KNOWN_EXTENSIONS.iter().position(|e| e.as_bytes() == name)
+  }}
+
+  /// Adds an extension, if it's one of the [`KNOWN_EXTENSIONS`].
+  fn insert(&mut self, name: &[u8]) {{
+    if let Some(i) = Self::index_of(name) {{
+      self.bits[i / 32] |= 1 << (i % 32);
+    }}
+  }}
+
+  /// If the set has the extension named, such as `"GL_KHR_debug"`.
+  ///
+  /// This is always `false` for extensions that aren't in
+  /// [`KNOWN_EXTENSIONS`].
+  pub fn contains(&self, name: &str) -> bool {{
+    match Self::index_of(name.as_bytes()) {{
+      Some(i) => (self.bits[i / 32] & (1 << (i % 32))) != 0,
+      None => false,
+    }}
+  }}
+
+  /// Iterates the names of the extensions in the set.
+  pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {{
+    KNOWN_EXTENSIONS.iter().cloned().filter(move |e| self.contains(e))
+  }}
+
+  #[allow(dead_code)]
+  fn load(atomics: &[AtomicU32; EXTENSION_WORDS]) -> Self {{
+    let mut out = Self::new();
+    for (bits, a) in out.bits.iter_mut().zip(atomics.iter()) {{
+      *bits = a.load(RELAX);
+    }}
+    out
+  }}
+
+  #[allow(dead_code)]
+  fn store(&self, atomics: &[AtomicU32; EXTENSION_WORDS]) {{
+    for (bits, a) in self.bits.iter().zip(atomics.iter()) {{
+      a.store(*bits, RELAX);
+    }}
+  }}
+
+  /// Reads which extensions the current context reports, using the pointers
+  /// directly so that the reading isn't traced, recorded, or checked.
+  ///
+  /// Gives `None` if the list can't be read at all, which usually means that
+  /// no context is current.
+  #[allow(dead_code)]
+  unsafe fn scan(
+    get_string: &APcv, get_integerv: &APcv, get_stringi: &APcv,
+  ) -> Option<Self> {{
+    let mut out = Self::new();
+    // GLES 2.0 and 3.x both give the whole list as one string.
+    if !get_string.load(RELAX).is_null() {{
+      let list: *const GLubyte =
+        call_atomic_ptr_1arg("glGetString", get_string, GL_EXTENSIONS);
+      if !list.is_null() {{
+        let list = c_str_bytes(list);
+        list.split(|b| *b == b' ').for_each(|name| out.insert(name));
+        return Some(out);
+      }}
+    }}
+    if !get_integerv.load(RELAX).is_null() && !get_stringi.load(RELAX).is_null() {{
+      let mut count: GLint = 0;
+      call_atomic_ptr_2arg::<(), _, _>(
+        "glGetIntegerv",
+        get_integerv,
+        GL_NUM_EXTENSIONS,
+        &mut count,
+      );
+      if count > 0 {{
+        for i in 0..count as GLuint {{
+          let name: *const GLubyte =
+            call_atomic_ptr_2arg("glGetStringi", get_stringi, GL_EXTENSIONS, i);
+          if !name.is_null() {{
+            out.insert(c_str_bytes(name));
+          }}
+        }}
+        return Some(out);
+      }}
+    }}
+    None
+  }}
+}}
+impl core::fmt::Debug for ExtensionSet {{
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+    f.debug_set().entries(self.iter()).finish()
+  }}
+}}
+
+'''
@@ -0,0 +1,282 @@
+# Emits src/trace_file/calls.rs, the per-command encode and replay code.
+import json, subprocess
+import paths
+CMDS = json.load(open(paths.COMMANDS))
+OUT = paths.CALLS
+
+SCALAR = {
+  'GLenum': ('u32', 'u32'), 'GLuint': ('u32', 'u32'), 'GLbitfield': ('u32', 'u32'),
+  'GLint': ('i32', 'i32'), 'GLsizei': ('i32', 'i32'), 'GLfloat': ('f32', 'f32'),
+  'GLboolean': ('u8', 'u8'), 'GLint64': ('i64', 'i64'), 'GLuint64': ('u64', 'u64'),
+}
+
+GEN = {'glGenBuffers': 'Buffer', 'glGenFramebuffers': 'Framebuffer',
+       'glGenProgramPipelines': 'ProgramPipeline',
+       'glGenQueries': 'Query', 'glGenQueriesEXT': 'Query',
+       'glGenRenderbuffers': 'Renderbuffer',
+       'glGenSamplers': 'Sampler', 'glGenTextures': 'Texture',
+       'glGenTransformFeedbacks': 'TransformFeedback',
+       'glGenVertexArrays': 'VertexArray'}
+DELETE = {n.replace('glGen', 'glDelete'): k for n, k in GEN.items()}
+
+def name_kind(cmd, p):
+    base = p.rstrip('s') if p not in ('buffers',) else 'buffer'
+    table = {'buffer': 'Buffer', 'texture': 'Texture', 'framebuffer': 'Framebuffer',
+             'renderbuffer': 'Renderbuffer', 'sampler': 'Sampler', 'array': 'VertexArray',
+             'program': 'Program', 'shader': 'Program'}
+    if p in ('buffer', 'texture', 'framebuffer', 'renderbuffer', 'sampler', 'array', 'program', 'shader'):
+        return table[p]
+    if p == 'pipeline':
+        return 'ProgramPipeline'
+    if p == 'id':
+        if 'Quer' in cmd: return 'Query'
+        if 'TransformFeedback' in cmd: return 'TransformFeedback'
+    return None
+
+def elem(t):
+    return t.split(' ', 1)[1]
+
+def pointer_spec(c, p, t):
+    """How a pointer argument is written. Returns (encode_stmt, replay_kind, replay_extra)"""
+    n = c['name']; lens = c['lens'].get(p)
+    if t.startswith('*mut'):
+        if n in GEN:
+            return ('array', lens)
+        if lens in ('bufSize', 'count', 'maxCount', 'n'):
+            return ('out', f'{as_usize(c, lens)} * size_of::<{elem(t)}>()')
+        return ('out', 'OUT_SCRATCH_BYTES')
+    # const pointers
+    if n.removesuffix('KHR') in ('glObjectPtrLabel', 'glGetObjectPtrLabel') and p == 'ptr':
+        return ('sync',)
+    if n.removesuffix('KHR') == 'glDebugMessageCallback':
+        return ('addr',)
+    if n == 'glShaderSource':
+        return ('strings', 'count', 'length') if p == 'string' else ('dropped',)
+    if t == '*const *const GLchar':
+        return ('strings', 'uniformCount' if n == 'glGetUniformIndices' else 'count', None)
+    if t == '*const GLchar':
+        if n.removesuffix('KHR') in ('glDebugMessageInsert', 'glObjectLabel', 'glObjectPtrLabel', 'glPushDebugGroup'):
+            return ('cstr', 'length')
+        return ('cstr', '-1')
+    if lens is None and n.removesuffix('KHR') == 'glDebugMessageControl':
+        return ('array', 'count')
+    if p == 'indices':
+        return ('indices',)
+    if p == 'pixels':
+        d = 'depth' if '3D' in n else '1'
+        return ('pixels', d)
+    if n.startswith('glCompressedTex'):
+        return ('unpack_bytes', 'imageSize')
+    if p in ('pointer', 'indirect'):
+        return ('addr',)
+    if lens is None:
+        raise Exception((n, p))
+    if lens.startswith('COMPSIZE(buffer'):
+        return ('array', 'clear_buffer_len(buffer)')
+    if lens == 'COMPSIZE(pname)':
+        if 'Parameter' in n:
+            return ('array', 'parameter_len(pname)')
+        return ('array', '1')
+    if t == '*const c_void':
+        return ('bytes', lens)
+    return ('array', lens)
+
+def as_usize(c, a):
+    # unsigned counts can't be negative
+    if dict(c['params']).get(a) == 'GLuint':
+        return f'{a} as usize'
+    return f'{a}.max(0) as usize'
+
+def count_expr(x, c):
+    # lens are written in terms of the argument names
+    if x.isdigit() or x in ('clear_buffer_len(buffer)', 'parameter_len(pname)'): return x
+    if '*' in x:
+        a, b = x.split('*')
+        return as_usize(c, a) if b == '1' else f'{as_usize(c, a)} * {b}'
+    return as_usize(c, x)
+
+def encode_arm(i, c):
+    n = c['name']; m = n[2:]
+    fields = [p for p, _ in c['params']] + (['ret'] if c['ret'] else [])
+    pat = f'GlCall::{m} {{ {", ".join(fields)} }}' if fields else f'GlCall::{m}'
+    s = [f'e.command({i});']
+    for p, t in c['params']:
+        s.append(enc_arg(c, p, t))
+    if n == 'glFlushMappedBufferRange':
+        s.append('e.mapped_range(target, offset, length);')
+    if c['ret']:
+        s.append(enc_scalar('ret', c['ret']))
+    cfg = f'      #[cfg({cfg_expr(c)})]\n' if c['cfg'] else ''
+    body = '\n        '.join(s)
+    return f'{cfg}      {pat} => {{\n        {body}\n      }}'
+
+def cfg_expr(c):
+    if len(c['cfg']) == 1:
+        return 'feature = "%s"' % c['cfg'][0]
+    return 'any(%s)' % ', '.join('feature = "%s"' % f for f in c['cfg'])
+
+def enc_scalar(p, t):
+    if t in SCALAR: return f'e.{SCALAR[t][0]}({p});'
+    if t in ('GLintptr', 'GLsizeiptr'): return f'e.i64({p} as i64);'
+    if t in ('GLsync', 'GLeglImageOES') or t.startswith('*'): return f'e.u64({p} as usize as u64);'
+    # recorded as the callback's address
+    if t in ('GLDEBUGPROC', 'GLDEBUGPROCKHR'): return f'e.u64({p} as usize as u64);'
+    raise Exception(t)
+
+def enc_arg(c, p, t):
+    if not t.startswith('*'):
+        return enc_scalar(p, t)
+    spec = pointer_spec(c, p, t)
+    k = spec[0]
+    if k in ('out', 'addr', 'dropped', 'sync'): return f'e.address({p});'
+    if k == 'array':
+        cnt = spec[1]
+        ptr = f'{p} as *const {elem(t)}' if t.startswith('*mut') else p
+        return f'e.array({ptr}, {count_expr(cnt, c)});'
+    if k == 'bytes': return f'e.bytes({p}, {count_expr(spec[1], c)});'
+    if k == 'cstr': return f'e.c_str({p}, {spec[1]});'
+    if k == 'strings':
+        lengths = spec[2] or 'null()'
+        return f'e.strings({p}, {spec[1]}, {lengths});'
+    if k == 'indices': return f'e.indices({p}, count, type_);'
+    if k == 'pixels': return f'e.pixels({p}, format, type_, width, height, {spec[1]});'
+    if k == 'unpack_bytes': return f'e.unpack_bytes({p}, {spec[1]});'
+    raise Exception(spec)
+
+def dec_scalar(t):
+    if t in SCALAR: return f'd.{SCALAR[t][1]}()?'
+    if t in ('GLintptr', 'GLsizeiptr'): return 'd.i64()? as isize'
+    if t == 'GLsync': return 'd.u64()?'
+    if t in ('GLDEBUGPROC', 'GLDEBUGPROCKHR'): return 'd.u64()?'
+    raise Exception(t)
+
+def replay_arm(i, c):
+    n = c['name']; m = n[2:]
+    s = []
+    post = []
+    args = []
+    for p, t in c['params']:
+        if not t.startswith('*'):
+            if t == 'GLsync':
+                s.append(f'let {p} = st.sync(d.u64()?);')
+            elif t in ('GLDEBUGPROC', 'GLDEBUGPROCKHR'):
+                s.append(f'd.u64()?;')
+                s.append(f'let {p} = None;')
+            elif t == 'GLeglImageOES':
+                # EGL images can't be remapped, so this only works when
+                # replaying in the process that recorded the trace.
+                s.append(f'let {p} = d.u64()? as usize as GLeglImageOES;')
+            else:
+                k = name_kind(n, p) if t == 'GLuint' else None
+                if k:
+                    s.append(f'let {p} = st.names.get(NameKind::{k}, {dec_scalar(t)});')
+                elif n.removesuffix('KHR') in ('glObjectLabel', 'glGetObjectLabel') and p == 'name':
+                    s.append(f'let {p} = st.names.get_for_identifier(identifier, {dec_scalar(t)});')
+                else:
+                    s.append(f'let {p} = {dec_scalar(t)};')
+            args.append(p)
+        else:
+            s.append(f'let mut {p}_arg = d.pointer()?;')
+    if n == 'glFlushMappedBufferRange':
+        s.append('let mapped_data = d.pointer()?;')
+    conv = []
+    for p, t in c['params']:
+        if not t.startswith('*'): continue
+        spec = pointer_spec(c, p, t)
+        k = spec[0]; et = elem(t)
+        if k == 'sync':
+            conv.append(f'let {p} = st.sync({p}_arg.address()) as *const c_void;')
+        elif k == 'dropped':
+            conv.append(f'let _ = {p}_arg;')
+            conv.append(f'let {p} = null();')
+        elif k == 'addr':
+            if n.removesuffix('KHR') == 'glDebugMessageCallback':
+                conv.append(f'let _ = {p}_arg;')
+                conv.append(f'let {p} = null();')
+            else:
+                conv.append(f'let {p} = {p}_arg.as_ptr::<{et}>();')
+        elif k == 'strings':
+            conv.append(f'let {p}_strings = {p}_arg.strings();')
+            conv.append(f'let {p} = {p}_strings.as_ptr();')
+        elif t.startswith('*mut'):
+            if n in GEN:
+                cnt = c['params'][0][0]
+                conv.append(f'let mut {p}_out = Scratch::new(&{p}_arg, {cnt}.max(0) as usize * size_of::<GLuint>());')
+                conv.append(f'let {p} = {p}_out.as_mut_ptr::<GLuint>();')
+                post.append(f'st.names.insert_all(NameKind::{GEN[n]}, &{p}_arg, {p}, {cnt});')
+            else:
+                conv.append(f'let mut {p}_out = Scratch::new(&{p}_arg, {spec[1]});')
+                conv.append(f'let {p} = {p}_out.as_mut_ptr::<{et.replace("mut ", "mut ")}>();')
+        else:
+            if n in DELETE:
+                conv.append(f'st.names.remap_payload(NameKind::{DELETE[n]}, &mut {p}_arg);')
+            if n == 'glShaderBinary' and p == 'shaders':
+                conv.append(f'st.names.remap_payload(NameKind::Program, &mut {p}_arg);')
+            conv.append(f'let {p} = {p}_arg.as_ptr::<{et}>();')
+    s += conv
+    call_args = ', '.join(p for p, _ in c['params'])
+    if n == 'glFlushMappedBufferRange':
+        s.append('st.write_mapped(target, offset, &mapped_data);')
+    if c['ret']:
+        s.append(f'let out = gl.{m}({call_args});')
+        rt = c['ret']
+        if n in ('glCreateShader', 'glCreateProgram', 'glCreateShaderProgramv'):
+            s.append('st.names.insert(NameKind::Program, d.u32()?, out);')
+        elif n == 'glFenceSync':
+            s.append('st.syncs.insert(d.u64()?, out);')
+        elif n == 'glMapBufferRange':
+            s.append('d.u64()?;')
+            s.append('st.mapped.insert(target, out);')
+        else:
+            if rt.startswith('*'): s.append('d.u64()?;')
+            else: s.append(f'{dec_scalar(rt)};')
+            s.append('let _ = out;')
+        if n == 'glUnmapBuffer':
+            s.append('st.mapped.remove(&target);')
+    else:
+        s.append(f'gl.{m}({call_args});')
+    s += post
+    cfg = f'      #[cfg({cfg_expr(c)})]\n' if c['cfg'] else ''
+    body = '\n        '.join(s)
+    return f'{cfg}      {i} => {{\n        {body}\n      }}'
+
+def main():
+    enc = '\n'.join(encode_arm(i, c) for i, c in enumerate(CMDS))
+    rep = '\n'.join(replay_arm(i, c) for i, c in enumerate(CMDS))
+    src = f'''// The per-command parts of the trace format. This is generated along with
+// the commands in `lib.rs`, so that every command is covered.
+
+use super::*;
+
+impl Encoder {{
+  /// Writes a call into the encoder's buffer.
+  ///
+  /// ## Safety
+  /// Any pointers in the call must still be valid to read from.
+  #[allow(unused_variables)]
+  pub(super) unsafe fn encode(&mut self, call: GlCall) {{
+    let e = self;
+    match call {{
+{enc}
+    }}
+  }}
+}}
+
+/// Decodes the call with the given index from `d` and makes it with `gl`.
+#[cfg(feature = "struct_loader")]
+#[allow(unused_mut, unused_variables, clippy::let_unit_value)]
+pub(super) unsafe fn replay_call(
+  st: &mut ReplayState, gl: &GlFns, index: usize, d: &mut Decoder,
+) -> Result<(), TraceError> {{
+  match index {{
+{rep}
+    _ => return Err(TraceError::UnsupportedCommand(LOADABLE_COMMANDS[index].name)),
+  }}
+  Ok(())
+}}
+'''
+    open(OUT, 'w').write(src)
+    subprocess.run(['rustfmt', '--edition', '2018', OUT], stderr=subprocess.DEVNULL)
+
+if __name__ == '__main__':
+    main()
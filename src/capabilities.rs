@@ -0,0 +1,319 @@
+//! A snapshot of what the current context supports.
+//!
+//! [`Capabilities`] reads the version strings, the extension list, and all the
+//! GLES 3.0 implementation limits, all at once:
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f(gl: &gles30::GlFns) {
+//! use gles30::capabilities::Capabilities;
+//! let caps = Capabilities::query(gl);
+//! println!("GLES {}.{}", caps.version.major, caps.version.minor);
+//! println!("max texture size: {}", caps.limits.max_texture_size);
+//! if caps.has_extension("GL_EXT_color_buffer_float") {
+//!   // ...
+//! }
+//! # }
+//! ```
+//!
+//! With the `serde` feature the types here also implement `Serialize` and
+//! `Deserialize`, so a snapshot can be saved along with bug reports.
+
+use super::*;
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+use std::ffi::CStr;
+use std::{string::String, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A version parsed from `GL_VERSION` or `GL_SHADING_LANGUAGE_VERSION`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Version {
+  /// If this is an ES version (the string started with `OpenGL ES`).
+  pub es: bool,
+  /// The major version.
+  pub major: u32,
+  /// The minor version, as written. For GLSL this is two digits, so
+  /// `3.10` has a minor version of 10.
+  pub minor: u32,
+  /// Whatever came after the version number, such as `Mesa 22.3.6`.
+  pub vendor_info: String,
+}
+impl Version {
+  /// Parses a `GL_VERSION` or `GL_SHADING_LANGUAGE_VERSION` string.
+  ///
+  /// ES strings look like `OpenGL ES 3.0 Mesa 22.3.6` and
+  /// `OpenGL ES GLSL ES 3.00`, while desktop GL strings start with the
+  /// version, like `4.6.0 NVIDIA 535.54`.
+  ///
+  /// Gives `None` if there's no `major.minor` number in the place expected.
+  pub fn parse(s: &str) -> Option<Self> {
+    let mut rest = s.trim();
+    let mut es = false;
+    for prefix in
+      &["OpenGL ES GLSL ES ", "OpenGL ES-CM ", "OpenGL ES-CL ", "OpenGL ES "]
+    {
+      if let Some(r) = strip_prefix(rest, prefix) {
+        rest = r;
+        es = true;
+        break;
+      }
+    }
+    let (number, vendor_info) = match rest.find(' ') {
+      Some(i) => (&rest[..i], rest[i..].trim_start()),
+      None => (rest, ""),
+    };
+    let mut parts = number.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some(Self { es, major, minor, vendor_info: String::from(vendor_info) })
+  }
+
+  /// If this version is at least `major.minor`.
+  pub fn at_least(&self, major: u32, minor: u32) -> bool {
+    (self.major, self.minor) >= (major, minor)
+  }
+}
+
+/// Declares the [`Limits`] struct, and how to read each field.
+macro_rules! limits {
+  ($( $(#[$doc:meta])* $field:ident: $ty:ty = $pname:ident, )*) => {
+    /// The implementation limits of GLES 3.0.
+    ///
+    /// Each field is named after its `GLenum`, and is read with the
+    /// `glGet*` command that fits its type.
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct Limits {
+      $( $(#[$doc])* pub $field: $ty, )*
+    }
+    impl Limits {
+      #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+      unsafe fn read(target: &Target<'_>) -> Self {
+        Self { $( $field: ReadLimit::read(target, $pname), )* }
+      }
+    }
+  };
+}
+
+limits! {
+  /// `GL_MAX_3D_TEXTURE_SIZE`
+  max_3d_texture_size: GLint = GL_MAX_3D_TEXTURE_SIZE,
+  /// `GL_MAX_ARRAY_TEXTURE_LAYERS`
+  max_array_texture_layers: GLint = GL_MAX_ARRAY_TEXTURE_LAYERS,
+  /// `GL_MAX_COLOR_ATTACHMENTS`
+  max_color_attachments: GLint = GL_MAX_COLOR_ATTACHMENTS,
+  /// `GL_MAX_COMBINED_FRAGMENT_UNIFORM_COMPONENTS`
+  max_combined_fragment_uniform_components: GLint64 =
+    GL_MAX_COMBINED_FRAGMENT_UNIFORM_COMPONENTS,
+  /// `GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`
+  max_combined_texture_image_units: GLint = GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS,
+  /// `GL_MAX_COMBINED_UNIFORM_BLOCKS`
+  max_combined_uniform_blocks: GLint = GL_MAX_COMBINED_UNIFORM_BLOCKS,
+  /// `GL_MAX_COMBINED_VERTEX_UNIFORM_COMPONENTS`
+  max_combined_vertex_uniform_components: GLint64 =
+    GL_MAX_COMBINED_VERTEX_UNIFORM_COMPONENTS,
+  /// `GL_MAX_CUBE_MAP_TEXTURE_SIZE`
+  max_cube_map_texture_size: GLint = GL_MAX_CUBE_MAP_TEXTURE_SIZE,
+  /// `GL_MAX_DRAW_BUFFERS`
+  max_draw_buffers: GLint = GL_MAX_DRAW_BUFFERS,
+  /// `GL_MAX_ELEMENT_INDEX`
+  max_element_index: GLint64 = GL_MAX_ELEMENT_INDEX,
+  /// `GL_MAX_ELEMENTS_INDICES`
+  max_elements_indices: GLint = GL_MAX_ELEMENTS_INDICES,
+  /// `GL_MAX_ELEMENTS_VERTICES`
+  max_elements_vertices: GLint = GL_MAX_ELEMENTS_VERTICES,
+  /// `GL_MAX_FRAGMENT_INPUT_COMPONENTS`
+  max_fragment_input_components: GLint = GL_MAX_FRAGMENT_INPUT_COMPONENTS,
+  /// `GL_MAX_FRAGMENT_UNIFORM_BLOCKS`
+  max_fragment_uniform_blocks: GLint = GL_MAX_FRAGMENT_UNIFORM_BLOCKS,
+  /// `GL_MAX_FRAGMENT_UNIFORM_COMPONENTS`
+  max_fragment_uniform_components: GLint = GL_MAX_FRAGMENT_UNIFORM_COMPONENTS,
+  /// `GL_MAX_FRAGMENT_UNIFORM_VECTORS`
+  max_fragment_uniform_vectors: GLint = GL_MAX_FRAGMENT_UNIFORM_VECTORS,
+  /// `GL_MAX_PROGRAM_TEXEL_OFFSET`
+  max_program_texel_offset: GLint = GL_MAX_PROGRAM_TEXEL_OFFSET,
+  /// `GL_MIN_PROGRAM_TEXEL_OFFSET`
+  min_program_texel_offset: GLint = GL_MIN_PROGRAM_TEXEL_OFFSET,
+  /// `GL_MAX_RENDERBUFFER_SIZE`
+  max_renderbuffer_size: GLint = GL_MAX_RENDERBUFFER_SIZE,
+  /// `GL_MAX_SAMPLES`
+  max_samples: GLint = GL_MAX_SAMPLES,
+  /// `GL_MAX_SERVER_WAIT_TIMEOUT`, in nanoseconds.
+  max_server_wait_timeout: GLint64 = GL_MAX_SERVER_WAIT_TIMEOUT,
+  /// `GL_MAX_TEXTURE_IMAGE_UNITS`
+  max_texture_image_units: GLint = GL_MAX_TEXTURE_IMAGE_UNITS,
+  /// `GL_MAX_TEXTURE_LOD_BIAS`
+  max_texture_lod_bias: GLfloat = GL_MAX_TEXTURE_LOD_BIAS,
+  /// `GL_MAX_TEXTURE_SIZE`
+  max_texture_size: GLint = GL_MAX_TEXTURE_SIZE,
+  /// `GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS`
+  max_transform_feedback_interleaved_components: GLint =
+    GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS,
+  /// `GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS`
+  max_transform_feedback_separate_attribs: GLint =
+    GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS,
+  /// `GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS`
+  max_transform_feedback_separate_components: GLint =
+    GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS,
+  /// `GL_MAX_UNIFORM_BLOCK_SIZE`, in bytes.
+  max_uniform_block_size: GLint64 = GL_MAX_UNIFORM_BLOCK_SIZE,
+  /// `GL_MAX_UNIFORM_BUFFER_BINDINGS`
+  max_uniform_buffer_bindings: GLint = GL_MAX_UNIFORM_BUFFER_BINDINGS,
+  /// `GL_MAX_VARYING_COMPONENTS`
+  max_varying_components: GLint = GL_MAX_VARYING_COMPONENTS,
+  /// `GL_MAX_VARYING_VECTORS`
+  max_varying_vectors: GLint = GL_MAX_VARYING_VECTORS,
+  /// `GL_MAX_VERTEX_ATTRIBS`
+  max_vertex_attribs: GLint = GL_MAX_VERTEX_ATTRIBS,
+  /// `GL_MAX_VERTEX_OUTPUT_COMPONENTS`
+  max_vertex_output_components: GLint = GL_MAX_VERTEX_OUTPUT_COMPONENTS,
+  /// `GL_MAX_VERTEX_TEXTURE_IMAGE_UNITS`
+  max_vertex_texture_image_units: GLint = GL_MAX_VERTEX_TEXTURE_IMAGE_UNITS,
+  /// `GL_MAX_VERTEX_UNIFORM_BLOCKS`
+  max_vertex_uniform_blocks: GLint = GL_MAX_VERTEX_UNIFORM_BLOCKS,
+  /// `GL_MAX_VERTEX_UNIFORM_COMPONENTS`
+  max_vertex_uniform_components: GLint = GL_MAX_VERTEX_UNIFORM_COMPONENTS,
+  /// `GL_MAX_VERTEX_UNIFORM_VECTORS`
+  max_vertex_uniform_vectors: GLint = GL_MAX_VERTEX_UNIFORM_VECTORS,
+  /// `GL_MAX_VIEWPORT_DIMS`, as width and height.
+  max_viewport_dims: [GLint; 2] = GL_MAX_VIEWPORT_DIMS,
+  /// `GL_ALIASED_LINE_WIDTH_RANGE`, as min and max.
+  aliased_line_width_range: [GLfloat; 2] = GL_ALIASED_LINE_WIDTH_RANGE,
+  /// `GL_ALIASED_POINT_SIZE_RANGE`, as min and max.
+  aliased_point_size_range: [GLfloat; 2] = GL_ALIASED_POINT_SIZE_RANGE,
+  /// `GL_SUBPIXEL_BITS`
+  subpixel_bits: GLint = GL_SUBPIXEL_BITS,
+  /// `GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT`, in bytes.
+  uniform_buffer_offset_alignment: GLint = GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT,
+}
+
+/// Everything that a context reports about itself.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Capabilities {
+  /// `GL_VENDOR`
+  pub vendor: String,
+  /// `GL_RENDERER`
+  pub renderer: String,
+  /// `GL_VERSION`, parsed. If it couldn't be parsed this is all zero, with
+  /// the whole string in `vendor_info`.
+  pub version: Version,
+  /// `GL_SHADING_LANGUAGE_VERSION`, parsed in the same way.
+  pub glsl_version: Version,
+  /// The extensions, from `glGetStringi(GL_EXTENSIONS, i)`.
+  pub extensions: Vec<String>,
+  /// The implementation limits.
+  pub limits: Limits,
+}
+impl Capabilities {
+  /// Reads everything from the context that's current on this thread, through
+  /// the `GlFns` given.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn query(gl: &GlFns) -> Self {
+    Self::read(&Target::Struct(gl))
+  }
+
+  /// Reads everything from the context that's current on this thread, using
+  /// the global loader.
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current
+  ///   on this thread.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn query_global() -> Self {
+    Self::read(&Target::Global(core::marker::PhantomData))
+  }
+
+  #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+  unsafe fn read(target: &Target<'_>) -> Self {
+    let version_string = to_string(target.get_string(GL_VERSION));
+    let glsl_string = to_string(target.get_string(GL_SHADING_LANGUAGE_VERSION));
+    let unparsed = |s: String| Version { vendor_info: s, ..Version::default() };
+    let mut count: GLint = 0;
+    target.get_integerv(GL_NUM_EXTENSIONS, &mut count);
+    let extensions = (0..count.max(0) as GLuint)
+      .map(|i| to_string(target.get_stringi(GL_EXTENSIONS, i)))
+      .collect();
+    Self {
+      vendor: to_string(target.get_string(GL_VENDOR)),
+      renderer: to_string(target.get_string(GL_RENDERER)),
+      version: Version::parse(&version_string)
+        .unwrap_or_else(|| unparsed(version_string)),
+      glsl_version: Version::parse(&glsl_string)
+        .unwrap_or_else(|| unparsed(glsl_string)),
+      extensions,
+      limits: Limits::read(target),
+    }
+  }
+
+  /// If the context reports the extension given, such as
+  /// `"GL_EXT_color_buffer_float"`.
+  pub fn has_extension(&self, name: &str) -> bool {
+    self.extensions.iter().any(|e| e == name)
+  }
+}
+
+/// Copies a string from GL, giving an empty string for null.
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+unsafe fn to_string(p: *const GLubyte) -> String {
+  if p.is_null() {
+    String::new()
+  } else {
+    CStr::from_ptr(p as *const c_char).to_string_lossy().into_owned()
+  }
+}
+
+/// A type that a limit can be read as.
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+trait ReadLimit: Default {
+  unsafe fn read(target: &Target<'_>, pname: GLenum) -> Self;
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl ReadLimit for GLint {
+  unsafe fn read(target: &Target<'_>, pname: GLenum) -> Self {
+    let mut out = Self::default();
+    target.get_integerv(pname, &mut out);
+    out
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl ReadLimit for GLint64 {
+  unsafe fn read(target: &Target<'_>, pname: GLenum) -> Self {
+    let mut out = Self::default();
+    target.get_integer64v(pname, &mut out);
+    out
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl ReadLimit for GLfloat {
+  unsafe fn read(target: &Target<'_>, pname: GLenum) -> Self {
+    let mut out = Self::default();
+    target.get_floatv(pname, &mut out);
+    out
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl ReadLimit for [GLint; 2] {
+  unsafe fn read(target: &Target<'_>, pname: GLenum) -> Self {
+    let mut out = Self::default();
+    target.get_integerv(pname, out.as_mut_ptr());
+    out
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl ReadLimit for [GLfloat; 2] {
+  unsafe fn read(target: &Target<'_>, pname: GLenum) -> Self {
+    let mut out = Self::default();
+    target.get_floatv(pname, out.as_mut_ptr());
+    out
+  }
+}
@@ -0,0 +1,1037 @@
+//! Helpers for the `GL_KHR_debug` extension.
+//!
+//! With the `debug_callback` feature, a Rust closure can be installed as the
+//! debug message callback, and gets each message as a [`DebugMessage`]:
+//! * For the global loader, use [`install_global_debug_callback`].
+//! * For the struct loader, use [`install_debug_callback`].
+//!
+//! The closure stays installed until the returned [`DebugCallback`] is
+//! dropped. With the `log` feature, [`log_debug_message`] is a ready-made
+//! callback that sends each message to `log`.
+//!
+//! ```no_run
+//! # #[cfg(all(feature = "struct_loader", feature = "debug_callback"))]
+//! # unsafe fn f(gl: &gles30::GlFns) {
+//! use gles30::{debug::*, *};
+//! gl.Enable(GL_DEBUG_OUTPUT_KHR);
+//! let _callback = install_debug_callback(gl, |message: DebugMessage<'_>| {
+//!   if message.severity == DebugSeverity::High {
+//!     eprintln!("{}", message);
+//!   }
+//! });
+//! # }
+//! ```
+//!
+//! Messages are only sent while `GL_DEBUG_OUTPUT_KHR` is enabled. That's on
+//! by default in a debug context, and off by default otherwise.
+//!
+//! There are also helpers for the other parts of the extension, which don't
+//! need any extra features:
+//! * [`GlFns::push_debug_group`] (or [`push_global_debug_group`]) pushes a
+//!   debug group, and gives a [`DebugGroup`] that pops the group when dropped.
+//! * [`GlFns::label_object`] and [`GlFns::object_label`] (or their global
+//!   versions) set and get the label of an object, and
+//!   [`GlFns::label_sync`] and [`GlFns::sync_label`] do the same for a
+//!   `GLsync`. Group messages and labels that are too long for the GL give a
+//!   [`TextTooLong`] error instead.
+//! * [`read_debug_message_log`] (or [`read_global_debug_message_log`]) reads
+//!   the messages that pile up in the log when there's no callback.
+//! * [`DebugMessageControl`] turns groups of messages on or off, such as
+//!   silencing the IDs of known noisy driver messages.
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f(
+//! #   gl: &gles30::GlFns, shadow_map: gles30::GLuint,
+//! # ) -> Result<(), gles30::debug::TextTooLong> {
+//! use gles30::debug::*;
+//! gl.label_object(ObjectIdentifier::Texture, shadow_map, "Shadow map")?;
+//! let _group = gl.push_debug_group("Shadow pass")?;
+//! // draw calls here are inside the "Shadow pass" group.
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+#[cfg(all(
+  feature = "debug_callback",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+use std::{
+  boxed::Box,
+  ffi::CStr,
+  string::String,
+  sync::{Mutex, PoisonError},
+};
+
+/// Where a debug message came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugSource {
+  /// `GL_DEBUG_SOURCE_API_KHR`: the GL itself.
+  Api,
+  /// `GL_DEBUG_SOURCE_WINDOW_SYSTEM_KHR`: the window system, such as EGL.
+  WindowSystem,
+  /// `GL_DEBUG_SOURCE_SHADER_COMPILER_KHR`: the shader compiler.
+  ShaderCompiler,
+  /// `GL_DEBUG_SOURCE_THIRD_PARTY_KHR`: a tool or layer outside the app.
+  ThirdParty,
+  /// `GL_DEBUG_SOURCE_APPLICATION_KHR`: the app, using
+  /// `glDebugMessageInsertKHR`.
+  Application,
+  /// `GL_DEBUG_SOURCE_OTHER_KHR`: anything else.
+  Other,
+}
+impl DebugSource {
+  /// Converts a `GLenum` value, if it's a debug source.
+  pub fn from_gl(value: GLenum) -> Option<Self> {
+    Some(match value {
+      GL_DEBUG_SOURCE_API_KHR => DebugSource::Api,
+      GL_DEBUG_SOURCE_WINDOW_SYSTEM_KHR => DebugSource::WindowSystem,
+      GL_DEBUG_SOURCE_SHADER_COMPILER_KHR => DebugSource::ShaderCompiler,
+      GL_DEBUG_SOURCE_THIRD_PARTY_KHR => DebugSource::ThirdParty,
+      GL_DEBUG_SOURCE_APPLICATION_KHR => DebugSource::Application,
+      GL_DEBUG_SOURCE_OTHER_KHR => DebugSource::Other,
+      _ => return None,
+    })
+  }
+
+  /// The `GLenum` value of this source.
+  pub fn to_gl(self) -> GLenum {
+    match self {
+      DebugSource::Api => GL_DEBUG_SOURCE_API_KHR,
+      DebugSource::WindowSystem => GL_DEBUG_SOURCE_WINDOW_SYSTEM_KHR,
+      DebugSource::ShaderCompiler => GL_DEBUG_SOURCE_SHADER_COMPILER_KHR,
+      DebugSource::ThirdParty => GL_DEBUG_SOURCE_THIRD_PARTY_KHR,
+      DebugSource::Application => GL_DEBUG_SOURCE_APPLICATION_KHR,
+      DebugSource::Other => GL_DEBUG_SOURCE_OTHER_KHR,
+    }
+  }
+}
+
+/// What kind of thing a debug message is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugType {
+  /// `GL_DEBUG_TYPE_ERROR_KHR`: an error, usually also given by `glGetError`.
+  Error,
+  /// `GL_DEBUG_TYPE_DEPRECATED_BEHAVIOR_KHR`: use of a deprecated feature.
+  DeprecatedBehavior,
+  /// `GL_DEBUG_TYPE_UNDEFINED_BEHAVIOR_KHR`: something with undefined
+  /// results.
+  UndefinedBehavior,
+  /// `GL_DEBUG_TYPE_PORTABILITY_KHR`: something that won't work everywhere.
+  Portability,
+  /// `GL_DEBUG_TYPE_PERFORMANCE_KHR`: something that's likely to be slow.
+  Performance,
+  /// `GL_DEBUG_TYPE_MARKER_KHR`: a marker in the command stream.
+  Marker,
+  /// `GL_DEBUG_TYPE_PUSH_GROUP_KHR`: a debug group was pushed.
+  PushGroup,
+  /// `GL_DEBUG_TYPE_POP_GROUP_KHR`: a debug group was popped.
+  PopGroup,
+  /// `GL_DEBUG_TYPE_OTHER_KHR`: anything else.
+  Other,
+}
+impl DebugType {
+  /// Converts a `GLenum` value, if it's a debug type.
+  pub fn from_gl(value: GLenum) -> Option<Self> {
+    Some(match value {
+      GL_DEBUG_TYPE_ERROR_KHR => DebugType::Error,
+      GL_DEBUG_TYPE_DEPRECATED_BEHAVIOR_KHR => DebugType::DeprecatedBehavior,
+      GL_DEBUG_TYPE_UNDEFINED_BEHAVIOR_KHR => DebugType::UndefinedBehavior,
+      GL_DEBUG_TYPE_PORTABILITY_KHR => DebugType::Portability,
+      GL_DEBUG_TYPE_PERFORMANCE_KHR => DebugType::Performance,
+      GL_DEBUG_TYPE_MARKER_KHR => DebugType::Marker,
+      GL_DEBUG_TYPE_PUSH_GROUP_KHR => DebugType::PushGroup,
+      GL_DEBUG_TYPE_POP_GROUP_KHR => DebugType::PopGroup,
+      GL_DEBUG_TYPE_OTHER_KHR => DebugType::Other,
+      _ => return None,
+    })
+  }
+
+  /// The `GLenum` value of this type.
+  pub fn to_gl(self) -> GLenum {
+    match self {
+      DebugType::Error => GL_DEBUG_TYPE_ERROR_KHR,
+      DebugType::DeprecatedBehavior => GL_DEBUG_TYPE_DEPRECATED_BEHAVIOR_KHR,
+      DebugType::UndefinedBehavior => GL_DEBUG_TYPE_UNDEFINED_BEHAVIOR_KHR,
+      DebugType::Portability => GL_DEBUG_TYPE_PORTABILITY_KHR,
+      DebugType::Performance => GL_DEBUG_TYPE_PERFORMANCE_KHR,
+      DebugType::Marker => GL_DEBUG_TYPE_MARKER_KHR,
+      DebugType::PushGroup => GL_DEBUG_TYPE_PUSH_GROUP_KHR,
+      DebugType::PopGroup => GL_DEBUG_TYPE_POP_GROUP_KHR,
+      DebugType::Other => GL_DEBUG_TYPE_OTHER_KHR,
+    }
+  }
+}
+
+/// How important a debug message is.
+///
+/// These are ordered, from `Notification` (least) up to `High` (most).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DebugSeverity {
+  /// `GL_DEBUG_SEVERITY_NOTIFICATION_KHR`: just information.
+  Notification,
+  /// `GL_DEBUG_SEVERITY_LOW_KHR`: minor performance or portability issues.
+  Low,
+  /// `GL_DEBUG_SEVERITY_MEDIUM_KHR`: major performance issues, or use of
+  /// deprecated or undefined behavior.
+  Medium,
+  /// `GL_DEBUG_SEVERITY_HIGH_KHR`: errors, or anything that's dangerous.
+  High,
+}
+impl DebugSeverity {
+  /// Converts a `GLenum` value, if it's a debug severity.
+  pub fn from_gl(value: GLenum) -> Option<Self> {
+    Some(match value {
+      GL_DEBUG_SEVERITY_NOTIFICATION_KHR => DebugSeverity::Notification,
+      GL_DEBUG_SEVERITY_LOW_KHR => DebugSeverity::Low,
+      GL_DEBUG_SEVERITY_MEDIUM_KHR => DebugSeverity::Medium,
+      GL_DEBUG_SEVERITY_HIGH_KHR => DebugSeverity::High,
+      _ => return None,
+    })
+  }
+
+  /// The `GLenum` value of this severity.
+  pub fn to_gl(self) -> GLenum {
+    match self {
+      DebugSeverity::Notification => GL_DEBUG_SEVERITY_NOTIFICATION_KHR,
+      DebugSeverity::Low => GL_DEBUG_SEVERITY_LOW_KHR,
+      DebugSeverity::Medium => GL_DEBUG_SEVERITY_MEDIUM_KHR,
+      DebugSeverity::High => GL_DEBUG_SEVERITY_HIGH_KHR,
+    }
+  }
+}
+
+/// One message from the GL's debug output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DebugMessage<'a> {
+  /// Where the message came from.
+  pub source: DebugSource,
+  /// What kind of thing the message is about.
+  pub ty: DebugType,
+  /// The message's ID, which (along with `source` and `ty`) identifies the
+  /// message for the driver.
+  pub id: GLuint,
+  /// How important the message is.
+  pub severity: DebugSeverity,
+  /// The text of the message.
+  pub message: &'a str,
+}
+impl DebugMessage<'_> {
+  /// Makes a message from the raw values the GL gives.
+  ///
+  /// Values outside of what `GL_KHR_debug` allows are read as the "other"
+  /// source and type, and as high severity.
+  pub fn from_gl(
+    source: GLenum, ty: GLenum, id: GLuint, severity: GLenum, message: &str,
+  ) -> DebugMessage<'_> {
+    DebugMessage {
+      source: DebugSource::from_gl(source).unwrap_or(DebugSource::Other),
+      ty: DebugType::from_gl(ty).unwrap_or(DebugType::Other),
+      id,
+      severity: DebugSeverity::from_gl(severity).unwrap_or(DebugSeverity::High),
+      message,
+    }
+  }
+}
+impl core::fmt::Display for DebugMessage<'_> {
+  /// Shows the message as `[Source/Type] id: message`.
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "[{:?}/{:?}] {}: {}", self.source, self.ty, self.id, self.message)
+  }
+}
+
+/// Sends a debug message to `log`, at a level based on the severity.
+///
+/// `High` is `error!`, `Medium` is `warn!`, `Low` is `info!`, and
+/// `Notification` is `debug!`.
+///
+/// This can be given directly to [`install_debug_callback`] or
+/// [`install_global_debug_callback`].
+#[cfg(feature = "log")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "log")))]
+pub fn log_debug_message(message: DebugMessage<'_>) {
+  let level = match message.severity {
+    DebugSeverity::High => log::Level::Error,
+    DebugSeverity::Medium => log::Level::Warn,
+    DebugSeverity::Low => log::Level::Info,
+    DebugSeverity::Notification => log::Level::Debug,
+  };
+  log::log!(level, "{}", message);
+}
+
+#[cfg(all(
+  feature = "debug_callback",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+type BoxedCallback = Box<dyn FnMut(DebugMessage<'_>) + Send>;
+
+/// Keeps a closure installed as the debug message callback.
+///
+/// Dropping this removes the callback (by setting a null callback), and then
+/// frees the closure. The GL context must still be current when that
+/// happens. If the context is already gone, use [`core::mem::forget`] on this
+/// instead, which leaks the closure.
+#[cfg(all(
+  feature = "debug_callback",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+#[cfg_attr(docs_rs, doc(cfg(feature = "debug_callback")))]
+pub struct DebugCallback<'gl> {
+  target: Target<'gl>,
+  closure: *mut Mutex<BoxedCallback>,
+}
+#[cfg(all(
+  feature = "debug_callback",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+impl core::fmt::Debug for DebugCallback<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "DebugCallback")
+  }
+}
+#[cfg(all(
+  feature = "debug_callback",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+impl Drop for DebugCallback<'_> {
+  fn drop(&mut self) {
+    // Safety: the type's docs require the context to still be current, and
+    // once the null callback is set the GL won't use the closure again.
+    unsafe {
+      self.target.debug_message_callback(None, core::ptr::null());
+      drop(Box::from_raw(self.closure));
+    }
+  }
+}
+
+/// Installs a closure as the debug message callback of a [`GlFns`].
+///
+/// The closure gets each message while the returned [`DebugCallback`] is
+/// alive. The GL can call it from another thread (unless
+/// `GL_DEBUG_OUTPUT_SYNCHRONOUS_KHR` is enabled), so it must be `Send`.
+///
+/// The closure must not make any GL calls that make a debug message (such
+/// as a call with an error), or it will deadlock. It also must not panic,
+/// since a panic can't unwind back through the GL.
+///
+/// ## Safety
+/// * The `GlFns` must be loaded for the current context, with
+///   `glDebugMessageCallbackKHR` available.
+/// * The context must still be current when the `DebugCallback` drops.
+#[cfg(all(feature = "debug_callback", feature = "struct_loader"))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(feature = "debug_callback", feature = "struct_loader")))
+)]
+pub unsafe fn install_debug_callback<F>(gl: &GlFns, f: F) -> DebugCallback<'_>
+where
+  F: FnMut(DebugMessage<'_>) + Send + 'static,
+{
+  let closure = box_callback(f);
+  gl.DebugMessageCallbackKHR(Some(trampoline), closure as *const c_void);
+  DebugCallback { target: Target::Struct(gl), closure }
+}
+
+/// Installs a closure as the debug message callback of the global loader.
+///
+/// This works like [`install_debug_callback`], see there for details.
+///
+/// ## Safety
+/// * The global functions must be loaded for the current context, with
+///   `glDebugMessageCallbackKHR` available.
+/// * The context must still be current when the `DebugCallback` drops.
+#[cfg(all(feature = "debug_callback", feature = "global_loader"))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(feature = "debug_callback", feature = "global_loader")))
+)]
+pub unsafe fn install_global_debug_callback<F>(f: F) -> DebugCallback<'static>
+where
+  F: FnMut(DebugMessage<'_>) + Send + 'static,
+{
+  let closure = box_callback(f);
+  global_commands::glDebugMessageCallbackKHR(
+    Some(trampoline),
+    closure as *const c_void,
+  );
+  DebugCallback { target: Target::Global(core::marker::PhantomData), closure }
+}
+
+#[cfg(all(
+  feature = "debug_callback",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+fn box_callback<F>(f: F) -> *mut Mutex<BoxedCallback>
+where
+  F: FnMut(DebugMessage<'_>) + Send + 'static,
+{
+  let boxed: BoxedCallback = Box::new(f);
+  Box::into_raw(Box::new(Mutex::new(boxed)))
+}
+
+#[cfg(all(
+  feature = "debug_callback",
+  any(feature = "global_loader", feature = "struct_loader")
+))]
+extern "system" fn trampoline(
+  source: GLenum, gltype: GLenum, id: GLuint, severity: GLenum,
+  length: GLsizei, message: *const GLchar, user_param: *mut c_void,
+) {
+  if user_param.is_null() || message.is_null() {
+    return;
+  }
+  // Safety: the GL gives a message of `length` bytes, or a null-terminated
+  // message if the length is negative.
+  let bytes = unsafe {
+    if length < 0 {
+      CStr::from_ptr(message).to_bytes()
+    } else {
+      core::slice::from_raw_parts(message as *const u8, length as usize)
+    }
+  };
+  let text = String::from_utf8_lossy(bytes);
+  // Safety: `user_param` is the closure given when installing, which lives
+  // until after the callback is removed.
+  let closure = unsafe { &*(user_param as *const Mutex<BoxedCallback>) };
+  let mut guard = closure.lock().unwrap_or_else(PoisonError::into_inner);
+  let f: &mut BoxedCallback = &mut guard;
+  f(DebugMessage::from_gl(source, gltype, id, severity, &text));
+}
+
+/// The kinds of object that can have a label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectIdentifier {
+  /// `GL_BUFFER_KHR`
+  Buffer,
+  /// `GL_SHADER_KHR`
+  Shader,
+  /// `GL_PROGRAM_KHR`
+  Program,
+  /// `GL_VERTEX_ARRAY_KHR`
+  VertexArray,
+  /// `GL_QUERY_KHR`
+  Query,
+  /// `GL_PROGRAM_PIPELINE_KHR`
+  ProgramPipeline,
+  /// `GL_TRANSFORM_FEEDBACK`
+  TransformFeedback,
+  /// `GL_SAMPLER_KHR`
+  Sampler,
+  /// `GL_TEXTURE`
+  Texture,
+  /// `GL_RENDERBUFFER`
+  Renderbuffer,
+  /// `GL_FRAMEBUFFER`
+  Framebuffer,
+}
+impl ObjectIdentifier {
+  /// The `GLenum` value of this identifier.
+  pub fn to_gl(self) -> GLenum {
+    match self {
+      ObjectIdentifier::Buffer => GL_BUFFER_KHR,
+      ObjectIdentifier::Shader => GL_SHADER_KHR,
+      ObjectIdentifier::Program => GL_PROGRAM_KHR,
+      ObjectIdentifier::VertexArray => GL_VERTEX_ARRAY_KHR,
+      ObjectIdentifier::Query => GL_QUERY_KHR,
+      ObjectIdentifier::ProgramPipeline => GL_PROGRAM_PIPELINE_KHR,
+      ObjectIdentifier::TransformFeedback => GL_TRANSFORM_FEEDBACK,
+      ObjectIdentifier::Sampler => GL_SAMPLER_KHR,
+      ObjectIdentifier::Texture => GL_TEXTURE,
+      ObjectIdentifier::Renderbuffer => GL_RENDERBUFFER,
+      ObjectIdentifier::Framebuffer => GL_FRAMEBUFFER,
+    }
+  }
+}
+
+/// A debug group, which is popped when this is dropped.
+///
+/// Get one from [`GlFns::push_debug_group`] or [`push_global_debug_group`].
+/// The GL context must still be current when this drops.
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+#[must_use = "the debug group is popped as soon as this is dropped"]
+pub struct DebugGroup<'gl> {
+  target: Target<'gl>,
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl core::fmt::Debug for DebugGroup<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "DebugGroup")
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl Drop for DebugGroup<'_> {
+  fn drop(&mut self) {
+    // Safety: the type's docs require the context to still be current.
+    unsafe { self.target.pop_debug_group() }
+  }
+}
+
+/// A debug group message or a label that's too long for the GL.
+///
+/// A group message must be shorter than `GL_MAX_DEBUG_MESSAGE_LENGTH_KHR`,
+/// and a label must be shorter than `GL_MAX_LABEL_LENGTH_KHR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextTooLong {
+  /// The length of the text, in bytes.
+  pub len: usize,
+  /// The GL's limit, which the length must be under.
+  pub max: GLint,
+}
+impl core::fmt::Display for TextTooLong {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(
+      f,
+      "{} bytes of text is too long, the limit is {}",
+      self.len, self.max
+    )
+  }
+}
+with_std! {
+  impl std::error::Error for TextTooLong {}
+}
+
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl Target<'_> {
+  /// Gets the length of `text` for GL, which takes the length instead of
+  /// needing a null terminator, if it's under the `limit` given (such as
+  /// `GL_MAX_LABEL_LENGTH_KHR`).
+  unsafe fn text_len(
+    &self, text: &str, limit: GLenum,
+  ) -> Result<GLsizei, TextTooLong> {
+    let mut max: GLint = 0;
+    self.get_integerv(limit, &mut max);
+    if text.len() < max.max(0) as usize {
+      Ok(text.len() as GLsizei)
+    } else {
+      Err(TextTooLong { len: text.len(), max })
+    }
+  }
+}
+
+#[cfg(feature = "struct_loader")]
+impl GlFns {
+  /// Pushes a debug group with the `message` given, using the
+  /// application source and an ID of 0.
+  ///
+  /// The group is popped when the returned [`DebugGroup`] is dropped. Fails
+  /// if `message` isn't shorter than `GL_MAX_DEBUG_MESSAGE_LENGTH_KHR`.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  /// * The context must still be current when the `DebugGroup` drops.
+  pub unsafe fn push_debug_group(
+    &self, message: &str,
+  ) -> Result<DebugGroup<'_>, TextTooLong> {
+    let target = Target::Struct(self);
+    let length = target.text_len(message, GL_MAX_DEBUG_MESSAGE_LENGTH_KHR)?;
+    self.PushDebugGroupKHR(
+      GL_DEBUG_SOURCE_APPLICATION_KHR,
+      0,
+      length,
+      message.as_ptr() as *const GLchar,
+    );
+    Ok(DebugGroup { target })
+  }
+
+  /// Sets the label of an object.
+  ///
+  /// Fails if `label` isn't shorter than `GL_MAX_LABEL_LENGTH_KHR`.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  /// * `name` must be an object of the `identifier`'s kind in that context.
+  pub unsafe fn label_object(
+    &self, identifier: ObjectIdentifier, name: GLuint, label: &str,
+  ) -> Result<(), TextTooLong> {
+    let length =
+      Target::Struct(self).text_len(label, GL_MAX_LABEL_LENGTH_KHR)?;
+    self.ObjectLabelKHR(
+      identifier.to_gl(),
+      name,
+      length,
+      label.as_ptr() as *const GLchar,
+    );
+    Ok(())
+  }
+
+  /// Sets the label of a sync object.
+  ///
+  /// Fails if `label` isn't shorter than `GL_MAX_LABEL_LENGTH_KHR`.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  /// * `sync` must be a sync object in that context.
+  pub unsafe fn label_sync(
+    &self, sync: GLsync, label: &str,
+  ) -> Result<(), TextTooLong> {
+    let length =
+      Target::Struct(self).text_len(label, GL_MAX_LABEL_LENGTH_KHR)?;
+    self.ObjectPtrLabelKHR(
+      sync as *const c_void,
+      length,
+      label.as_ptr() as *const GLchar,
+    );
+    Ok(())
+  }
+}
+
+with_std! {
+  #[cfg(feature = "struct_loader")]
+  impl GlFns {
+    /// Gets the label of an object, which is empty if it has no label.
+    ///
+    /// This needs `std`, which is there unless you use `chlorine` without any
+    /// feature that requires `std`.
+    ///
+    /// ## Safety
+    /// * The `GlFns` must be loaded for the current context, with the
+    ///   `GL_KHR_debug` functions available.
+    /// * `name` must be an object of the `identifier`'s kind in that context.
+    pub unsafe fn object_label(
+      &self, identifier: ObjectIdentifier, name: GLuint,
+    ) -> std::string::String {
+      let mut max = 0;
+      self.GetIntegerv(GL_MAX_LABEL_LENGTH_KHR, &mut max);
+      read_gl_string(max, |size, length, buf| {
+        self.GetObjectLabelKHR(identifier.to_gl(), name, size, length, buf)
+      })
+    }
+
+    /// Gets the label of a sync object, which is empty if it has no label.
+    ///
+    /// This needs `std`, like [`object_label`](GlFns::object_label).
+    ///
+    /// ## Safety
+    /// * The `GlFns` must be loaded for the current context, with the
+    ///   `GL_KHR_debug` functions available.
+    /// * `sync` must be a sync object in that context.
+    pub unsafe fn sync_label(&self, sync: GLsync) -> std::string::String {
+      let mut max = 0;
+      self.GetIntegerv(GL_MAX_LABEL_LENGTH_KHR, &mut max);
+      read_gl_string(max, |size, length, buf| {
+        self.GetObjectPtrLabelKHR(sync as *const c_void, size, length, buf)
+      })
+    }
+  }
+}
+
+/// Pushes a debug group with the global loader.
+///
+/// This works like [`GlFns::push_debug_group`].
+///
+/// ## Safety
+/// * The global functions must be loaded for the current context, with the
+///   `GL_KHR_debug` functions available.
+/// * The context must still be current when the `DebugGroup` drops.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub unsafe fn push_global_debug_group(
+  message: &str,
+) -> Result<DebugGroup<'static>, TextTooLong> {
+  let target = Target::Global(core::marker::PhantomData);
+  let length = target.text_len(message, GL_MAX_DEBUG_MESSAGE_LENGTH_KHR)?;
+  global_commands::glPushDebugGroupKHR(
+    GL_DEBUG_SOURCE_APPLICATION_KHR,
+    0,
+    length,
+    message.as_ptr() as *const GLchar,
+  );
+  Ok(DebugGroup { target })
+}
+
+/// Sets the label of an object with the global loader.
+///
+/// This works like [`GlFns::label_object`].
+///
+/// ## Safety
+/// * The global functions must be loaded for the current context, with the
+///   `GL_KHR_debug` functions available.
+/// * `name` must be an object of the `identifier`'s kind in that context.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub unsafe fn label_global_object(
+  identifier: ObjectIdentifier, name: GLuint, label: &str,
+) -> Result<(), TextTooLong> {
+  let target = Target::Global(core::marker::PhantomData);
+  let length = target.text_len(label, GL_MAX_LABEL_LENGTH_KHR)?;
+  global_commands::glObjectLabelKHR(
+    identifier.to_gl(),
+    name,
+    length,
+    label.as_ptr() as *const GLchar,
+  );
+  Ok(())
+}
+
+/// Sets the label of a sync object with the global loader.
+///
+/// This works like [`GlFns::label_sync`].
+///
+/// ## Safety
+/// * The global functions must be loaded for the current context, with the
+///   `GL_KHR_debug` functions available.
+/// * `sync` must be a sync object in that context.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub unsafe fn label_global_sync(
+  sync: GLsync, label: &str,
+) -> Result<(), TextTooLong> {
+  let target = Target::Global(core::marker::PhantomData);
+  let length = target.text_len(label, GL_MAX_LABEL_LENGTH_KHR)?;
+  global_commands::glObjectPtrLabelKHR(
+    sync as *const c_void,
+    length,
+    label.as_ptr() as *const GLchar,
+  );
+  Ok(())
+}
+
+with_std! {
+  /// Gets the label of an object with the global loader.
+  ///
+  /// This works like [`GlFns::object_label`].
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  /// * `name` must be an object of the `identifier`'s kind in that context.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn global_object_label(
+    identifier: ObjectIdentifier, name: GLuint,
+  ) -> std::string::String {
+    let mut max = 0;
+    global_commands::glGetIntegerv(GL_MAX_LABEL_LENGTH_KHR, &mut max);
+    read_gl_string(max, |size, length, buf| {
+      global_commands::glGetObjectLabelKHR(
+        identifier.to_gl(),
+        name,
+        size,
+        length,
+        buf,
+      )
+    })
+  }
+
+  /// Gets the label of a sync object with the global loader.
+  ///
+  /// This works like [`GlFns::sync_label`].
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  /// * `sync` must be a sync object in that context.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn global_sync_label(sync: GLsync) -> std::string::String {
+    let mut max = 0;
+    global_commands::glGetIntegerv(GL_MAX_LABEL_LENGTH_KHR, &mut max);
+    read_gl_string(max, |size, length, buf| {
+      global_commands::glGetObjectPtrLabelKHR(
+        sync as *const c_void,
+        size,
+        length,
+        buf,
+      )
+    })
+  }
+}
+
+with_std! {
+  /// A message read from the debug message log, which owns its text.
+  ///
+  /// Get these from [`read_debug_message_log`] or
+  /// [`read_global_debug_message_log`].
+  #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+  pub struct LoggedDebugMessage {
+    /// Where the message came from.
+    pub source: DebugSource,
+    /// What kind of thing the message is about.
+    pub ty: DebugType,
+    /// The message's ID.
+    pub id: GLuint,
+    /// How important the message is.
+    pub severity: DebugSeverity,
+    /// The text of the message.
+    pub message: std::string::String,
+  }
+  impl LoggedDebugMessage {
+    /// Borrows this as a [`DebugMessage`].
+    pub fn as_message(&self) -> DebugMessage<'_> {
+      DebugMessage {
+        source: self.source,
+        ty: self.ty,
+        id: self.id,
+        severity: self.severity,
+        message: &self.message,
+      }
+    }
+  }
+  impl core::fmt::Display for LoggedDebugMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+      core::fmt::Display::fmt(&self.as_message(), f)
+    }
+  }
+
+  /// Reads all messages in the log, with the buffers sized by the GL's limits.
+  #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+  fn read_log(
+    get_integer: impl Fn(GLenum) -> GLint,
+    mut get_log: impl FnMut(
+      GLuint,
+      GLsizei,
+      *mut GLenum,
+      *mut GLenum,
+      *mut GLuint,
+      *mut GLenum,
+      *mut GLsizei,
+      *mut GLchar,
+    ) -> GLuint,
+  ) -> std::vec::Vec<LoggedDebugMessage> {
+    use std::{string::String, vec, vec::Vec};
+    let count = get_integer(GL_MAX_DEBUG_LOGGED_MESSAGES_KHR).max(1) as usize;
+    let max_length =
+      get_integer(GL_MAX_DEBUG_MESSAGE_LENGTH_KHR).max(1) as usize;
+    let mut sources = vec![0; count];
+    let mut types = vec![0; count];
+    let mut ids = vec![0; count];
+    let mut severities = vec![0; count];
+    let mut lengths = vec![0; count];
+    let mut text = vec![0_u8; count * max_length];
+    let mut out = Vec::new();
+    loop {
+      let got = get_log(
+        count as GLuint,
+        text.len() as GLsizei,
+        sources.as_mut_slice().as_mut_ptr(),
+        types.as_mut_slice().as_mut_ptr(),
+        ids.as_mut_slice().as_mut_ptr(),
+        severities.as_mut_slice().as_mut_ptr(),
+        lengths.as_mut_slice().as_mut_ptr(),
+        text.as_mut_slice().as_mut_ptr() as *mut GLchar,
+      ) as usize;
+      if got == 0 {
+        break;
+      }
+      let mut start = 0;
+      for i in 0..got.min(count) {
+        // Each message is null-terminated, and the length counts the null.
+        let end = (start + lengths[i].max(0) as usize).min(text.len());
+        let bytes = &text[start..end];
+        let bytes = match bytes.split_last() {
+          Some((0, rest)) => rest,
+          _ => bytes,
+        };
+        let text = String::from_utf8_lossy(bytes);
+        let message = DebugMessage::from_gl(
+          sources[i],
+          types[i],
+          ids[i],
+          severities[i],
+          &text,
+        );
+        out.push(LoggedDebugMessage {
+          source: message.source,
+          ty: message.ty,
+          id: message.id,
+          severity: message.severity,
+          message: String::from(message.message),
+        });
+        start = end;
+      }
+    }
+    out
+  }
+
+  /// Reads (and removes) all messages in the debug message log of a [`GlFns`].
+  ///
+  /// Messages only go into the log when no debug callback is installed. The
+  /// buffers are sized using `GL_MAX_DEBUG_LOGGED_MESSAGES_KHR` and
+  /// `GL_MAX_DEBUG_MESSAGE_LENGTH_KHR`, and `glGetDebugMessageLogKHR` is called
+  /// until the log is empty.
+  ///
+  /// This needs `std`, like [`GlFns::object_label`].
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn read_debug_message_log(
+    gl: &GlFns,
+  ) -> std::vec::Vec<LoggedDebugMessage> {
+    read_log(
+      |pname| {
+        let mut value = 0;
+        gl.GetIntegerv(pname, &mut value);
+        value
+      },
+      |count, buf_size, sources, types, ids, severities, lengths, text| {
+        gl.GetDebugMessageLogKHR(
+          count, buf_size, sources, types, ids, severities, lengths, text,
+        )
+      },
+    )
+  }
+
+  /// Reads (and removes) all messages in the debug message log, with the
+  /// global loader.
+  ///
+  /// This works like [`read_debug_message_log`].
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn read_global_debug_message_log(
+  ) -> std::vec::Vec<LoggedDebugMessage> {
+    read_log(
+      |pname| {
+        let mut value = 0;
+        global_commands::glGetIntegerv(pname, &mut value);
+        value
+      },
+      |count, buf_size, sources, types, ids, severities, lengths, text| {
+        global_commands::glGetDebugMessageLogKHR(
+          count, buf_size, sources, types, ids, severities, lengths, text,
+        )
+      },
+    )
+  }
+}
+
+/// Picks which debug messages to turn on or off, for
+/// `glDebugMessageControlKHR`.
+///
+/// Each part that isn't set matches any value. Messages with a given ID list
+/// need a specific source and type, and no severity.
+///
+/// ```no_run
+/// # #[cfg(feature = "struct_loader")]
+/// # unsafe fn f(gl: &gles30::GlFns) {
+/// use gles30::debug::*;
+/// // silence a noisy driver message about buffer usage.
+/// DebugMessageControl::new()
+///   .source(DebugSource::Api)
+///   .ty(DebugType::Other)
+///   .ids(&[131185])
+///   .disable(gl);
+/// // and skip all notifications.
+/// DebugMessageControl::new()
+///   .severity(DebugSeverity::Notification)
+///   .disable(gl);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DebugMessageControl<'a> {
+  source: Option<DebugSource>,
+  ty: Option<DebugType>,
+  severity: Option<DebugSeverity>,
+  ids: &'a [GLuint],
+}
+impl<'a> DebugMessageControl<'a> {
+  /// Makes a control that matches all messages.
+  pub fn new() -> Self {
+    Self { source: None, ty: None, severity: None, ids: &[] }
+  }
+
+  /// Only match messages from this source.
+  pub const fn source(self, source: DebugSource) -> Self {
+    Self { source: Some(source), ..self }
+  }
+
+  /// Only match messages of this type.
+  pub const fn ty(self, ty: DebugType) -> Self {
+    Self { ty: Some(ty), ..self }
+  }
+
+  /// Only match messages of this severity.
+  pub const fn severity(self, severity: DebugSeverity) -> Self {
+    Self { severity: Some(severity), ..self }
+  }
+
+  /// Only match messages with these IDs.
+  pub const fn ids(self, ids: &'a [GLuint]) -> Self {
+    Self { ids, ..self }
+  }
+
+  /// The arguments for `glDebugMessageControlKHR`, other than `enabled`.
+  #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+  fn args(&self) -> (GLenum, GLenum, GLenum, GLsizei, *const GLuint) {
+    (
+      self.source.map_or(GL_DONT_CARE, DebugSource::to_gl),
+      self.ty.map_or(GL_DONT_CARE, DebugType::to_gl),
+      self.severity.map_or(GL_DONT_CARE, DebugSeverity::to_gl),
+      self.ids.len() as GLsizei,
+      if self.ids.is_empty() { core::ptr::null() } else { self.ids.as_ptr() },
+    )
+  }
+
+  /// Turns on the matching messages for a [`GlFns`].
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn enable(&self, gl: &GlFns) {
+    self.apply(gl, true)
+  }
+
+  /// Turns off the matching messages for a [`GlFns`].
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn disable(&self, gl: &GlFns) {
+    self.apply(gl, false)
+  }
+
+  #[cfg(feature = "struct_loader")]
+  unsafe fn apply(&self, gl: &GlFns, enabled: bool) {
+    let (source, ty, severity, count, ids) = self.args();
+    gl.DebugMessageControlKHR(source, ty, severity, count, ids, enabled as _)
+  }
+
+  /// Turns on the matching messages, with the global loader.
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn enable_global(&self) {
+    self.apply_global(true)
+  }
+
+  /// Turns off the matching messages, with the global loader.
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the current context, with the
+  ///   `GL_KHR_debug` functions available.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn disable_global(&self) {
+    self.apply_global(false)
+  }
+
+  #[cfg(feature = "global_loader")]
+  unsafe fn apply_global(&self, enabled: bool) {
+    let (source, ty, severity, count, ids) = self.args();
+    global_commands::glDebugMessageControlKHR(
+      source,
+      ty,
+      severity,
+      count,
+      ids,
+      enabled as _,
+    )
+  }
+}
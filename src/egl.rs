@@ -0,0 +1,533 @@
+//! The small part of EGL needed for a headless GLES 3.0 context.
+//!
+//! [`EglFns`] holds the EGL functions, loaded the same way as a [`GlFns`]
+//! (atomic pointers filled in from a `get_proc_address` that you give). The
+//! functions cover making a surfaceless or pbuffer context, which is enough
+//! for running GL without a window, such as on a render farm or in tests
+//! with Mesa's software renderer.
+//!
+//! [`HeadlessContext`] does all the steps for you:
+//!
+//! ```no_run
+//! # #[cfg(all(feature = "native", feature = "struct_loader", unix))]
+//! # unsafe fn f() -> Result<(), Box<dyn std::error::Error>> {
+//! use gles30::{egl::*, native::NativeGl, GlFns};
+//! let native = NativeGl::open()?;
+//! let egl = EglFns::load_with(|name| native.get_proc_address(name));
+//! let context = HeadlessContext::new(&egl)?;
+//! let gl = GlFns::load_with(|name| native.get_proc_address(name));
+//! // ... use GL, rendering into your own framebuffer objects ...
+//! drop(context);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only the functions listed here are loaded; this isn't a full EGL binding.
+
+use super::*;
+
+/// `EGLBoolean`
+pub type EGLBoolean = u32;
+/// `EGLenum`
+pub type EGLenum = u32;
+/// `EGLint`
+pub type EGLint = i32;
+/// `EGLAttrib`
+pub type EGLAttrib = isize;
+/// `EGLDisplay`
+pub type EGLDisplay = *mut c_void;
+/// `EGLConfig`
+pub type EGLConfig = *mut c_void;
+/// `EGLContext`
+pub type EGLContext = *mut c_void;
+/// `EGLSurface`
+pub type EGLSurface = *mut c_void;
+/// `EGLNativeDisplayType`
+pub type EGLNativeDisplayType = *mut c_void;
+
+/// `EGL_FALSE`
+pub const EGL_FALSE: EGLBoolean = 0;
+/// `EGL_TRUE`
+pub const EGL_TRUE: EGLBoolean = 1;
+/// `EGL_DEFAULT_DISPLAY`
+pub const EGL_DEFAULT_DISPLAY: EGLNativeDisplayType = null_mut();
+/// `EGL_NO_DISPLAY`
+pub const EGL_NO_DISPLAY: EGLDisplay = null_mut();
+/// `EGL_NO_CONTEXT`
+pub const EGL_NO_CONTEXT: EGLContext = null_mut();
+/// `EGL_NO_SURFACE`
+pub const EGL_NO_SURFACE: EGLSurface = null_mut();
+/// `EGL_DONT_CARE`
+pub const EGL_DONT_CARE: EGLint = -1;
+
+/// `EGL_SUCCESS`
+pub const EGL_SUCCESS: EGLint = 0x3000;
+/// `EGL_NOT_INITIALIZED`
+pub const EGL_NOT_INITIALIZED: EGLint = 0x3001;
+/// `EGL_BAD_ACCESS`
+pub const EGL_BAD_ACCESS: EGLint = 0x3002;
+/// `EGL_BAD_ALLOC`
+pub const EGL_BAD_ALLOC: EGLint = 0x3003;
+/// `EGL_BAD_ATTRIBUTE`
+pub const EGL_BAD_ATTRIBUTE: EGLint = 0x3004;
+/// `EGL_BAD_CONFIG`
+pub const EGL_BAD_CONFIG: EGLint = 0x3005;
+/// `EGL_BAD_CONTEXT`
+pub const EGL_BAD_CONTEXT: EGLint = 0x3006;
+/// `EGL_BAD_CURRENT_SURFACE`
+pub const EGL_BAD_CURRENT_SURFACE: EGLint = 0x3007;
+/// `EGL_BAD_DISPLAY`
+pub const EGL_BAD_DISPLAY: EGLint = 0x3008;
+/// `EGL_BAD_MATCH`
+pub const EGL_BAD_MATCH: EGLint = 0x3009;
+/// `EGL_BAD_NATIVE_PIXMAP`
+pub const EGL_BAD_NATIVE_PIXMAP: EGLint = 0x300A;
+/// `EGL_BAD_NATIVE_WINDOW`
+pub const EGL_BAD_NATIVE_WINDOW: EGLint = 0x300B;
+/// `EGL_BAD_PARAMETER`
+pub const EGL_BAD_PARAMETER: EGLint = 0x300C;
+/// `EGL_BAD_SURFACE`
+pub const EGL_BAD_SURFACE: EGLint = 0x300D;
+/// `EGL_CONTEXT_LOST`
+pub const EGL_CONTEXT_LOST: EGLint = 0x300E;
+
+/// `EGL_ALPHA_SIZE`
+pub const EGL_ALPHA_SIZE: EGLint = 0x3021;
+/// `EGL_BLUE_SIZE`
+pub const EGL_BLUE_SIZE: EGLint = 0x3022;
+/// `EGL_GREEN_SIZE`
+pub const EGL_GREEN_SIZE: EGLint = 0x3023;
+/// `EGL_RED_SIZE`
+pub const EGL_RED_SIZE: EGLint = 0x3024;
+/// `EGL_DEPTH_SIZE`
+pub const EGL_DEPTH_SIZE: EGLint = 0x3025;
+/// `EGL_STENCIL_SIZE`
+pub const EGL_STENCIL_SIZE: EGLint = 0x3026;
+/// `EGL_SURFACE_TYPE`
+pub const EGL_SURFACE_TYPE: EGLint = 0x3033;
+/// `EGL_NONE`
+pub const EGL_NONE: EGLint = 0x3038;
+/// `EGL_RENDERABLE_TYPE`
+pub const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+/// `EGL_VENDOR`
+pub const EGL_VENDOR: EGLint = 0x3053;
+/// `EGL_VERSION`
+pub const EGL_VERSION: EGLint = 0x3054;
+/// `EGL_EXTENSIONS`
+pub const EGL_EXTENSIONS: EGLint = 0x3055;
+/// `EGL_HEIGHT`
+pub const EGL_HEIGHT: EGLint = 0x3056;
+/// `EGL_WIDTH`
+pub const EGL_WIDTH: EGLint = 0x3057;
+/// `EGL_CLIENT_APIS`
+pub const EGL_CLIENT_APIS: EGLint = 0x308D;
+/// `EGL_CONTEXT_CLIENT_VERSION`, which is the same value as
+/// `EGL_CONTEXT_MAJOR_VERSION` in EGL 1.5.
+pub const EGL_CONTEXT_CLIENT_VERSION: EGLint = 0x3098;
+/// `EGL_CONTEXT_MINOR_VERSION`
+pub const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
+/// `EGL_CONTEXT_FLAGS_KHR`
+pub const EGL_CONTEXT_FLAGS_KHR: EGLint = 0x30FC;
+/// `EGL_CONTEXT_OPENGL_DEBUG_BIT_KHR`
+pub const EGL_CONTEXT_OPENGL_DEBUG_BIT_KHR: EGLint = 0x0001;
+
+/// `EGL_PBUFFER_BIT`
+pub const EGL_PBUFFER_BIT: EGLint = 0x0001;
+/// `EGL_WINDOW_BIT`
+pub const EGL_WINDOW_BIT: EGLint = 0x0004;
+/// `EGL_OPENGL_ES2_BIT`
+pub const EGL_OPENGL_ES2_BIT: EGLint = 0x0004;
+/// `EGL_OPENGL_ES3_BIT` (also `EGL_OPENGL_ES3_BIT_KHR`)
+pub const EGL_OPENGL_ES3_BIT: EGLint = 0x0040;
+
+/// `EGL_OPENGL_ES_API`
+pub const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
+/// `EGL_PLATFORM_SURFACELESS_MESA`
+pub const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+
+macro_rules! egl_fns {
+  ($(
+    $(#[$doc:meta])*
+    fn $name:ident = $c_name:literal ($($arg:ident: $ty:ty),*) -> $ret:ty;
+  )*) => {
+    /// The EGL functions, loaded from a `get_proc_address`.
+    ///
+    /// All the pointers start as null, and calling a function that isn't
+    /// loaded will panic.
+    #[repr(C)]
+    pub struct EglFns {
+      $($name: APcv,)*
+    }
+    impl EglFns {
+      /// Loads all the functions using the `get_proc_address` given.
+      ///
+      /// The closure gets the null-terminated name of each function, and
+      /// should give a null pointer for any that it doesn't have.
+      ///
+      /// ## Safety
+      /// * Each non-null pointer that `get_proc_address` gives must be the
+      ///   EGL function of the name asked for.
+      pub unsafe fn load_with<F>(mut get_proc_address: F) -> Self
+      where
+        F: FnMut(*const c_char) -> *mut c_void,
+      {
+        // Safety: the struct is nothing but `AtomicPtr` fields, which can be
+        // safely constructed with `zeroed`.
+        let out: Self = core::mem::zeroed();
+        $(
+          load_dyn_name_atomic_ptr(
+            &mut get_proc_address,
+            concat!($c_name, "\0").as_bytes(),
+            &out.$name,
+          );
+        )*
+        out
+      }
+
+      $(
+        $(#[$doc])*
+        ///
+        /// ## Safety
+        /// * The arguments must be valid for this EGL function, as its
+        ///   documentation describes.
+        #[cfg_attr(
+          all(feature = "inline", not(feature = "inline_always")),
+          inline
+        )]
+        #[cfg_attr(feature = "inline_always", inline(always))]
+        pub unsafe fn $name(&self, $($arg: $ty),*) -> $ret {
+          let p = self.$name.load(RELAX);
+          match transmute::<*mut c_void, Option<extern "system" fn($($ty),*) -> $ret>>(p) {
+            Some(fn_p) => fn_p($($arg),*),
+            None => go_panic_because_fn_not_loaded($c_name),
+          }
+        }
+      )*
+    }
+  };
+}
+
+egl_fns! {
+  /// [eglGetError](https://registry.khronos.org/EGL/sdk/docs/man/html/eglGetError.xhtml)
+  fn GetError = "eglGetError"() -> EGLint;
+  /// [eglGetProcAddress](https://registry.khronos.org/EGL/sdk/docs/man/html/eglGetProcAddress.xhtml)
+  fn GetProcAddress = "eglGetProcAddress"(procname: *const c_char) -> *mut c_void;
+  /// [eglGetDisplay](https://registry.khronos.org/EGL/sdk/docs/man/html/eglGetDisplay.xhtml)
+  fn GetDisplay = "eglGetDisplay"(display_id: EGLNativeDisplayType) -> EGLDisplay;
+  /// [eglGetPlatformDisplay](https://registry.khronos.org/EGL/sdk/docs/man/html/eglGetPlatformDisplay.xhtml)
+  /// (EGL 1.5)
+  fn GetPlatformDisplay = "eglGetPlatformDisplay"(
+    platform: EGLenum, native_display: *mut c_void, attrib_list: *const EGLAttrib
+  ) -> EGLDisplay;
+  /// `eglGetPlatformDisplayEXT` (`EGL_EXT_platform_base`)
+  fn GetPlatformDisplayEXT = "eglGetPlatformDisplayEXT"(
+    platform: EGLenum, native_display: *mut c_void, attrib_list: *const EGLint
+  ) -> EGLDisplay;
+  /// [eglInitialize](https://registry.khronos.org/EGL/sdk/docs/man/html/eglInitialize.xhtml)
+  fn Initialize = "eglInitialize"(
+    dpy: EGLDisplay, major: *mut EGLint, minor: *mut EGLint
+  ) -> EGLBoolean;
+  /// [eglTerminate](https://registry.khronos.org/EGL/sdk/docs/man/html/eglTerminate.xhtml)
+  fn Terminate = "eglTerminate"(dpy: EGLDisplay) -> EGLBoolean;
+  /// [eglQueryString](https://registry.khronos.org/EGL/sdk/docs/man/html/eglQueryString.xhtml)
+  fn QueryString = "eglQueryString"(dpy: EGLDisplay, name: EGLint) -> *const c_char;
+  /// [eglBindAPI](https://registry.khronos.org/EGL/sdk/docs/man/html/eglBindAPI.xhtml)
+  fn BindAPI = "eglBindAPI"(api: EGLenum) -> EGLBoolean;
+  /// [eglChooseConfig](https://registry.khronos.org/EGL/sdk/docs/man/html/eglChooseConfig.xhtml)
+  fn ChooseConfig = "eglChooseConfig"(
+    dpy: EGLDisplay, attrib_list: *const EGLint, configs: *mut EGLConfig,
+    config_size: EGLint, num_config: *mut EGLint
+  ) -> EGLBoolean;
+  /// [eglGetConfigAttrib](https://registry.khronos.org/EGL/sdk/docs/man/html/eglGetConfigAttrib.xhtml)
+  fn GetConfigAttrib = "eglGetConfigAttrib"(
+    dpy: EGLDisplay, config: EGLConfig, attribute: EGLint, value: *mut EGLint
+  ) -> EGLBoolean;
+  /// [eglCreateContext](https://registry.khronos.org/EGL/sdk/docs/man/html/eglCreateContext.xhtml)
+  fn CreateContext = "eglCreateContext"(
+    dpy: EGLDisplay, config: EGLConfig, share_context: EGLContext,
+    attrib_list: *const EGLint
+  ) -> EGLContext;
+  /// [eglDestroyContext](https://registry.khronos.org/EGL/sdk/docs/man/html/eglDestroyContext.xhtml)
+  fn DestroyContext = "eglDestroyContext"(dpy: EGLDisplay, ctx: EGLContext) -> EGLBoolean;
+  /// [eglCreatePbufferSurface](https://registry.khronos.org/EGL/sdk/docs/man/html/eglCreatePbufferSurface.xhtml)
+  fn CreatePbufferSurface = "eglCreatePbufferSurface"(
+    dpy: EGLDisplay, config: EGLConfig, attrib_list: *const EGLint
+  ) -> EGLSurface;
+  /// [eglDestroySurface](https://registry.khronos.org/EGL/sdk/docs/man/html/eglDestroySurface.xhtml)
+  fn DestroySurface = "eglDestroySurface"(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+  /// [eglMakeCurrent](https://registry.khronos.org/EGL/sdk/docs/man/html/eglMakeCurrent.xhtml)
+  fn MakeCurrent = "eglMakeCurrent"(
+    dpy: EGLDisplay, draw: EGLSurface, read: EGLSurface, ctx: EGLContext
+  ) -> EGLBoolean;
+  /// [eglGetCurrentContext](https://registry.khronos.org/EGL/sdk/docs/man/html/eglGetCurrentContext.xhtml)
+  fn GetCurrentContext = "eglGetCurrentContext"() -> EGLContext;
+  /// [eglReleaseThread](https://registry.khronos.org/EGL/sdk/docs/man/html/eglReleaseThread.xhtml)
+  fn ReleaseThread = "eglReleaseThread"() -> EGLBoolean;
+}
+
+impl core::fmt::Debug for EglFns {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "EglFns")
+  }
+}
+
+/// An EGL error: the function that failed, and the code from `eglGetError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EglError {
+  /// The name of the EGL function that failed.
+  pub function: &'static str,
+  /// The error code, such as [`EGL_BAD_CONFIG`].
+  pub code: EGLint,
+}
+impl EglError {
+  /// The name of the error code, such as `"EGL_BAD_CONFIG"`.
+  pub fn code_name(&self) -> Option<&'static str> {
+    Some(match self.code {
+      EGL_SUCCESS => "EGL_SUCCESS",
+      EGL_NOT_INITIALIZED => "EGL_NOT_INITIALIZED",
+      EGL_BAD_ACCESS => "EGL_BAD_ACCESS",
+      EGL_BAD_ALLOC => "EGL_BAD_ALLOC",
+      EGL_BAD_ATTRIBUTE => "EGL_BAD_ATTRIBUTE",
+      EGL_BAD_CONFIG => "EGL_BAD_CONFIG",
+      EGL_BAD_CONTEXT => "EGL_BAD_CONTEXT",
+      EGL_BAD_CURRENT_SURFACE => "EGL_BAD_CURRENT_SURFACE",
+      EGL_BAD_DISPLAY => "EGL_BAD_DISPLAY",
+      EGL_BAD_MATCH => "EGL_BAD_MATCH",
+      EGL_BAD_NATIVE_PIXMAP => "EGL_BAD_NATIVE_PIXMAP",
+      EGL_BAD_NATIVE_WINDOW => "EGL_BAD_NATIVE_WINDOW",
+      EGL_BAD_PARAMETER => "EGL_BAD_PARAMETER",
+      EGL_BAD_SURFACE => "EGL_BAD_SURFACE",
+      EGL_CONTEXT_LOST => "EGL_CONTEXT_LOST",
+      _ => return None,
+    })
+  }
+}
+impl core::fmt::Display for EglError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self.code_name() {
+      Some(name) => write!(f, "{} failed with {}", self.function, name),
+      None => write!(f, "{} failed with {:#X}", self.function, self.code),
+    }
+  }
+}
+with_std! {
+  impl std::error::Error for EglError {}
+}
+
+impl EglFns {
+  /// Makes an [`EglError`] for `function`, with the current error code.
+  unsafe fn error(&self, function: &'static str) -> EglError {
+    EglError { function, code: self.GetError() }
+  }
+
+  /// If `eglQueryString(dpy, EGL_EXTENSIONS)` lists the extension given.
+  ///
+  /// With [`EGL_NO_DISPLAY`] this checks the client extensions (which needs
+  /// EGL 1.5 or `EGL_EXT_client_extensions`).
+  ///
+  /// ## Safety
+  /// * `eglQueryString` and `eglGetError` must be loaded.
+  /// * `dpy` must be [`EGL_NO_DISPLAY`] or a display from this EGL.
+  pub unsafe fn has_extension(&self, dpy: EGLDisplay, name: &str) -> bool {
+    let list = self.QueryString(dpy, EGL_EXTENSIONS);
+    if list.is_null() {
+      // An error here just means "no extensions", so clear it.
+      self.GetError();
+      return false;
+    }
+    let list = c_str_bytes(list as *const GLubyte);
+    list.split(|b| *b == b' ').any(|ext| ext == name.as_bytes())
+  }
+}
+
+/// A GLES 3.0 context with no window, which is made current on creation.
+///
+/// When the display supports `EGL_KHR_surfaceless_context` the context has
+/// no surface at all (render into your own framebuffer objects), otherwise
+/// it gets a small pbuffer surface.
+///
+/// Dropping this makes no context current (on this thread) and destroys the
+/// context and surface. The display is left initialized, since other code
+/// could be using it too.
+pub struct HeadlessContext<'egl> {
+  egl: &'egl EglFns,
+  display: EGLDisplay,
+  context: EGLContext,
+  surface: EGLSurface,
+}
+impl core::fmt::Debug for HeadlessContext<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("HeadlessContext")
+      .field("display", &self.display)
+      .field("context", &self.context)
+      .field("surface", &self.surface)
+      .finish()
+  }
+}
+impl<'egl> HeadlessContext<'egl> {
+  /// Makes a surfaceless context if possible, or one with a 1x1 pbuffer if
+  /// not.
+  ///
+  /// The display is the Mesa surfaceless platform when EGL offers it
+  /// (`EGL_MESA_platform_surfaceless`), or the default display otherwise.
+  ///
+  /// ## Safety
+  /// * The `EglFns` must be loaded from a working EGL, with every function
+  ///   that this module uses (only `eglGetPlatformDisplayEXT` is optional).
+  pub unsafe fn new(egl: &'egl EglFns) -> Result<Self, EglError> {
+    Self::create(egl, None)
+  }
+
+  /// Makes a context with a pbuffer surface of the size given, even if
+  /// surfaceless contexts are supported.
+  ///
+  /// ## Safety
+  /// * The `EglFns` must be loaded, as with [`new`](HeadlessContext::new).
+  pub unsafe fn with_pbuffer(
+    egl: &'egl EglFns, width: EGLint, height: EGLint,
+  ) -> Result<Self, EglError> {
+    Self::create(egl, Some((width, height)))
+  }
+
+  unsafe fn create(
+    egl: &'egl EglFns, pbuffer: Option<(EGLint, EGLint)>,
+  ) -> Result<Self, EglError> {
+    let display = Self::get_display(egl)?;
+    let (mut major, mut minor) = (0, 0);
+    if egl.Initialize(display, &mut major, &mut minor) == EGL_FALSE {
+      return Err(egl.error("eglInitialize"));
+    }
+    if egl.BindAPI(EGL_OPENGL_ES_API) == EGL_FALSE {
+      return Err(egl.error("eglBindAPI"));
+    }
+    let pbuffer = match pbuffer {
+      Some(size) => Some(size),
+      None if egl.has_extension(display, "EGL_KHR_surfaceless_context") => None,
+      None => Some((1, 1)),
+    };
+    let surface_type = if pbuffer.is_some() { EGL_PBUFFER_BIT } else { 0 };
+    let config_attribs = [
+      EGL_RENDERABLE_TYPE,
+      EGL_OPENGL_ES3_BIT,
+      EGL_SURFACE_TYPE,
+      surface_type,
+      EGL_NONE,
+    ];
+    let mut config: EGLConfig = null_mut();
+    let mut num_config = 0;
+    if egl.ChooseConfig(
+      display,
+      config_attribs.as_ptr(),
+      &mut config,
+      1,
+      &mut num_config,
+    ) == EGL_FALSE
+    {
+      return Err(egl.error("eglChooseConfig"));
+    }
+    if num_config < 1 {
+      return Err(EglError {
+        function: "eglChooseConfig",
+        code: EGL_BAD_CONFIG,
+      });
+    }
+    let context_attribs = [EGL_CONTEXT_CLIENT_VERSION, 3, EGL_NONE];
+    let context = egl.CreateContext(
+      display,
+      config,
+      EGL_NO_CONTEXT,
+      context_attribs.as_ptr(),
+    );
+    if context == EGL_NO_CONTEXT {
+      return Err(egl.error("eglCreateContext"));
+    }
+    // From here on, dropping `out` cleans up whatever was made.
+    let mut out = Self { egl, display, context, surface: EGL_NO_SURFACE };
+    if let Some((width, height)) = pbuffer {
+      let surface_attribs = [EGL_WIDTH, width, EGL_HEIGHT, height, EGL_NONE];
+      out.surface =
+        egl.CreatePbufferSurface(display, config, surface_attribs.as_ptr());
+      if out.surface == EGL_NO_SURFACE {
+        return Err(egl.error("eglCreatePbufferSurface"));
+      }
+    }
+    out.make_current()?;
+    Ok(out)
+  }
+
+  unsafe fn get_display(egl: &EglFns) -> Result<EGLDisplay, EglError> {
+    let display = if !egl.GetPlatformDisplayEXT.load(RELAX).is_null()
+      && egl.has_extension(EGL_NO_DISPLAY, "EGL_MESA_platform_surfaceless")
+    {
+      let attribs = [EGL_NONE];
+      egl.GetPlatformDisplayEXT(
+        EGL_PLATFORM_SURFACELESS_MESA,
+        EGL_DEFAULT_DISPLAY,
+        attribs.as_ptr(),
+      )
+    } else {
+      egl.GetDisplay(EGL_DEFAULT_DISPLAY)
+    };
+    if display == EGL_NO_DISPLAY {
+      Err(egl.error("eglGetDisplay"))
+    } else {
+      Ok(display)
+    }
+  }
+
+  /// Makes this context current on this thread.
+  ///
+  /// This is done when the context is made, so you only need this after
+  /// making some other context current, or to use it on another thread.
+  ///
+  /// ## Safety
+  /// * The context must not be current on any other thread.
+  pub unsafe fn make_current(&self) -> Result<(), EglError> {
+    let made = self.egl.MakeCurrent(
+      self.display,
+      self.surface,
+      self.surface,
+      self.context,
+    );
+    if made == EGL_FALSE {
+      Err(self.egl.error("eglMakeCurrent"))
+    } else {
+      Ok(())
+    }
+  }
+
+  /// The EGL display.
+  pub fn display(&self) -> EGLDisplay {
+    self.display
+  }
+
+  /// The EGL context.
+  pub fn context(&self) -> EGLContext {
+    self.context
+  }
+
+  /// The pbuffer surface, or [`EGL_NO_SURFACE`] for a surfaceless context.
+  pub fn surface(&self) -> EGLSurface {
+    self.surface
+  }
+}
+impl Drop for HeadlessContext<'_> {
+  fn drop(&mut self) {
+    // Safety: these handles were all made by this value, and aren't used
+    // after this.
+    unsafe {
+      if self.egl.GetCurrentContext() == self.context {
+        self.egl.MakeCurrent(
+          self.display,
+          EGL_NO_SURFACE,
+          EGL_NO_SURFACE,
+          EGL_NO_CONTEXT,
+        );
+      }
+      if self.surface != EGL_NO_SURFACE {
+        self.egl.DestroySurface(self.display, self.surface);
+      }
+      self.egl.DestroyContext(self.display, self.context);
+    }
+  }
+}
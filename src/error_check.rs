@@ -0,0 +1,153 @@
+//! What happens when the automatic `glGetError` check finds an error.
+//!
+//! With the `debug_automatic_glGetError` feature (in debug builds) or the
+//! `automatic_glGetError` feature (in all builds), `glGetError` is called
+//! after every other GL call. Any error found is handled according to the
+//! current [`ErrorCheckPolicy`]:
+//! * For the global loader, use [`set_global_error_check_policy`].
+//! * For the struct loader, use [`GlFns::set_error_check_policy`]. Each
+//!   `GlFns` has its own policy.
+//!
+//! The default policy is [`Log`](ErrorCheckPolicy::Log).
+//!
+//! ```no_run
+//! # #[cfg(feature = "global_loader")]
+//! # fn f() {
+//! use gles30::error_check::*;
+//! // panic on the first error, at the line that made the bad call.
+//! set_global_error_check_policy(ErrorCheckPolicy::Panic);
+//! # }
+//! ```
+
+// Without a loader, or in a release build with only the debug feature, the
+// check is never made.
+#![cfg_attr(
+  not(all(
+    any(feature = "global_loader", feature = "struct_loader"),
+    any(feature = "automatic_glGetError", debug_assertions)
+  )),
+  allow(dead_code)
+)]
+
+use super::*;
+
+/// What to do with an error found by the automatic `glGetError` check.
+#[derive(Debug, Clone, Copy)]
+pub enum ErrorCheckPolicy {
+  /// Do nothing.
+  ///
+  /// The `glGetError` calls are still made (so errors don't pile up), but
+  /// the errors are thrown away.
+  Ignore,
+  /// Show the error with `error!`, along with the name of the command.
+  ///
+  /// This is the default.
+  Log,
+  /// Panic, with the name of the command and the error.
+  Panic,
+  /// Call a function with the name of the command and the error.
+  Callback(fn(&'static str, GLenum)),
+}
+impl Default for ErrorCheckPolicy {
+  fn default() -> Self {
+    ErrorCheckPolicy::Log
+  }
+}
+
+// Policies are stored in an `AtomicPtr`, with the default as null, and the
+// other variants without data as pointer values that a function can't have.
+const IGNORE: usize = 1;
+const PANIC: usize = 2;
+
+impl ErrorCheckPolicy {
+  fn to_ptr(self) -> *mut c_void {
+    match self {
+      ErrorCheckPolicy::Log => null_mut(),
+      ErrorCheckPolicy::Ignore => IGNORE as *mut c_void,
+      ErrorCheckPolicy::Panic => PANIC as *mut c_void,
+      ErrorCheckPolicy::Callback(f) => f as *mut c_void,
+    }
+  }
+
+  fn from_ptr(p: *mut c_void) -> Self {
+    match p as usize {
+      0 => ErrorCheckPolicy::Log,
+      IGNORE => ErrorCheckPolicy::Ignore,
+      PANIC => ErrorCheckPolicy::Panic,
+      // Safety: the only other values stored are from `Callback`.
+      _ => ErrorCheckPolicy::Callback(unsafe {
+        transmute::<*mut c_void, fn(&'static str, GLenum)>(p)
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "global_loader")]
+static GLOBAL_POLICY: APcv = AtomicPtr::new(null_mut());
+
+/// Sets the policy for errors found after calls to the global GL functions.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn set_global_error_check_policy(policy: ErrorCheckPolicy) {
+  GLOBAL_POLICY.store(policy.to_ptr(), RELAX)
+}
+
+/// Gets the policy for errors found after calls to the global GL functions.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn global_error_check_policy() -> ErrorCheckPolicy {
+  ErrorCheckPolicy::from_ptr(GLOBAL_POLICY.load(RELAX))
+}
+
+#[cfg(feature = "global_loader")]
+pub(crate) fn report_global_error(name: &'static str, err: GLenum) {
+  report_error(global_error_check_policy(), name, err)
+}
+
+#[cfg(feature = "struct_loader")]
+impl GlFns {
+  /// Sets the policy for errors found after calls through this `GlFns`.
+  pub fn set_error_check_policy(&self, policy: ErrorCheckPolicy) {
+    self.error_check_policy.store(policy.to_ptr(), RELAX)
+  }
+
+  /// Gets the policy for errors found after calls through this `GlFns`.
+  pub fn error_check_policy(&self) -> ErrorCheckPolicy {
+    ErrorCheckPolicy::from_ptr(self.error_check_policy.load(RELAX))
+  }
+}
+
+pub(crate) fn report_error(
+  policy: ErrorCheckPolicy, name: &'static str, err: GLenum,
+) {
+  match policy {
+    ErrorCheckPolicy::Ignore => (),
+    ErrorCheckPolicy::Log => log_error(name, err),
+    ErrorCheckPolicy::Panic => match name_of_in_group("ErrorCode", err) {
+      Some(err_name) => panic!("{} from {}", err_name, name),
+      None => panic!("Unknown error code {:#X} from {}", err, name),
+    },
+    ErrorCheckPolicy::Callback(f) => f(name, err),
+  }
+}
+
+#[inline(never)]
+fn log_error(name: &str, err: GLenum) {
+  match err {
+    GL_NO_ERROR => (),
+    GL_INVALID_ENUM => error!("Invalid Enum to {name}.", name = name),
+    GL_INVALID_VALUE => error!("Invalid Value to {name}.", name = name),
+    GL_INVALID_OPERATION => error!("Invalid Operation to {name}.", name = name),
+    GL_INVALID_FRAMEBUFFER_OPERATION => {
+      error!("Invalid Framebuffer Operation to {name}.", name = name)
+    }
+    GL_OUT_OF_MEMORY => error!("Out of Memory in {name}.", name = name),
+    GL_STACK_UNDERFLOW => error!("Stack Underflow in {name}.", name = name),
+    GL_STACK_OVERFLOW => error!("Stack Overflow in {name}.", name = name),
+    unknown => error!(
+      "Unknown error code {unknown} to {name}.",
+      name = name,
+      unknown = unknown
+    ),
+  }
+}
@@ -0,0 +1,166 @@
+//! User hooks that run before and after every GL call.
+//!
+//! With the `call_hooks` feature enabled, every GL command calls
+//! [`GlHooks::before`] just before making the call, and [`GlHooks::after`]
+//! just after it, when hooks are installed. This works with both loaders:
+//! * For the global loader, use [`set_global_hooks`].
+//! * For the struct loader, use [`GlFns::set_hooks`]. Each `GlFns` has its
+//!   own hooks.
+//!
+//! When no hooks are installed the check for them is all that happens.
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f(gl: &mut gles30::GlFns) {
+//! use core::fmt::Debug;
+//! use gles30::hooks::GlHooks;
+//!
+//! struct PrintDraws;
+//! impl GlHooks for PrintDraws {
+//!   fn before(&self, cmd: &'static str, args: &dyn Debug) {
+//!     if cmd.starts_with("glDraw") {
+//!       // such as "glDrawArrays(GL_TRIANGLES, 0, 3)"
+//!       println!("{}({:?})", cmd, args);
+//!     }
+//!   }
+//! }
+//!
+//! gl.set_hooks(PrintDraws);
+//! # }
+//! ```
+//!
+//! The `args` are formatted the same as with `debug_trace_calls`: `GLenum`
+//! and `GLbitfield` values by name, and pointers as addresses.
+//!
+//! GL calls made from inside a hook (through the same loader) run the hooks
+//! too, so a hook that calls GL needs to avoid recursing forever.
+
+use super::*;
+use core::fmt::Debug;
+#[cfg(feature = "struct_loader")]
+use std::boxed::Box;
+use std::sync::Arc;
+#[cfg(feature = "global_loader")]
+use std::sync::{PoisonError, RwLock};
+
+/// Something that runs before and after each GL call.
+///
+/// Both methods do nothing by default, so implement just the ones you need.
+///
+/// The hooks can be used from any thread that makes GL calls, so they must
+/// be `Send` and `Sync`.
+pub trait GlHooks: Send + Sync {
+  /// Runs before a call to the command named `cmd` (such as
+  /// `"glDrawArrays"`), with the arguments to that call.
+  #[allow(unused_variables)]
+  fn before(&self, cmd: &'static str, args: &dyn Debug) {}
+
+  /// Runs after a call to the command named `cmd`, with the value that the
+  /// call returned (`()` for commands without a return value).
+  #[allow(unused_variables)]
+  fn after(&self, cmd: &'static str, ret: &dyn Debug) {}
+}
+
+impl<H> GlHooks for Arc<H>
+where
+  H: GlHooks + ?Sized,
+{
+  fn before(&self, cmd: &'static str, args: &dyn Debug) {
+    (**self).before(cmd, args)
+  }
+
+  fn after(&self, cmd: &'static str, ret: &dyn Debug) {
+    (**self).after(cmd, ret)
+  }
+}
+
+#[cfg(feature = "global_loader")]
+static GLOBAL_HOOKS: LazyStatic<RwLock<Option<Arc<dyn GlHooks>>>> =
+  LazyStatic::new();
+#[cfg(feature = "global_loader")]
+static GLOBAL_HOOKS_ARE_SET: core::sync::atomic::AtomicBool =
+  core::sync::atomic::AtomicBool::new(false);
+
+/// Sets the hooks that the global GL functions run.
+///
+/// Any previous hooks are replaced and returned.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn set_global_hooks<H: GlHooks + 'static>(
+  hooks: H,
+) -> Option<Arc<dyn GlHooks>> {
+  let mut guard =
+    GLOBAL_HOOKS.get().write().unwrap_or_else(PoisonError::into_inner);
+  GLOBAL_HOOKS_ARE_SET.store(true, RELAX);
+  guard.replace(Arc::new(hooks))
+}
+
+/// Stops the global GL functions from running hooks.
+///
+/// The previous hooks are returned, if there were any.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn clear_global_hooks() -> Option<Arc<dyn GlHooks>> {
+  let mut guard =
+    GLOBAL_HOOKS.get().write().unwrap_or_else(PoisonError::into_inner);
+  GLOBAL_HOOKS_ARE_SET.store(false, RELAX);
+  guard.take()
+}
+
+/// Gets the global hooks, if any are set.
+///
+/// This gives a clone of the `Arc` so that the lock isn't held while the
+/// hooks run, which lets them make GL calls.
+#[cfg(feature = "global_loader")]
+#[inline]
+pub(crate) fn global_hooks() -> Option<Arc<dyn GlHooks>> {
+  if !GLOBAL_HOOKS_ARE_SET.load(RELAX) {
+    return None;
+  }
+  GLOBAL_HOOKS.get().read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+#[cfg(feature = "struct_loader")]
+type BoxedHooks = Box<dyn GlHooks>;
+
+#[cfg(feature = "struct_loader")]
+impl GlFns {
+  /// Sets the hooks that this `GlFns` runs.
+  ///
+  /// Any previous hooks are replaced and returned.
+  pub fn set_hooks<H: GlHooks + 'static>(
+    &mut self, hooks: H,
+  ) -> Option<Box<dyn GlHooks>> {
+    let new: Box<BoxedHooks> = Box::new(Box::new(hooks));
+    let old = self.hooks.swap(Box::into_raw(new) as *mut c_void, RELAX);
+    Self::unbox_hooks(old)
+  }
+
+  /// Stops this `GlFns` from running hooks.
+  ///
+  /// The previous hooks are returned, if there were any.
+  pub fn clear_hooks(&mut self) -> Option<Box<dyn GlHooks>> {
+    let old = self.hooks.swap(null_mut(), RELAX);
+    Self::unbox_hooks(old)
+  }
+
+  fn unbox_hooks(p: *mut c_void) -> Option<BoxedHooks> {
+    if p.is_null() {
+      None
+    } else {
+      // Safety: non-null hook pointers always come from `Box::into_raw` in
+      // `set_hooks`, and the `&mut self` there and in `clear_hooks` means
+      // that no call can be using the hooks right now.
+      Some(*unsafe { Box::from_raw(p as *mut BoxedHooks) })
+    }
+  }
+
+  /// Gets the hooks of this `GlFns`, if any are set.
+  #[inline]
+  pub(crate) fn hooks(&self) -> Option<&dyn GlHooks> {
+    let p = self.hooks.load(RELAX) as *const BoxedHooks;
+    // Safety: see `unbox_hooks`. The hooks can't be replaced while `self` is
+    // borrowed.
+    unsafe { p.as_ref() }.map(|b| &**b)
+  }
+}
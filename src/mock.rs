@@ -0,0 +1,4159 @@
+//! A fake GL context that lives entirely in memory, for testing without a GPU.
+//!
+//! Pass [`get_proc_address`] to either loader and every command in this crate
+//! will be loaded with a Rust function that emulates a GLES 3.0 context:
+//!
+//! ```
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe {
+//! use gles30::*;
+//! let gl = GlFns::load_with(|name| mock::get_proc_address(name));
+//! let mut buffer = 0;
+//! gl.GenBuffers(1, &mut buffer);
+//! gl.BindBuffer(GL_ARRAY_BUFFER, buffer);
+//! gl.BufferData(GL_ARRAY_BUFFER, 4, [1_u8, 2, 3, 4].as_ptr() as *const _,
+//!   GL_STATIC_DRAW);
+//! assert_eq!(gl.GetError(), GL_NO_ERROR);
+//! mock::with_state(|state| {
+//!   assert_eq!(state.buffer_data(buffer), Some(&[1_u8, 2, 3, 4][..]));
+//! });
+//! # }
+//! ```
+//!
+//! The mock keeps track of object names, bindings, buffer contents, texture
+//! sizes, shader sources and program linking, enabled capabilities, the
+//! `glGetError` flags, and the `GL_KHR_debug` state (labels, debug groups,
+//! message callback and log). Invalid arguments to the modeled commands raise
+//! the same errors that a real context would. Commands that only affect
+//! rendering (blending, stencil, uniforms, and so on) are accepted and
+//! otherwise ignored, since there's no rendering.
+//!
+//! The GLES 3.1 and 3.2 commands and the extension commands are there too,
+//! so the mock loads completely with any cargo features. The ones that are
+//! just the `GL_KHR_debug` or query commands without (or with) a suffix act
+//! like those, and program pipeline names are kept track of. The rest are
+//! accepted and ignored.
+//!
+//! Each thread has its own mock context, much like a real GL context is
+//! current on only one thread at a time. This means that tests running in
+//! parallel don't interfere with each other. Use [`reset`] to put the current
+//! thread's context back into its starting state.
+//!
+//! Shader "compilation" fails if any line of the source starts with `#error`,
+//! and the info log then has a line in the usual
+//! `ERROR: 0:<line>: '#error' : <message>` format. Linking succeeds when the
+//! program has exactly one compiled vertex shader and one compiled fragment
+//! shader attached. Uniforms declared with a `uniform <type> <name>;` line get
+//! locations in the order that they're declared.
+
+use super::*;
+use std::{
+  cell::RefCell,
+  collections::{BTreeMap, HashMap, HashSet, VecDeque},
+  ffi::{CStr, CString},
+  format,
+  string::{String, ToString},
+  thread_local, vec,
+  vec::Vec,
+};
+
+// `GL_KHR_debug` values, which the mock always supports even if the feature
+// isn't enabled.
+const DEBUG_OUTPUT: GLenum = 0x92E0;
+const DEBUG_OUTPUT_SYNCHRONOUS: GLenum = 0x8242;
+const DEBUG_CALLBACK_FUNCTION: GLenum = 0x8244;
+const DEBUG_CALLBACK_USER_PARAM: GLenum = 0x8245;
+const DEBUG_SOURCE_API: GLenum = 0x8246;
+const DEBUG_TYPE_ERROR: GLenum = 0x824C;
+const DEBUG_TYPE_PUSH_GROUP: GLenum = 0x8269;
+const DEBUG_TYPE_POP_GROUP: GLenum = 0x826A;
+const DEBUG_SEVERITY_NOTIFICATION: GLenum = 0x826B;
+const DEBUG_SEVERITY_HIGH: GLenum = 0x9146;
+const DEBUG_SEVERITY_LOW: GLenum = 0x9148;
+const DEBUG_LOGGED_MESSAGES: GLenum = 0x9145;
+const DEBUG_NEXT_LOGGED_MESSAGE_LENGTH: GLenum = 0x8243;
+const DEBUG_GROUP_STACK_DEPTH: GLenum = 0x826D;
+const MAX_DEBUG_GROUP_STACK_DEPTH: GLenum = 0x826C;
+const MAX_LABEL_LENGTH: GLenum = 0x82E8;
+const MAX_DEBUG_MESSAGE_LENGTH: GLenum = 0x9143;
+const MAX_DEBUG_LOGGED_MESSAGES: GLenum = 0x9144;
+const DEBUG_SOURCE_APPLICATION: GLenum = 0x824A;
+const DEBUG_SOURCE_THIRD_PARTY: GLenum = 0x8249;
+const CONTEXT_FLAGS: GLenum = 0x821E;
+const BUFFER_IDENTIFIER: GLenum = 0x82E0;
+const SHADER_IDENTIFIER: GLenum = 0x82E1;
+const PROGRAM_IDENTIFIER: GLenum = 0x82E2;
+const QUERY_IDENTIFIER: GLenum = 0x82E3;
+const SAMPLER_IDENTIFIER: GLenum = 0x82E6;
+const VERTEX_ARRAY_IDENTIFIER: GLenum = 0x8074;
+const PROGRAM_PIPELINE_IDENTIFIER: GLenum = 0x82E4;
+
+const MAX_DEBUG_GROUP_DEPTH_VALUE: usize = 64;
+const MAX_LABEL_LENGTH_VALUE: usize = 256;
+const MAX_DEBUG_MESSAGE_LENGTH_VALUE: usize = 1024;
+const MAX_DEBUG_LOGGED_MESSAGES_VALUE: usize = 64;
+const MAX_VERTEX_ATTRIBS_VALUE: usize = 16;
+const MAX_TEXTURE_UNITS_VALUE: usize = 32;
+const MAX_UNIFORM_BUFFER_BINDINGS_VALUE: usize = 24;
+const MAX_TRANSFORM_FEEDBACK_BINDINGS_VALUE: usize = 4;
+const MAX_DRAW_BUFFERS_VALUE: usize = 4;
+
+/// Implementation limits reported by the mock.
+///
+/// These are the minimum values that GLES 3.0 allows.
+const LIMITS: &[(GLenum, &[GLint])] = &[
+  (GL_ALIASED_LINE_WIDTH_RANGE, &[1, 1]),
+  (GL_ALIASED_POINT_SIZE_RANGE, &[1, 1]),
+  (GL_ALPHA_BITS, &[8]),
+  (GL_BLUE_BITS, &[8]),
+  (GL_DEPTH_BITS, &[24]),
+  (GL_GREEN_BITS, &[8]),
+  (GL_IMPLEMENTATION_COLOR_READ_FORMAT, &[GL_RGBA as GLint]),
+  (GL_IMPLEMENTATION_COLOR_READ_TYPE, &[GL_UNSIGNED_BYTE as GLint]),
+  (GL_MAJOR_VERSION, &[3]),
+  (GL_MAX_3D_TEXTURE_SIZE, &[256]),
+  (GL_MAX_ARRAY_TEXTURE_LAYERS, &[256]),
+  (GL_MAX_COLOR_ATTACHMENTS, &[MAX_DRAW_BUFFERS_VALUE as GLint]),
+  (GL_MAX_COMBINED_FRAGMENT_UNIFORM_COMPONENTS, &[50176]),
+  (GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS, &[MAX_TEXTURE_UNITS_VALUE as GLint]),
+  (GL_MAX_COMBINED_UNIFORM_BLOCKS, &[24]),
+  (GL_MAX_COMBINED_VERTEX_UNIFORM_COMPONENTS, &[50176]),
+  (GL_MAX_CUBE_MAP_TEXTURE_SIZE, &[2048]),
+  (GL_MAX_DRAW_BUFFERS, &[MAX_DRAW_BUFFERS_VALUE as GLint]),
+  (GL_MAX_ELEMENTS_INDICES, &[65536]),
+  (GL_MAX_ELEMENTS_VERTICES, &[65536]),
+  (GL_MAX_ELEMENT_INDEX, &[16777215]),
+  (GL_MAX_FRAGMENT_INPUT_COMPONENTS, &[60]),
+  (GL_MAX_FRAGMENT_UNIFORM_BLOCKS, &[12]),
+  (GL_MAX_FRAGMENT_UNIFORM_COMPONENTS, &[896]),
+  (GL_MAX_FRAGMENT_UNIFORM_VECTORS, &[224]),
+  (GL_MAX_PROGRAM_TEXEL_OFFSET, &[7]),
+  (GL_MAX_RENDERBUFFER_SIZE, &[2048]),
+  (GL_MAX_SAMPLES, &[4]),
+  (GL_MAX_SERVER_WAIT_TIMEOUT, &[0]),
+  (GL_MAX_TEXTURE_IMAGE_UNITS, &[16]),
+  (GL_MAX_TEXTURE_LOD_BIAS, &[2]),
+  (GL_MAX_TEXTURE_SIZE, &[2048]),
+  (GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS, &[64]),
+  (
+    GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS,
+    &[MAX_TRANSFORM_FEEDBACK_BINDINGS_VALUE as GLint],
+  ),
+  (GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS, &[4]),
+  (GL_MAX_UNIFORM_BLOCK_SIZE, &[16384]),
+  (
+    GL_MAX_UNIFORM_BUFFER_BINDINGS,
+    &[MAX_UNIFORM_BUFFER_BINDINGS_VALUE as GLint],
+  ),
+  (GL_MAX_VARYING_COMPONENTS, &[60]),
+  (GL_MAX_VARYING_VECTORS, &[15]),
+  (GL_MAX_VERTEX_ATTRIBS, &[MAX_VERTEX_ATTRIBS_VALUE as GLint]),
+  (GL_MAX_VERTEX_OUTPUT_COMPONENTS, &[64]),
+  (GL_MAX_VERTEX_TEXTURE_IMAGE_UNITS, &[16]),
+  (GL_MAX_VERTEX_UNIFORM_BLOCKS, &[12]),
+  (GL_MAX_VERTEX_UNIFORM_COMPONENTS, &[1024]),
+  (GL_MAX_VERTEX_UNIFORM_VECTORS, &[256]),
+  (GL_MAX_VIEWPORT_DIMS, &[2048, 2048]),
+  (GL_MINOR_VERSION, &[0]),
+  (GL_MIN_PROGRAM_TEXEL_OFFSET, &[-8]),
+  (GL_NUM_COMPRESSED_TEXTURE_FORMATS, &[0]),
+  (GL_NUM_PROGRAM_BINARY_FORMATS, &[0]),
+  (GL_NUM_SHADER_BINARY_FORMATS, &[0]),
+  (GL_RED_BITS, &[8]),
+  (GL_SAMPLES, &[0]),
+  (GL_SAMPLE_BUFFERS, &[0]),
+  (GL_STENCIL_BITS, &[8]),
+  (GL_SUBPIXEL_BITS, &[4]),
+  (GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT, &[256]),
+  (CONTEXT_FLAGS, &[0]),
+  (MAX_DEBUG_GROUP_STACK_DEPTH, &[MAX_DEBUG_GROUP_DEPTH_VALUE as GLint]),
+  (MAX_LABEL_LENGTH, &[MAX_LABEL_LENGTH_VALUE as GLint]),
+  (MAX_DEBUG_MESSAGE_LENGTH, &[MAX_DEBUG_MESSAGE_LENGTH_VALUE as GLint]),
+  (MAX_DEBUG_LOGGED_MESSAGES, &[MAX_DEBUG_LOGGED_MESSAGES_VALUE as GLint]),
+];
+
+/// The capabilities accepted by `glEnable`, `glDisable` and `glIsEnabled`.
+const CAPABILITIES: &[GLenum] = &[
+  GL_BLEND,
+  GL_CULL_FACE,
+  GL_DEPTH_TEST,
+  GL_DITHER,
+  GL_POLYGON_OFFSET_FILL,
+  GL_PRIMITIVE_RESTART_FIXED_INDEX,
+  GL_RASTERIZER_DISCARD,
+  GL_SAMPLE_ALPHA_TO_COVERAGE,
+  GL_SAMPLE_COVERAGE,
+  GL_SCISSOR_TEST,
+  GL_STENCIL_TEST,
+  DEBUG_OUTPUT,
+  DEBUG_OUTPUT_SYNCHRONOUS,
+];
+
+const BUFFER_TARGETS: &[(GLenum, GLenum)] = &[
+  (GL_ARRAY_BUFFER, GL_ARRAY_BUFFER_BINDING),
+  (GL_COPY_READ_BUFFER, GL_COPY_READ_BUFFER_BINDING),
+  (GL_COPY_WRITE_BUFFER, GL_COPY_WRITE_BUFFER_BINDING),
+  (GL_ELEMENT_ARRAY_BUFFER, GL_ELEMENT_ARRAY_BUFFER_BINDING),
+  (GL_PIXEL_PACK_BUFFER, GL_PIXEL_PACK_BUFFER_BINDING),
+  (GL_PIXEL_UNPACK_BUFFER, GL_PIXEL_UNPACK_BUFFER_BINDING),
+  (GL_TRANSFORM_FEEDBACK_BUFFER, GL_TRANSFORM_FEEDBACK_BUFFER_BINDING),
+  (GL_UNIFORM_BUFFER, GL_UNIFORM_BUFFER_BINDING),
+];
+
+const TEXTURE_TARGETS: &[(GLenum, GLenum)] = &[
+  (GL_TEXTURE_2D, GL_TEXTURE_BINDING_2D),
+  (GL_TEXTURE_2D_ARRAY, GL_TEXTURE_BINDING_2D_ARRAY),
+  (GL_TEXTURE_3D, GL_TEXTURE_BINDING_3D),
+  (GL_TEXTURE_CUBE_MAP, GL_TEXTURE_BINDING_CUBE_MAP),
+];
+
+const PIXEL_STORE_PARAMS: &[(GLenum, GLint)] = &[
+  (GL_PACK_ALIGNMENT, 4),
+  (GL_PACK_ROW_LENGTH, 0),
+  (GL_PACK_SKIP_PIXELS, 0),
+  (GL_PACK_SKIP_ROWS, 0),
+  (GL_UNPACK_ALIGNMENT, 4),
+  (GL_UNPACK_IMAGE_HEIGHT, 0),
+  (GL_UNPACK_ROW_LENGTH, 0),
+  (GL_UNPACK_SKIP_IMAGES, 0),
+  (GL_UNPACK_SKIP_PIXELS, 0),
+  (GL_UNPACK_SKIP_ROWS, 0),
+];
+
+/// The kinds of object that the mock context keeps track of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectKind {
+  /// Made by `glGenBuffers`.
+  Buffer,
+  /// Made by `glGenTextures`.
+  Texture,
+  /// Made by `glGenFramebuffers`.
+  Framebuffer,
+  /// Made by `glGenRenderbuffers`.
+  Renderbuffer,
+  /// Made by `glGenSamplers`.
+  Sampler,
+  /// Made by `glGenQueries`.
+  Query,
+  /// Made by `glGenVertexArrays`.
+  VertexArray,
+  /// Made by `glGenTransformFeedbacks`.
+  TransformFeedback,
+  /// Made by `glGenProgramPipelines`.
+  ProgramPipeline,
+  /// Made by `glCreateShader`.
+  Shader,
+  /// Made by `glCreateProgram`.
+  Program,
+  /// Made by `glFenceSync`.
+  Sync,
+}
+
+#[derive(Debug, Default)]
+struct Buffer {
+  data: Vec<u8>,
+  usage: GLenum,
+  mapping: Option<(usize, usize, GLbitfield)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TexLevel {
+  internal_format: GLenum,
+  width: GLsizei,
+  height: GLsizei,
+  depth: GLsizei,
+}
+
+#[derive(Debug, Default)]
+struct Texture {
+  /// The target it was first bound to, or 0 if never bound.
+  target: GLenum,
+  /// Keyed by (image target, level).
+  levels: BTreeMap<(GLenum, GLint), TexLevel>,
+  params: HashMap<GLenum, GLfloat>,
+  immutable_levels: GLsizei,
+}
+
+#[derive(Debug, Default)]
+struct Renderbuffer {
+  internal_format: GLenum,
+  width: GLsizei,
+  height: GLsizei,
+  samples: GLsizei,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Attachment {
+  Texture(GLuint, GLint),
+  Renderbuffer(GLuint),
+}
+
+#[derive(Debug, Default)]
+struct Framebuffer {
+  attachments: HashMap<GLenum, Attachment>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct VertexAttrib {
+  enabled: bool,
+  size: GLint,
+  ty: GLenum,
+  normalized: bool,
+  integer: bool,
+  stride: GLsizei,
+  pointer: usize,
+  buffer: GLuint,
+  divisor: GLuint,
+}
+
+#[derive(Debug)]
+struct VertexArray {
+  element_buffer: GLuint,
+  attribs: [VertexAttrib; MAX_VERTEX_ATTRIBS_VALUE],
+}
+impl Default for VertexArray {
+  fn default() -> Self {
+    let attrib =
+      VertexAttrib { size: 4, ty: GL_FLOAT, ..VertexAttrib::default() };
+    Self { element_buffer: 0, attribs: [attrib; MAX_VERTEX_ATTRIBS_VALUE] }
+  }
+}
+
+#[derive(Debug, Default)]
+struct Shader {
+  kind: GLenum,
+  source: String,
+  compiled: bool,
+  info_log: String,
+  delete_pending: bool,
+}
+
+#[derive(Debug, Default)]
+struct Program {
+  shaders: Vec<GLuint>,
+  linked: bool,
+  info_log: String,
+  attrib_bindings: HashMap<String, GLuint>,
+  attribs: HashMap<String, GLint>,
+  uniforms: HashMap<String, GLint>,
+  delete_pending: bool,
+}
+
+#[derive(Debug, Clone)]
+struct LoggedMessage {
+  source: GLenum,
+  ty: GLenum,
+  id: GLuint,
+  severity: GLenum,
+  message: CString,
+}
+
+#[derive(Debug, Clone)]
+struct DebugControl {
+  source: GLenum,
+  ty: GLenum,
+  severity: GLenum,
+  ids: Option<Vec<GLuint>>,
+  enabled: bool,
+}
+
+/// The state of a mock GL context.
+///
+/// You can look at the state of the current thread's context with
+/// [`with_state`].
+#[derive(Debug)]
+pub struct MockState {
+  errors: Vec<GLenum>,
+  next_name: HashMap<ObjectKind, GLuint>,
+  buffers: HashMap<GLuint, Buffer>,
+  textures: HashMap<GLuint, Texture>,
+  framebuffers: HashMap<GLuint, Framebuffer>,
+  renderbuffers: HashMap<GLuint, Renderbuffer>,
+  samplers: HashMap<GLuint, HashMap<GLenum, GLfloat>>,
+  queries: HashMap<GLuint, GLenum>,
+  vertex_arrays: HashMap<GLuint, VertexArray>,
+  transform_feedbacks: HashSet<GLuint>,
+  program_pipelines: HashSet<GLuint>,
+  shaders: HashMap<GLuint, Shader>,
+  programs: HashMap<GLuint, Program>,
+  syncs: HashSet<usize>,
+  buffer_bindings: HashMap<GLenum, GLuint>,
+  indexed_buffer_bindings: HashMap<(GLenum, GLuint), GLuint>,
+  active_texture: GLuint,
+  texture_bindings: HashMap<(GLuint, GLenum), GLuint>,
+  sampler_bindings: HashMap<GLuint, GLuint>,
+  draw_framebuffer: GLuint,
+  read_framebuffer: GLuint,
+  renderbuffer: GLuint,
+  vertex_array: GLuint,
+  transform_feedback: GLuint,
+  program_pipeline: GLuint,
+  current_program: GLuint,
+  active_queries: HashMap<GLenum, GLuint>,
+  enabled: HashSet<GLenum>,
+  viewport: [GLint; 4],
+  scissor: [GLint; 4],
+  clear_color: [GLfloat; 4],
+  clear_depth: GLfloat,
+  clear_stencil: GLint,
+  pixel_store: HashMap<GLenum, GLint>,
+  draw_calls: usize,
+  extensions: Vec<CString>,
+  labels: HashMap<(GLenum, GLuint), String>,
+  sync_labels: HashMap<usize, String>,
+  debug_groups: Vec<(GLenum, GLuint, String)>,
+  // The `GLDEBUGPROCKHR`, as a pointer so that this can derive `Debug`.
+  debug_callback: *mut c_void,
+  debug_user_param: *mut c_void,
+  debug_log: VecDeque<LoggedMessage>,
+  debug_controls: Vec<DebugControl>,
+  pending_callbacks: Vec<LoggedMessage>,
+}
+
+impl Default for MockState {
+  fn default() -> Self {
+    let mut state = Self {
+      errors: Vec::new(),
+      next_name: HashMap::new(),
+      buffers: HashMap::new(),
+      textures: HashMap::new(),
+      framebuffers: HashMap::new(),
+      renderbuffers: HashMap::new(),
+      samplers: HashMap::new(),
+      queries: HashMap::new(),
+      vertex_arrays: HashMap::new(),
+      transform_feedbacks: HashSet::new(),
+      program_pipelines: HashSet::new(),
+      shaders: HashMap::new(),
+      programs: HashMap::new(),
+      syncs: HashSet::new(),
+      buffer_bindings: HashMap::new(),
+      indexed_buffer_bindings: HashMap::new(),
+      active_texture: 0,
+      texture_bindings: HashMap::new(),
+      sampler_bindings: HashMap::new(),
+      draw_framebuffer: 0,
+      read_framebuffer: 0,
+      renderbuffer: 0,
+      vertex_array: 0,
+      transform_feedback: 0,
+      program_pipeline: 0,
+      current_program: 0,
+      active_queries: HashMap::new(),
+      enabled: HashSet::new(),
+      viewport: [0, 0, 800, 600],
+      scissor: [0, 0, 800, 600],
+      clear_color: [0.0; 4],
+      clear_depth: 1.0,
+      clear_stencil: 0,
+      pixel_store: PIXEL_STORE_PARAMS.iter().cloned().collect(),
+      draw_calls: 0,
+      extensions: Vec::new(),
+      labels: HashMap::new(),
+      sync_labels: HashMap::new(),
+      debug_groups: Vec::new(),
+      debug_callback: core::ptr::null_mut(),
+      debug_user_param: null_mut(),
+      debug_log: VecDeque::new(),
+      debug_controls: Vec::new(),
+      pending_callbacks: Vec::new(),
+    };
+    // the default vertex array object and transform feedback object always
+    // exist, and dithering starts enabled.
+    state.vertex_arrays.insert(0, VertexArray::default());
+    state.transform_feedbacks.insert(0);
+    state.enabled.insert(GL_DITHER);
+    state.extensions.push(CString::new("GL_KHR_debug").unwrap());
+    state
+  }
+}
+
+thread_local! {
+  static STATE: RefCell<MockState> = RefCell::new(MockState::default());
+}
+
+/// Runs `f` on the current thread's mock state, then delivers any debug
+/// messages to the debug callback (outside of the state borrow, so the
+/// callback is free to call GL).
+fn with<R>(f: impl FnOnce(&mut MockState) -> R) -> R {
+  let out = STATE.with(|s| f(&mut s.borrow_mut()));
+  let (pending, callback, user_param) = STATE.with(|s| {
+    let mut s = s.borrow_mut();
+    let pending = core::mem::replace(&mut s.pending_callbacks, Vec::new());
+    let callback =
+      unsafe { transmute::<*mut c_void, GLDEBUGPROCKHR>(s.debug_callback) };
+    (pending, callback, s.debug_user_param)
+  });
+  if let Some(callback) = callback {
+    for m in pending {
+      let bytes = m.message.as_bytes();
+      callback(
+        m.source,
+        m.ty,
+        m.id,
+        m.severity,
+        bytes.len() as GLsizei,
+        m.message.as_ptr(),
+        user_param,
+      );
+    }
+  }
+  out
+}
+
+/// Looks up a mock GL command by name.
+///
+/// This takes the same argument that both loaders pass, so you can give
+/// `|name| mock::get_proc_address(name)` to [`load_global_gl_with`] or
+/// [`GlFns::load_with`]. Unknown names give a null pointer.
+///
+/// ## Safety
+/// * `name` must be null, or point to a null-terminated string, as with any
+///   other `get_proc_address` function.
+pub unsafe fn get_proc_address(name: *const c_char) -> *mut c_void {
+  if name.is_null() {
+    return null_mut();
+  }
+  let name = CStr::from_ptr(name);
+  lookup(name.to_bytes())
+}
+
+/// Puts the current thread's mock context back into its starting state.
+pub fn reset() {
+  STATE.with(|s| *s.borrow_mut() = MockState::default());
+}
+
+/// Raises a GL error in the current thread's mock context, as if a command
+/// had failed.
+pub fn inject_error(error: GLenum) {
+  with(|s| s.error(error, "error injected by the test"));
+}
+
+/// Sets the extensions that the current thread's mock context reports.
+///
+/// The mock starts out reporting only `GL_KHR_debug`.
+pub fn set_extensions(extensions: &[&str]) {
+  with(|s| {
+    s.extensions =
+      extensions.iter().map(|e| CString::new(*e).unwrap()).collect()
+  });
+}
+
+/// Lets you look at the state of the current thread's mock context.
+///
+/// Don't call GL from within `f`, it will panic.
+pub fn with_state<R>(f: impl FnOnce(&MockState) -> R) -> R {
+  STATE.with(|s| f(&s.borrow()))
+}
+
+impl MockState {
+  /// The errors that are waiting to be returned by `glGetError`.
+  pub fn pending_errors(&self) -> &[GLenum] {
+    &self.errors
+  }
+
+  /// How many objects of the given kind currently exist.
+  ///
+  /// This is handy for checking that your code doesn't leak GL objects.
+  pub fn live_objects(&self, kind: ObjectKind) -> usize {
+    match kind {
+      ObjectKind::Buffer => self.buffers.len(),
+      ObjectKind::Texture => self.textures.len(),
+      ObjectKind::Framebuffer => self.framebuffers.len(),
+      ObjectKind::Renderbuffer => self.renderbuffers.len(),
+      ObjectKind::Sampler => self.samplers.len(),
+      ObjectKind::Query => self.queries.len(),
+      // the default objects don't count
+      ObjectKind::VertexArray => self.vertex_arrays.len() - 1,
+      ObjectKind::TransformFeedback => self.transform_feedbacks.len() - 1,
+      ObjectKind::ProgramPipeline => self.program_pipelines.len(),
+      ObjectKind::Shader => self.shaders.len(),
+      ObjectKind::Program => self.programs.len(),
+      ObjectKind::Sync => self.syncs.len(),
+    }
+  }
+
+  /// The contents of a buffer, if it exists and has had storage allocated.
+  pub fn buffer_data(&self, buffer: GLuint) -> Option<&[u8]> {
+    self.buffers.get(&buffer).map(|b| b.data.as_slice())
+  }
+
+  /// The usage hint given when a buffer's storage was allocated.
+  pub fn buffer_usage(&self, buffer: GLuint) -> Option<GLenum> {
+    self.buffers.get(&buffer).map(|b| b.usage)
+  }
+
+  /// The buffer bound to a (non-indexed) buffer target, or 0.
+  ///
+  /// `GL_ELEMENT_ARRAY_BUFFER` gives the binding of the current vertex array
+  /// object.
+  pub fn bound_buffer(&self, target: GLenum) -> GLuint {
+    if target == GL_ELEMENT_ARRAY_BUFFER {
+      self.vao().element_buffer
+    } else {
+      self.buffer_bindings.get(&target).cloned().unwrap_or(0)
+    }
+  }
+
+  /// The buffer bound to one index of an indexed buffer target, or 0.
+  pub fn bound_buffer_indexed(&self, target: GLenum, index: GLuint) -> GLuint {
+    self.indexed_buffer_bindings.get(&(target, index)).cloned().unwrap_or(0)
+  }
+
+  /// The active texture unit, as an index (so `GL_TEXTURE3` gives 3).
+  pub fn active_texture_unit(&self) -> GLuint {
+    self.active_texture
+  }
+
+  /// The texture bound to a target of the active texture unit, or 0.
+  pub fn bound_texture(&self, target: GLenum) -> GLuint {
+    self
+      .texture_bindings
+      .get(&(self.active_texture, target))
+      .cloned()
+      .unwrap_or(0)
+  }
+
+  /// The width, height, and depth of one level of a texture.
+  ///
+  /// For cube maps give the face as `target`, otherwise give the texture
+  /// target.
+  pub fn texture_level_size(
+    &self, texture: GLuint, target: GLenum, level: GLint,
+  ) -> Option<(GLsizei, GLsizei, GLsizei)> {
+    let l = self.textures.get(&texture)?.levels.get(&(target, level))?;
+    Some((l.width, l.height, l.depth))
+  }
+
+  /// The internal format of one level of a texture.
+  pub fn texture_level_format(
+    &self, texture: GLuint, target: GLenum, level: GLint,
+  ) -> Option<GLenum> {
+    let l = self.textures.get(&texture)?.levels.get(&(target, level))?;
+    Some(l.internal_format)
+  }
+
+  /// The framebuffer bound for drawing, or 0.
+  pub fn draw_framebuffer(&self) -> GLuint {
+    self.draw_framebuffer
+  }
+
+  /// The framebuffer bound for reading, or 0.
+  pub fn read_framebuffer(&self) -> GLuint {
+    self.read_framebuffer
+  }
+
+  /// The bound vertex array object, or 0.
+  pub fn vertex_array(&self) -> GLuint {
+    self.vertex_array
+  }
+
+  /// The program in use, or 0.
+  pub fn current_program(&self) -> GLuint {
+    self.current_program
+  }
+
+  /// The bound program pipeline object, or 0.
+  pub fn program_pipeline(&self) -> GLuint {
+    self.program_pipeline
+  }
+
+  /// The source last given to a shader with `glShaderSource`.
+  pub fn shader_source(&self, shader: GLuint) -> Option<&str> {
+    self.shaders.get(&shader).map(|s| s.source.as_str())
+  }
+
+  /// If a program was successfully linked.
+  pub fn program_linked(&self, program: GLuint) -> bool {
+    self.programs.get(&program).map(|p| p.linked).unwrap_or(false)
+  }
+
+  /// If a capability is enabled.
+  pub fn is_enabled(&self, cap: GLenum) -> bool {
+    self.enabled.contains(&cap)
+  }
+
+  /// The current viewport as `[x, y, width, height]`.
+  pub fn viewport(&self) -> [GLint; 4] {
+    self.viewport
+  }
+
+  /// The current scissor box as `[x, y, width, height]`.
+  pub fn scissor(&self) -> [GLint; 4] {
+    self.scissor
+  }
+
+  /// The current clear color.
+  pub fn clear_color(&self) -> [GLfloat; 4] {
+    self.clear_color
+  }
+
+  /// How many draw calls have been accepted without error.
+  pub fn draw_calls(&self) -> usize {
+    self.draw_calls
+  }
+
+  /// The label of an object, set with `glObjectLabelKHR`.
+  pub fn object_label(&self, identifier: GLenum, name: GLuint) -> Option<&str> {
+    self.labels.get(&(identifier, name)).map(|s| s.as_str())
+  }
+
+  /// The label of a sync object, set with `glObjectPtrLabelKHR`.
+  pub fn sync_label(&self, sync: GLsync) -> Option<&str> {
+    self.sync_labels.get(&(sync as usize)).map(|s| s.as_str())
+  }
+
+  /// The messages of the debug groups that are currently pushed, outermost
+  /// first.
+  pub fn debug_groups(&self) -> impl Iterator<Item = &str> + '_ {
+    self.debug_groups.iter().map(|(_, _, m)| m.as_str())
+  }
+}
+
+// Internal helpers.
+impl MockState {
+  /// Records an error flag, and sends a debug message about it.
+  fn error(&mut self, error: GLenum, message: &str) {
+    if !self.errors.contains(&error) {
+      self.errors.push(error);
+    }
+    self.debug_message(
+      DEBUG_SOURCE_API,
+      DEBUG_TYPE_ERROR,
+      error,
+      DEBUG_SEVERITY_HIGH,
+      message,
+    );
+  }
+
+  fn debug_message_enabled(
+    &self, source: GLenum, ty: GLenum, id: GLuint, severity: GLenum,
+  ) -> bool {
+    let mut enabled = severity != DEBUG_SEVERITY_LOW;
+    for c in self.debug_controls.iter() {
+      let matches = (c.source == GL_DONT_CARE || c.source == source)
+        && (c.ty == GL_DONT_CARE || c.ty == ty)
+        && (c.severity == GL_DONT_CARE || c.severity == severity)
+        && c.ids.as_ref().map(|ids| ids.contains(&id)).unwrap_or(true);
+      if matches {
+        enabled = c.enabled;
+      }
+    }
+    enabled
+  }
+
+  fn debug_message(
+    &mut self, source: GLenum, ty: GLenum, id: GLuint, severity: GLenum,
+    message: &str,
+  ) {
+    if !self.enabled.contains(&DEBUG_OUTPUT)
+      || !self.debug_message_enabled(source, ty, id, severity)
+    {
+      return;
+    }
+    let message = CString::new(message.replace('\0', "")).unwrap();
+    let m = LoggedMessage { source, ty, id, severity, message };
+    if !self.debug_callback.is_null() {
+      self.pending_callbacks.push(m);
+    } else if self.debug_log.len() < MAX_DEBUG_LOGGED_MESSAGES_VALUE {
+      self.debug_log.push_back(m);
+    }
+  }
+
+  fn gen_name(&mut self, kind: ObjectKind) -> GLuint {
+    let next = self.next_name.entry(kind).or_insert(1);
+    let name = *next;
+    *next += 1;
+    name
+  }
+
+  fn vao(&self) -> &VertexArray {
+    &self.vertex_arrays[&self.vertex_array]
+  }
+
+  fn vao_mut(&mut self) -> &mut VertexArray {
+    self.vertex_arrays.get_mut(&self.vertex_array).unwrap()
+  }
+
+  /// The buffer bound to `target`, with errors for invalid targets and for
+  /// nothing being bound.
+  fn target_buffer(
+    &mut self, target: GLenum, cmd: &str,
+  ) -> Option<&mut Buffer> {
+    if !BUFFER_TARGETS.iter().any(|(t, _)| *t == target) {
+      self.error(GL_INVALID_ENUM, &format!("{}: invalid buffer target", cmd));
+      return None;
+    }
+    let name = self.bound_buffer(target);
+    if name == 0 {
+      self.error(
+        GL_INVALID_OPERATION,
+        &format!("{}: no buffer is bound to the target", cmd),
+      );
+      return None;
+    }
+    self.buffers.get_mut(&name)
+  }
+
+  /// The texture bound to `target` on the active unit, with errors for
+  /// invalid targets.
+  fn target_texture(
+    &mut self, target: GLenum, cmd: &str,
+  ) -> Option<&mut Texture> {
+    if !TEXTURE_TARGETS.iter().any(|(t, _)| *t == target) {
+      self.error(GL_INVALID_ENUM, &format!("{}: invalid texture target", cmd));
+      return None;
+    }
+    let name = self.bound_texture(target);
+    // texture 0 is the default texture, which we model as a normal texture.
+    Some(
+      self
+        .textures
+        .entry(name)
+        .or_insert_with(|| Texture { target, ..Texture::default() }),
+    )
+  }
+
+  fn framebuffer_for_target(
+    &mut self, target: GLenum, cmd: &str,
+  ) -> Option<GLuint> {
+    match target {
+      GL_FRAMEBUFFER | GL_DRAW_FRAMEBUFFER => Some(self.draw_framebuffer),
+      GL_READ_FRAMEBUFFER => Some(self.read_framebuffer),
+      _ => {
+        self.error(
+          GL_INVALID_ENUM,
+          &format!("{}: invalid framebuffer target", cmd),
+        );
+        None
+      }
+    }
+  }
+
+  fn check_draw_mode(
+    &mut self, mode: GLenum, count: GLsizei, cmd: &str,
+  ) -> bool {
+    match mode {
+      GL_POINTS | GL_LINE_STRIP | GL_LINE_LOOP | GL_LINES
+      | GL_TRIANGLE_STRIP | GL_TRIANGLE_FAN | GL_TRIANGLES => (),
+      _ => {
+        self.error(GL_INVALID_ENUM, &format!("{}: invalid mode", cmd));
+        return false;
+      }
+    }
+    if count < 0 {
+      self.error(GL_INVALID_VALUE, &format!("{}: negative count", cmd));
+      return false;
+    }
+    if self.draw_framebuffer != 0
+      && self.framebuffer_status(self.draw_framebuffer)
+        != GL_FRAMEBUFFER_COMPLETE
+    {
+      self.error(
+        GL_INVALID_FRAMEBUFFER_OPERATION,
+        &format!("{}: the draw framebuffer is incomplete", cmd),
+      );
+      return false;
+    }
+    true
+  }
+
+  fn framebuffer_status(&self, framebuffer: GLuint) -> GLenum {
+    if framebuffer == 0 {
+      return GL_FRAMEBUFFER_COMPLETE;
+    }
+    match self.framebuffers.get(&framebuffer) {
+      Some(f) if !f.attachments.is_empty() => GL_FRAMEBUFFER_COMPLETE,
+      _ => GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT,
+    }
+  }
+
+  fn delete_shader(&mut self, shader: GLuint) {
+    let attached = self.programs.values().any(|p| p.shaders.contains(&shader));
+    if attached {
+      if let Some(s) = self.shaders.get_mut(&shader) {
+        s.delete_pending = true;
+      }
+    } else {
+      self.shaders.remove(&shader);
+      self.labels.remove(&(SHADER_IDENTIFIER, shader));
+    }
+  }
+
+  fn delete_program(&mut self, program: GLuint) {
+    if self.current_program == program {
+      if let Some(p) = self.programs.get_mut(&program) {
+        p.delete_pending = true;
+      }
+      return;
+    }
+    if let Some(p) = self.programs.remove(&program) {
+      self.labels.remove(&(PROGRAM_IDENTIFIER, program));
+      for shader in p.shaders {
+        if self.shaders.get(&shader).map(|s| s.delete_pending).unwrap_or(false)
+        {
+          self.delete_shader(shader);
+        }
+      }
+    }
+  }
+
+  /// Gets the value(s) of a state variable, for the `glGet*v` commands.
+  fn get(&mut self, pname: GLenum, cmd: &str) -> Option<Vec<f64>> {
+    let ints = |v: &[GLint]| Some(v.iter().map(|&i| f64::from(i)).collect());
+    if let Some((_, v)) = LIMITS.iter().find(|(p, _)| *p == pname) {
+      return ints(v);
+    }
+    if let Some((target, _)) = BUFFER_TARGETS.iter().find(|(_, b)| *b == pname)
+    {
+      return ints(&[self.bound_buffer(*target) as GLint]);
+    }
+    if let Some((target, _)) = TEXTURE_TARGETS.iter().find(|(_, b)| *b == pname)
+    {
+      return ints(&[self.bound_texture(*target) as GLint]);
+    }
+    if let Some(v) = self.pixel_store.get(&pname) {
+      return ints(&[*v]);
+    }
+    if CAPABILITIES.contains(&pname) {
+      return ints(&[self.enabled.contains(&pname) as GLint]);
+    }
+    let v = match pname {
+      GL_ACTIVE_TEXTURE => {
+        ints(&[(GL_TEXTURE0 + self.active_texture) as GLint])
+      }
+      GL_CURRENT_PROGRAM => ints(&[self.current_program as GLint]),
+      GL_DRAW_FRAMEBUFFER_BINDING => ints(&[self.draw_framebuffer as GLint]),
+      GL_READ_FRAMEBUFFER_BINDING => ints(&[self.read_framebuffer as GLint]),
+      GL_RENDERBUFFER_BINDING => ints(&[self.renderbuffer as GLint]),
+      GL_VERTEX_ARRAY_BINDING => ints(&[self.vertex_array as GLint]),
+      GL_TRANSFORM_FEEDBACK_BINDING => {
+        ints(&[self.transform_feedback as GLint])
+      }
+      GL_SAMPLER_BINDING => ints(&[self
+        .sampler_bindings
+        .get(&self.active_texture)
+        .cloned()
+        .unwrap_or(0) as GLint]),
+      GL_VIEWPORT => ints(&self.viewport),
+      GL_SCISSOR_BOX => ints(&self.scissor),
+      GL_COLOR_CLEAR_VALUE => {
+        Some(self.clear_color.iter().map(|&f| f64::from(f)).collect())
+      }
+      GL_DEPTH_CLEAR_VALUE => Some(vec![f64::from(self.clear_depth)]),
+      GL_STENCIL_CLEAR_VALUE => ints(&[self.clear_stencil]),
+      GL_NUM_EXTENSIONS => ints(&[self.extensions.len() as GLint]),
+      GL_COMPRESSED_TEXTURE_FORMATS
+      | GL_PROGRAM_BINARY_FORMATS
+      | GL_SHADER_BINARY_FORMATS => Some(Vec::new()),
+      DEBUG_GROUP_STACK_DEPTH => ints(&[self.debug_groups.len() as GLint + 1]),
+      DEBUG_LOGGED_MESSAGES => ints(&[self.debug_log.len() as GLint]),
+      DEBUG_NEXT_LOGGED_MESSAGE_LENGTH => ints(&[self
+        .debug_log
+        .front()
+        .map(|m| m.message.as_bytes_with_nul().len() as GLint)
+        .unwrap_or(0)]),
+      _ => None,
+    };
+    if v.is_none() {
+      self.error(GL_INVALID_ENUM, &format!("{}: unknown pname", cmd));
+    }
+    v
+  }
+}
+
+/// Copies `s` into a GL output string buffer.
+///
+/// Writes at most `buf_size - 1` bytes plus a null terminator, and puts the
+/// number of bytes written (excluding the null) into `length` if it's not
+/// null.
+unsafe fn write_str_out(
+  s: &str, buf_size: GLsizei, length: *mut GLsizei, out: *mut GLchar,
+) {
+  let mut written = 0;
+  if buf_size > 0 && !out.is_null() {
+    let n = s.len().min(buf_size as usize - 1);
+    core::ptr::copy_nonoverlapping(s.as_ptr() as *const GLchar, out, n);
+    *out.add(n) = 0;
+    written = n;
+  }
+  if !length.is_null() {
+    *length = written as GLsizei;
+  }
+}
+
+/// Reads a GL input string which is either null-terminated (when `length` is
+/// negative) or `length` bytes long.
+unsafe fn read_str_in(s: *const GLchar, length: GLsizei) -> String {
+  if s.is_null() {
+    return String::new();
+  }
+  if length < 0 {
+    CStr::from_ptr(s).to_string_lossy().into_owned()
+  } else {
+    let bytes = core::slice::from_raw_parts(s as *const u8, length as usize);
+    String::from_utf8_lossy(bytes).into_owned()
+  }
+}
+
+unsafe fn write_slice_out<T: Copy>(values: &[T], out: *mut T) {
+  if !out.is_null() {
+    core::ptr::copy_nonoverlapping(values.as_ptr(), out, values.len());
+  }
+}
+
+/// Reads the names given to a `glDelete*` call.
+unsafe fn names_in<'a>(n: GLsizei, names: *const GLuint) -> &'a [GLuint] {
+  if n <= 0 || names.is_null() {
+    &[]
+  } else {
+    core::slice::from_raw_parts(names, n as usize)
+  }
+}
+
+fn gl_bool(b: bool) -> GLboolean {
+  b as GLboolean
+}
+
+fn texture_binding_target(image_target: GLenum) -> GLenum {
+  match image_target {
+    GL_TEXTURE_CUBE_MAP_POSITIVE_X..=GL_TEXTURE_CUBE_MAP_NEGATIVE_Z => {
+      GL_TEXTURE_CUBE_MAP
+    }
+    other => other,
+  }
+}
+
+/// Parses `uniform <type> <name>;` declarations out of GLSL source.
+fn declared_uniforms(source: &str) -> impl Iterator<Item = String> + '_ {
+  source.lines().filter_map(|line| {
+    let line = line.trim();
+    let rest = strip_prefix(line, "uniform ")?;
+    let decl = strip_suffix(rest, ";")?;
+    if decl.contains('{') {
+      return None;
+    }
+    let name = decl.split_whitespace().last()?;
+    Some(name.split('[').next().unwrap().to_string())
+  })
+}
+
+/// Parses `in <type> <name>;` declarations out of vertex shader source.
+fn declared_attribs(source: &str) -> impl Iterator<Item = String> + '_ {
+  source.lines().filter_map(|line| {
+    let line = line.trim();
+    let line = match line.find("in ") {
+      Some(0) => line,
+      Some(i) if line[..i].trim_start().starts_with("layout") => &line[i..],
+      _ => return None,
+    };
+    let decl = strip_suffix(strip_prefix(line, "in ")?, ";")?;
+    Some(decl.split_whitespace().last()?.to_string())
+  })
+}
+
+fn compile(shader: &mut Shader) {
+  let mut log = String::new();
+  let mut errors = 0;
+  for (i, line) in shader.source.lines().enumerate() {
+    if let Some(message) = strip_prefix(line.trim_start(), "#error") {
+      errors += 1;
+      log.push_str(&format!(
+        "ERROR: 0:{}: '#error' : {}\n",
+        i + 1,
+        message.trim()
+      ));
+    }
+  }
+  if errors > 0 {
+    log.push_str(&format!(
+      "ERROR: {} compilation errors.  No code generated.\n",
+      errors
+    ));
+  }
+  shader.compiled = errors == 0;
+  shader.info_log = log;
+}
+
+// // // // //
+// Modeled commands
+// // // // //
+
+extern "system" fn glActiveTexture(texture: GLenum) {
+  with(|s| {
+    let unit = texture.wrapping_sub(GL_TEXTURE0);
+    if (unit as usize) < MAX_TEXTURE_UNITS_VALUE {
+      s.active_texture = unit;
+    } else {
+      s.error(GL_INVALID_ENUM, "glActiveTexture: invalid texture unit");
+    }
+  })
+}
+
+extern "system" fn glAttachShader(program: GLuint, shader: GLuint) {
+  with(|s| {
+    if !s.shaders.contains_key(&shader) {
+      return s.error(GL_INVALID_VALUE, "glAttachShader: unknown shader");
+    }
+    let kind = s.shaders[&shader].kind;
+    let mut already = false;
+    let mut same_kind = false;
+    match s.programs.get(&program) {
+      Some(p) => {
+        for attached in p.shaders.iter() {
+          already |= *attached == shader;
+          same_kind |= s.shaders.get(attached).map(|a| a.kind) == Some(kind);
+        }
+      }
+      None => {
+        return s.error(GL_INVALID_VALUE, "glAttachShader: unknown program")
+      }
+    }
+    if already || same_kind {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glAttachShader: a shader of that type is already attached",
+      );
+    }
+    s.programs.get_mut(&program).unwrap().shaders.push(shader);
+  })
+}
+
+extern "system" fn glBeginQuery(target: GLenum, id: GLuint) {
+  with(|s| {
+    match target {
+      GL_ANY_SAMPLES_PASSED
+      | GL_ANY_SAMPLES_PASSED_CONSERVATIVE
+      | GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN => (),
+      _ => return s.error(GL_INVALID_ENUM, "glBeginQuery: invalid target"),
+    }
+    if s.active_queries.contains_key(&target) {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glBeginQuery: a query is already active for the target",
+      );
+    }
+    match s.queries.get(&id).cloned() {
+      Some(t) if t == 0 || t == target => {
+        s.queries.insert(id, target);
+      }
+      _ => return s.error(GL_INVALID_OPERATION, "glBeginQuery: invalid query"),
+    }
+    s.active_queries.insert(target, id);
+  })
+}
+
+extern "system" fn glEndQuery(target: GLenum) {
+  with(|s| {
+    if s.active_queries.remove(&target).is_none() {
+      s.error(
+        GL_INVALID_OPERATION,
+        "glEndQuery: no query is active for the target",
+      );
+    }
+  })
+}
+
+extern "system" fn glBindAttribLocation(
+  program: GLuint, index: GLuint, name: *const GLchar,
+) {
+  let name = unsafe { read_str_in(name, -1) };
+  with(|s| {
+    if index as usize >= MAX_VERTEX_ATTRIBS_VALUE {
+      return s.error(GL_INVALID_VALUE, "glBindAttribLocation: invalid index");
+    }
+    match s.programs.get_mut(&program) {
+      Some(p) => {
+        p.attrib_bindings.insert(name, index);
+      }
+      None => {
+        s.error(GL_INVALID_VALUE, "glBindAttribLocation: unknown program")
+      }
+    }
+  })
+}
+
+extern "system" fn glBindBuffer(target: GLenum, buffer: GLuint) {
+  with(|s| {
+    if !BUFFER_TARGETS.iter().any(|(t, _)| *t == target) {
+      return s.error(GL_INVALID_ENUM, "glBindBuffer: invalid target");
+    }
+    if buffer != 0 {
+      s.buffers.entry(buffer).or_default();
+    }
+    if target == GL_ELEMENT_ARRAY_BUFFER {
+      s.vao_mut().element_buffer = buffer;
+    } else {
+      s.buffer_bindings.insert(target, buffer);
+    }
+  })
+}
+
+fn bind_buffer_indexed(
+  s: &mut MockState, target: GLenum, index: GLuint, buffer: GLuint, cmd: &str,
+) {
+  let max = match target {
+    GL_UNIFORM_BUFFER => MAX_UNIFORM_BUFFER_BINDINGS_VALUE,
+    GL_TRANSFORM_FEEDBACK_BUFFER => MAX_TRANSFORM_FEEDBACK_BINDINGS_VALUE,
+    _ => return s.error(GL_INVALID_ENUM, &format!("{}: invalid target", cmd)),
+  };
+  if index as usize >= max {
+    return s.error(GL_INVALID_VALUE, &format!("{}: invalid index", cmd));
+  }
+  if buffer != 0 {
+    s.buffers.entry(buffer).or_default();
+  }
+  s.indexed_buffer_bindings.insert((target, index), buffer);
+  s.buffer_bindings.insert(target, buffer);
+}
+
+extern "system" fn glBindBufferBase(
+  target: GLenum, index: GLuint, buffer: GLuint,
+) {
+  with(|s| bind_buffer_indexed(s, target, index, buffer, "glBindBufferBase"))
+}
+
+extern "system" fn glBindBufferRange(
+  target: GLenum, index: GLuint, buffer: GLuint, offset: GLintptr,
+  size: GLsizeiptr,
+) {
+  with(|s| {
+    if buffer != 0 && (offset < 0 || size <= 0) {
+      return s.error(GL_INVALID_VALUE, "glBindBufferRange: invalid range");
+    }
+    bind_buffer_indexed(s, target, index, buffer, "glBindBufferRange")
+  })
+}
+
+extern "system" fn glBindFramebuffer(target: GLenum, framebuffer: GLuint) {
+  with(|s| {
+    if framebuffer != 0 {
+      s.framebuffers.entry(framebuffer).or_default();
+    }
+    match target {
+      GL_FRAMEBUFFER => {
+        s.draw_framebuffer = framebuffer;
+        s.read_framebuffer = framebuffer;
+      }
+      GL_DRAW_FRAMEBUFFER => s.draw_framebuffer = framebuffer,
+      GL_READ_FRAMEBUFFER => s.read_framebuffer = framebuffer,
+      _ => s.error(GL_INVALID_ENUM, "glBindFramebuffer: invalid target"),
+    }
+  })
+}
+
+extern "system" fn glBindProgramPipeline(pipeline: GLuint) {
+  with(|s| {
+    if pipeline != 0 && !s.program_pipelines.contains(&pipeline) {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glBindProgramPipeline: unknown program pipeline",
+      );
+    }
+    s.program_pipeline = pipeline;
+  })
+}
+
+extern "system" fn glBindRenderbuffer(target: GLenum, renderbuffer: GLuint) {
+  with(|s| {
+    if target != GL_RENDERBUFFER {
+      return s.error(GL_INVALID_ENUM, "glBindRenderbuffer: invalid target");
+    }
+    if renderbuffer != 0 {
+      s.renderbuffers.entry(renderbuffer).or_default();
+    }
+    s.renderbuffer = renderbuffer;
+  })
+}
+
+extern "system" fn glBindSampler(unit: GLuint, sampler: GLuint) {
+  with(|s| {
+    if unit as usize >= MAX_TEXTURE_UNITS_VALUE {
+      return s.error(GL_INVALID_VALUE, "glBindSampler: invalid unit");
+    }
+    if sampler != 0 && !s.samplers.contains_key(&sampler) {
+      return s.error(GL_INVALID_OPERATION, "glBindSampler: unknown sampler");
+    }
+    s.sampler_bindings.insert(unit, sampler);
+  })
+}
+
+extern "system" fn glBindTexture(target: GLenum, texture: GLuint) {
+  with(|s| {
+    if !TEXTURE_TARGETS.iter().any(|(t, _)| *t == target) {
+      return s.error(GL_INVALID_ENUM, "glBindTexture: invalid target");
+    }
+    if texture != 0 {
+      let t = s.textures.entry(texture).or_default();
+      if t.target == 0 {
+        t.target = target;
+      } else if t.target != target {
+        return s.error(
+          GL_INVALID_OPERATION,
+          "glBindTexture: texture was previously bound to another target",
+        );
+      }
+    }
+    let unit = s.active_texture;
+    s.texture_bindings.insert((unit, target), texture);
+  })
+}
+
+extern "system" fn glBindTransformFeedback(target: GLenum, id: GLuint) {
+  with(|s| {
+    if target != GL_TRANSFORM_FEEDBACK {
+      return s
+        .error(GL_INVALID_ENUM, "glBindTransformFeedback: invalid target");
+    }
+    if !s.transform_feedbacks.contains(&id) {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glBindTransformFeedback: unknown transform feedback",
+      );
+    }
+    s.transform_feedback = id;
+  })
+}
+
+extern "system" fn glBindVertexArray(array: GLuint) {
+  with(|s| {
+    if !s.vertex_arrays.contains_key(&array) {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glBindVertexArray: unknown vertex array",
+      );
+    }
+    s.vertex_array = array;
+  })
+}
+
+extern "system" fn glBufferData(
+  target: GLenum, size: GLsizeiptr, data: *const c_void, usage: GLenum,
+) {
+  with(|s| {
+    match usage {
+      GL_STREAM_DRAW | GL_STREAM_READ | GL_STREAM_COPY | GL_STATIC_DRAW
+      | GL_STATIC_READ | GL_STATIC_COPY | GL_DYNAMIC_DRAW | GL_DYNAMIC_READ
+      | GL_DYNAMIC_COPY => (),
+      _ => return s.error(GL_INVALID_ENUM, "glBufferData: invalid usage"),
+    }
+    if size < 0 {
+      return s.error(GL_INVALID_VALUE, "glBufferData: negative size");
+    }
+    if let Some(b) = s.target_buffer(target, "glBufferData") {
+      b.data = if data.is_null() {
+        vec![0; size as usize]
+      } else {
+        unsafe { core::slice::from_raw_parts(data as *const u8, size as usize) }
+          .to_vec()
+      };
+      b.usage = usage;
+      b.mapping = None;
+    }
+  })
+}
+
+extern "system" fn glBufferSubData(
+  target: GLenum, offset: GLintptr, size: GLsizeiptr, data: *const c_void,
+) {
+  with(|s| {
+    if offset < 0 || size < 0 {
+      return s.error(GL_INVALID_VALUE, "glBufferSubData: negative range");
+    }
+    let err = match s.target_buffer(target, "glBufferSubData") {
+      None => return,
+      Some(ref b) if b.mapping.is_some() => {
+        Some((GL_INVALID_OPERATION, "glBufferSubData: buffer is mapped"))
+      }
+      Some(ref b) if (offset + size) as usize > b.data.len() => {
+        Some((GL_INVALID_VALUE, "glBufferSubData: range is out of bounds"))
+      }
+      Some(b) => {
+        if !data.is_null() {
+          let src = unsafe {
+            core::slice::from_raw_parts(data as *const u8, size as usize)
+          };
+          b.data[offset as usize..(offset + size) as usize]
+            .copy_from_slice(src);
+        }
+        None
+      }
+    };
+    if let Some((e, m)) = err {
+      s.error(e, m)
+    }
+  })
+}
+
+extern "system" fn glCheckFramebufferStatus(target: GLenum) -> GLenum {
+  with(|s| match s.framebuffer_for_target(target, "glCheckFramebufferStatus") {
+    Some(f) => s.framebuffer_status(f),
+    None => 0,
+  })
+}
+
+extern "system" fn glClear(mask: GLbitfield) {
+  with(|s| {
+    let valid =
+      GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT | GL_STENCIL_BUFFER_BIT;
+    if mask & !valid != 0 {
+      s.error(GL_INVALID_VALUE, "glClear: invalid mask bits");
+    }
+  })
+}
+
+extern "system" fn glClearColor(
+  red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat,
+) {
+  with(|s| s.clear_color = [red, green, blue, alpha])
+}
+
+extern "system" fn glClearDepthf(d: GLfloat) {
+  with(|s| s.clear_depth = d.max(0.0).min(1.0))
+}
+
+extern "system" fn glClearStencil(stencil: GLint) {
+  with(|s| s.clear_stencil = stencil)
+}
+
+extern "system" fn glClientWaitSync(
+  sync: GLsync, flags: GLbitfield, _timeout: GLuint64,
+) -> GLenum {
+  with(|s| {
+    if !s.syncs.contains(&(sync as usize)) {
+      s.error(GL_INVALID_VALUE, "glClientWaitSync: unknown sync");
+      return GL_WAIT_FAILED;
+    }
+    if flags & !GL_SYNC_FLUSH_COMMANDS_BIT != 0 {
+      s.error(GL_INVALID_VALUE, "glClientWaitSync: invalid flags");
+      return GL_WAIT_FAILED;
+    }
+    // the mock has no GPU, so all work is always already done.
+    GL_ALREADY_SIGNALED
+  })
+}
+
+extern "system" fn glCompileShader(shader: GLuint) {
+  with(|s| match s.shaders.get_mut(&shader) {
+    Some(sh) => compile(sh),
+    None => s.error(GL_INVALID_VALUE, "glCompileShader: unknown shader"),
+  })
+}
+
+extern "system" fn glCopyBufferSubData(
+  readTarget: GLenum, writeTarget: GLenum, readOffset: GLintptr,
+  writeOffset: GLintptr, size: GLsizeiptr,
+) {
+  with(|s| {
+    if readOffset < 0 || writeOffset < 0 || size < 0 {
+      return s.error(GL_INVALID_VALUE, "glCopyBufferSubData: negative range");
+    }
+    let src = match s.target_buffer(readTarget, "glCopyBufferSubData") {
+      Some(b) => {
+        let (start, end) = (readOffset as usize, (readOffset + size) as usize);
+        b.data.get(start..end).map(|d| d.to_vec())
+      }
+      None => return,
+    };
+    let src = match src {
+      Some(src) => src,
+      None => {
+        return s.error(
+          GL_INVALID_VALUE,
+          "glCopyBufferSubData: read range is out of bounds",
+        )
+      }
+    };
+    let ok = match s.target_buffer(writeTarget, "glCopyBufferSubData") {
+      Some(b) => {
+        let (start, end) =
+          (writeOffset as usize, (writeOffset + size) as usize);
+        match b.data.get_mut(start..end) {
+          Some(dst) => {
+            dst.copy_from_slice(&src);
+            true
+          }
+          None => false,
+        }
+      }
+      None => return,
+    };
+    if !ok {
+      s.error(
+        GL_INVALID_VALUE,
+        "glCopyBufferSubData: write range is out of bounds",
+      )
+    }
+  })
+}
+
+extern "system" fn glCreateProgram() -> GLuint {
+  with(|s| {
+    // shaders and programs share a namespace
+    let name = s.gen_name(ObjectKind::Shader);
+    s.programs.insert(name, Program::default());
+    name
+  })
+}
+
+extern "system" fn glCreateShader(type_: GLenum) -> GLuint {
+  with(|s| {
+    if type_ != GL_VERTEX_SHADER && type_ != GL_FRAGMENT_SHADER {
+      s.error(GL_INVALID_ENUM, "glCreateShader: invalid shader type");
+      return 0;
+    }
+    let name = s.gen_name(ObjectKind::Shader);
+    s.shaders.insert(name, Shader { kind: type_, ..Shader::default() });
+    name
+  })
+}
+
+extern "system" fn glDebugMessageCallbackKHR(
+  callback: GLDEBUGPROCKHR, userParam: *const c_void,
+) {
+  with(|s| {
+    s.debug_callback =
+      unsafe { transmute::<GLDEBUGPROCKHR, *mut c_void>(callback) };
+    s.debug_user_param = userParam as *mut c_void;
+  })
+}
+
+extern "system" fn glDebugMessageControlKHR(
+  source: GLenum, type_: GLenum, severity: GLenum, count: GLsizei,
+  ids: *const GLuint, enabled: GLboolean,
+) {
+  let ids = unsafe { names_in(count, ids) }.to_vec();
+  with(|s| {
+    if count < 0 {
+      return s
+        .error(GL_INVALID_VALUE, "glDebugMessageControlKHR: negative count");
+    }
+    if count > 0
+      && (source == GL_DONT_CARE
+        || type_ == GL_DONT_CARE
+        || severity != GL_DONT_CARE)
+    {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glDebugMessageControlKHR: ids need a specific source and type",
+      );
+    }
+    s.debug_controls.push(DebugControl {
+      source,
+      ty: type_,
+      severity,
+      ids: if count > 0 { Some(ids) } else { None },
+      enabled: enabled != 0,
+    });
+  })
+}
+
+extern "system" fn glDebugMessageInsertKHR(
+  source: GLenum, type_: GLenum, id: GLuint, severity: GLenum, length: GLsizei,
+  buf: *const GLchar,
+) {
+  let message = unsafe { read_str_in(buf, length) };
+  with(|s| {
+    if source != DEBUG_SOURCE_APPLICATION && source != DEBUG_SOURCE_THIRD_PARTY
+    {
+      return s
+        .error(GL_INVALID_ENUM, "glDebugMessageInsertKHR: invalid source");
+    }
+    if message.len() >= MAX_DEBUG_MESSAGE_LENGTH_VALUE {
+      return s.error(
+        GL_INVALID_VALUE,
+        "glDebugMessageInsertKHR: message is too long",
+      );
+    }
+    s.debug_message(source, type_, id, severity, &message);
+  })
+}
+
+macro_rules! delete_names {
+  ($fn_name:ident, $n:ident, $names:ident, |$s:ident, $name:ident| $body:expr) => {
+    extern "system" fn $fn_name($n: GLsizei, $names: *const GLuint) {
+      let names = unsafe { names_in($n, $names) };
+      with(|$s| {
+        if $n < 0 {
+          return $s.error(
+            GL_INVALID_VALUE,
+            concat!(stringify!($fn_name), ": negative count"),
+          );
+        }
+        for &$name in names.iter().filter(|&&n| n != 0) {
+          $body
+        }
+      })
+    }
+  };
+}
+
+delete_names!(glDeleteBuffers, n, buffers, |s, name| {
+  if s.buffers.remove(&name).is_some() {
+    s.labels.remove(&(BUFFER_IDENTIFIER, name));
+    for bound in s.buffer_bindings.values_mut() {
+      if *bound == name {
+        *bound = 0;
+      }
+    }
+    for bound in s.indexed_buffer_bindings.values_mut() {
+      if *bound == name {
+        *bound = 0;
+      }
+    }
+    let vao = s.vao_mut();
+    if vao.element_buffer == name {
+      vao.element_buffer = 0;
+    }
+    for attrib in vao.attribs.iter_mut() {
+      if attrib.buffer == name {
+        attrib.buffer = 0;
+      }
+    }
+  }
+});
+
+delete_names!(glDeleteFramebuffers, n, framebuffers, |s, name| {
+  if s.framebuffers.remove(&name).is_some() {
+    s.labels.remove(&(GL_FRAMEBUFFER, name));
+    if s.draw_framebuffer == name {
+      s.draw_framebuffer = 0;
+    }
+    if s.read_framebuffer == name {
+      s.read_framebuffer = 0;
+    }
+  }
+});
+
+delete_names!(glDeleteProgramPipelines, n, pipelines, |s, name| {
+  if s.program_pipelines.remove(&name) {
+    s.labels.remove(&(PROGRAM_PIPELINE_IDENTIFIER, name));
+    if s.program_pipeline == name {
+      s.program_pipeline = 0;
+    }
+  }
+});
+
+delete_names!(glDeleteQueries, n, ids, |s, name| {
+  if s.queries.remove(&name).is_some() {
+    s.labels.remove(&(QUERY_IDENTIFIER, name));
+    s.active_queries.retain(|_, q| *q != name);
+  }
+});
+
+delete_names!(glDeleteRenderbuffers, n, renderbuffers, |s, name| {
+  if s.renderbuffers.remove(&name).is_some() {
+    s.labels.remove(&(GL_RENDERBUFFER, name));
+    if s.renderbuffer == name {
+      s.renderbuffer = 0;
+    }
+    for f in s.framebuffers.values_mut() {
+      f.attachments.retain(|_, a| *a != Attachment::Renderbuffer(name));
+    }
+  }
+});
+
+delete_names!(glDeleteSamplers, count, samplers, |s, name| {
+  if s.samplers.remove(&name).is_some() {
+    s.labels.remove(&(SAMPLER_IDENTIFIER, name));
+    for bound in s.sampler_bindings.values_mut() {
+      if *bound == name {
+        *bound = 0;
+      }
+    }
+  }
+});
+
+delete_names!(glDeleteTextures, n, textures, |s, name| {
+  if s.textures.remove(&name).is_some() {
+    s.labels.remove(&(GL_TEXTURE, name));
+    for bound in s.texture_bindings.values_mut() {
+      if *bound == name {
+        *bound = 0;
+      }
+    }
+    for f in s.framebuffers.values_mut() {
+      f.attachments.retain(|_, a| match a {
+        Attachment::Texture(t, _) => *t != name,
+        _ => true,
+      });
+    }
+  }
+});
+
+delete_names!(glDeleteTransformFeedbacks, n, ids, |s, name| {
+  if s.transform_feedback == name {
+    return s.error(
+      GL_INVALID_OPERATION,
+      "glDeleteTransformFeedbacks: transform feedback is bound",
+    );
+  }
+  if s.transform_feedbacks.remove(&name) {
+    s.labels.remove(&(GL_TRANSFORM_FEEDBACK, name));
+  }
+});
+
+delete_names!(glDeleteVertexArrays, n, arrays, |s, name| {
+  if s.vertex_arrays.remove(&name).is_some() {
+    s.labels.remove(&(VERTEX_ARRAY_IDENTIFIER, name));
+    if s.vertex_array == name {
+      s.vertex_array = 0;
+    }
+  }
+});
+
+extern "system" fn glDeleteProgram(program: GLuint) {
+  with(|s| {
+    if program == 0 {
+      return;
+    }
+    if !s.programs.contains_key(&program) {
+      return s.error(GL_INVALID_VALUE, "glDeleteProgram: unknown program");
+    }
+    s.delete_program(program)
+  })
+}
+
+extern "system" fn glDeleteShader(shader: GLuint) {
+  with(|s| {
+    if shader == 0 {
+      return;
+    }
+    if !s.shaders.contains_key(&shader) {
+      return s.error(GL_INVALID_VALUE, "glDeleteShader: unknown shader");
+    }
+    s.delete_shader(shader)
+  })
+}
+
+extern "system" fn glDeleteSync(sync: GLsync) {
+  with(|s| {
+    if sync.is_null() {
+      return;
+    }
+    if s.syncs.remove(&(sync as usize)) {
+      s.sync_labels.remove(&(sync as usize));
+    } else {
+      s.error(GL_INVALID_VALUE, "glDeleteSync: unknown sync")
+    }
+  })
+}
+
+extern "system" fn glDetachShader(program: GLuint, shader: GLuint) {
+  with(|s| {
+    let p = match s.programs.get_mut(&program) {
+      Some(p) => p,
+      None => {
+        return s.error(GL_INVALID_VALUE, "glDetachShader: unknown program")
+      }
+    };
+    match p.shaders.iter().position(|&a| a == shader) {
+      Some(i) => {
+        p.shaders.remove(i);
+      }
+      None => {
+        return s.error(
+          GL_INVALID_OPERATION,
+          "glDetachShader: shader is not attached",
+        )
+      }
+    }
+    if s.shaders.get(&shader).map(|sh| sh.delete_pending).unwrap_or(false) {
+      s.delete_shader(shader);
+    }
+  })
+}
+
+fn set_capability(cap: GLenum, enabled: bool, cmd: &str) {
+  with(|s| {
+    if !CAPABILITIES.contains(&cap) {
+      return s.error(GL_INVALID_ENUM, &format!("{}: invalid capability", cmd));
+    }
+    if enabled {
+      s.enabled.insert(cap);
+    } else {
+      s.enabled.remove(&cap);
+    }
+  })
+}
+
+extern "system" fn glDisable(cap: GLenum) {
+  set_capability(cap, false, "glDisable")
+}
+
+extern "system" fn glEnable(cap: GLenum) {
+  set_capability(cap, true, "glEnable")
+}
+
+extern "system" fn glIsEnabled(cap: GLenum) -> GLboolean {
+  with(|s| {
+    if !CAPABILITIES.contains(&cap) {
+      s.error(GL_INVALID_ENUM, "glIsEnabled: invalid capability");
+    }
+    gl_bool(s.enabled.contains(&cap))
+  })
+}
+
+fn set_attrib_array(index: GLuint, enabled: bool, cmd: &str) {
+  with(|s| {
+    if index as usize >= MAX_VERTEX_ATTRIBS_VALUE {
+      return s.error(GL_INVALID_VALUE, &format!("{}: invalid index", cmd));
+    }
+    s.vao_mut().attribs[index as usize].enabled = enabled;
+  })
+}
+
+extern "system" fn glDisableVertexAttribArray(index: GLuint) {
+  set_attrib_array(index, false, "glDisableVertexAttribArray")
+}
+
+extern "system" fn glEnableVertexAttribArray(index: GLuint) {
+  set_attrib_array(index, true, "glEnableVertexAttribArray")
+}
+
+fn draw(mode: GLenum, count: GLsizei, instances: GLsizei, cmd: &str) {
+  with(|s| {
+    if instances < 0 {
+      return s
+        .error(GL_INVALID_VALUE, &format!("{}: negative instances", cmd));
+    }
+    if s.check_draw_mode(mode, count, cmd) {
+      s.draw_calls += 1;
+    }
+  })
+}
+
+fn draw_elements(
+  mode: GLenum, count: GLsizei, type_: GLenum, instances: GLsizei, cmd: &str,
+) {
+  match type_ {
+    GL_UNSIGNED_BYTE | GL_UNSIGNED_SHORT | GL_UNSIGNED_INT => {
+      draw(mode, count, instances, cmd)
+    }
+    _ => with(|s| s.error(GL_INVALID_ENUM, &format!("{}: invalid type", cmd))),
+  }
+}
+
+extern "system" fn glDrawArrays(mode: GLenum, _first: GLint, count: GLsizei) {
+  draw(mode, count, 1, "glDrawArrays")
+}
+
+extern "system" fn glDrawArraysInstanced(
+  mode: GLenum, _first: GLint, count: GLsizei, instancecount: GLsizei,
+) {
+  draw(mode, count, instancecount, "glDrawArraysInstanced")
+}
+
+extern "system" fn glDrawElements(
+  mode: GLenum, count: GLsizei, type_: GLenum, _indices: *const c_void,
+) {
+  draw_elements(mode, count, type_, 1, "glDrawElements")
+}
+
+extern "system" fn glDrawElementsInstanced(
+  mode: GLenum, count: GLsizei, type_: GLenum, _indices: *const c_void,
+  instancecount: GLsizei,
+) {
+  draw_elements(mode, count, type_, instancecount, "glDrawElementsInstanced")
+}
+
+extern "system" fn glDrawRangeElements(
+  mode: GLenum, start: GLuint, end: GLuint, count: GLsizei, type_: GLenum,
+  _indices: *const c_void,
+) {
+  if end < start {
+    return with(|s| {
+      s.error(GL_INVALID_VALUE, "glDrawRangeElements: end is less than start")
+    });
+  }
+  draw_elements(mode, count, type_, 1, "glDrawRangeElements")
+}
+
+extern "system" fn glFenceSync(condition: GLenum, flags: GLbitfield) -> GLsync {
+  with(|s| {
+    if condition != GL_SYNC_GPU_COMMANDS_COMPLETE {
+      s.error(GL_INVALID_ENUM, "glFenceSync: invalid condition");
+      return null_mut();
+    }
+    if flags != 0 {
+      s.error(GL_INVALID_VALUE, "glFenceSync: flags must be zero");
+      return null_mut();
+    }
+    // syncs are pointers, so we make up addresses that are well aligned and
+    // far away from null.
+    let id = s.gen_name(ObjectKind::Sync) as usize;
+    let sync = 0x1000 + id * 16;
+    s.syncs.insert(sync);
+    sync as GLsync
+  })
+}
+
+extern "system" fn glFramebufferRenderbuffer(
+  target: GLenum, attachment: GLenum, renderbuffertarget: GLenum,
+  renderbuffer: GLuint,
+) {
+  with(|s| {
+    let cmd = "glFramebufferRenderbuffer";
+    let f = match s.framebuffer_for_target(target, cmd) {
+      Some(f) => f,
+      None => return,
+    };
+    if renderbuffertarget != GL_RENDERBUFFER {
+      return s
+        .error(GL_INVALID_ENUM, "glFramebufferRenderbuffer: invalid target");
+    }
+    if f == 0 {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glFramebufferRenderbuffer: the default framebuffer is bound",
+      );
+    }
+    if renderbuffer != 0 && !s.renderbuffers.contains_key(&renderbuffer) {
+      return s.error(
+        GL_INVALID_OPERATION,
+        "glFramebufferRenderbuffer: unknown renderbuffer",
+      );
+    }
+    let fb = s.framebuffers.get_mut(&f).unwrap();
+    if renderbuffer == 0 {
+      fb.attachments.remove(&attachment);
+    } else {
+      fb.attachments.insert(attachment, Attachment::Renderbuffer(renderbuffer));
+    }
+  })
+}
+
+fn framebuffer_texture(
+  target: GLenum, attachment: GLenum, texture: GLuint, level: GLint, cmd: &str,
+) {
+  with(|s| {
+    let f = match s.framebuffer_for_target(target, cmd) {
+      Some(f) => f,
+      None => return,
+    };
+    if f == 0 {
+      return s.error(
+        GL_INVALID_OPERATION,
+        &format!("{}: the default framebuffer is bound", cmd),
+      );
+    }
+    if texture != 0 && !s.textures.contains_key(&texture) {
+      return s
+        .error(GL_INVALID_OPERATION, &format!("{}: unknown texture", cmd));
+    }
+    let fb = s.framebuffers.get_mut(&f).unwrap();
+    if texture == 0 {
+      fb.attachments.remove(&attachment);
+    } else {
+      fb.attachments.insert(attachment, Attachment::Texture(texture, level));
+    }
+  })
+}
+
+extern "system" fn glFramebufferTexture2D(
+  target: GLenum, attachment: GLenum, _textarget: GLenum, texture: GLuint,
+  level: GLint,
+) {
+  framebuffer_texture(
+    target,
+    attachment,
+    texture,
+    level,
+    "glFramebufferTexture2D",
+  )
+}
+
+extern "system" fn glFramebufferTextureLayer(
+  target: GLenum, attachment: GLenum, texture: GLuint, level: GLint,
+  _layer: GLint,
+) {
+  framebuffer_texture(
+    target,
+    attachment,
+    texture,
+    level,
+    "glFramebufferTextureLayer",
+  )
+}
+
+macro_rules! gen_names {
+  ($fn_name:ident, $kind:expr, |$s:ident, $name:ident| $insert:expr) => {
+    extern "system" fn $fn_name(n: GLsizei, names: *mut GLuint) {
+      with(|$s| {
+        if n < 0 {
+          return $s.error(
+            GL_INVALID_VALUE,
+            concat!(stringify!($fn_name), ": negative count"),
+          );
+        }
+        for i in 0..n as usize {
+          let $name = $s.gen_name($kind);
+          $insert;
+          unsafe { *names.add(i) = $name };
+        }
+      })
+    }
+  };
+}
+
+gen_names!(glGenBuffers, ObjectKind::Buffer, |s, name| {
+  s.buffers.insert(name, Buffer::default())
+});
+gen_names!(glGenFramebuffers, ObjectKind::Framebuffer, |s, name| {
+  s.framebuffers.insert(name, Framebuffer::default())
+});
+gen_names!(glGenProgramPipelines, ObjectKind::ProgramPipeline, |s, name| {
+  s.program_pipelines.insert(name)
+});
+gen_names!(glGenQueries, ObjectKind::Query, |s, name| {
+  s.queries.insert(name, 0)
+});
+gen_names!(glGenRenderbuffers, ObjectKind::Renderbuffer, |s, name| {
+  s.renderbuffers.insert(name, Renderbuffer::default())
+});
+gen_names!(glGenSamplers, ObjectKind::Sampler, |s, name| {
+  s.samplers.insert(name, HashMap::new())
+});
+gen_names!(glGenTextures, ObjectKind::Texture, |s, name| {
+  s.textures.insert(name, Texture::default())
+});
+gen_names!(
+  glGenTransformFeedbacks,
+  ObjectKind::TransformFeedback,
+  |s, name| { s.transform_feedbacks.insert(name) }
+);
+gen_names!(glGenVertexArrays, ObjectKind::VertexArray, |s, name| {
+  s.vertex_arrays.insert(name, VertexArray::default())
+});
+
+extern "system" fn glGenerateMipmap(target: GLenum) {
+  with(|s| {
+    let t = match s.target_texture(target, "glGenerateMipmap") {
+      Some(t) => t,
+      None => return,
+    };
+    let faces: Vec<GLenum> = if target == GL_TEXTURE_CUBE_MAP {
+      (GL_TEXTURE_CUBE_MAP_POSITIVE_X..=GL_TEXTURE_CUBE_MAP_NEGATIVE_Z)
+        .collect()
+    } else {
+      vec![target]
+    };
+    let mut missing_base = false;
+    for face in faces {
+      let base = match t.levels.get(&(face, 0)) {
+        Some(base) => *base,
+        None => {
+          missing_base = true;
+          continue;
+        }
+      };
+      let mut level = base;
+      let mut i = 0;
+      while level.width > 1 || level.height > 1 || level.depth > 1 {
+        i += 1;
+        level.width = (level.width / 2).max(1);
+        level.height = (level.height / 2).max(1);
+        if target == GL_TEXTURE_3D {
+          level.depth = (level.depth / 2).max(1);
+        }
+        t.levels.insert((face, i), level);
+        if target != GL_TEXTURE_3D && level.width == 1 && level.height == 1 {
+          break;
+        }
+      }
+    }
+    if missing_base {
+      s.error(
+        GL_INVALID_OPERATION,
+        "glGenerateMipmap: the base level has no image",
+      );
+    }
+  })
+}
+
+extern "system" fn glGetAttribLocation(
+  program: GLuint, name: *const GLchar,
+) -> GLint {
+  let name = unsafe { read_str_in(name, -1) };
+  with(|s| match s.programs.get(&program) {
+    Some(p) if p.linked => p.attribs.get(&name).cloned().unwrap_or(-1),
+    Some(_) => {
+      s.error(
+        GL_INVALID_OPERATION,
+        "glGetAttribLocation: program isn't linked",
+      );
+      -1
+    }
+    None => {
+      s.error(GL_INVALID_VALUE, "glGetAttribLocation: unknown program");
+      -1
+    }
+  })
+}
+
+extern "system" fn glGetUniformLocation(
+  program: GLuint, name: *const GLchar,
+) -> GLint {
+  let name = unsafe { read_str_in(name, -1) };
+  with(|s| match s.programs.get(&program) {
+    Some(p) if p.linked => p.uniforms.get(&name).cloned().unwrap_or(-1),
+    Some(_) => {
+      s.error(
+        GL_INVALID_OPERATION,
+        "glGetUniformLocation: program isn't linked",
+      );
+      -1
+    }
+    None => {
+      s.error(GL_INVALID_VALUE, "glGetUniformLocation: unknown program");
+      -1
+    }
+  })
+}
+
+extern "system" fn glGetBooleanv(pname: GLenum, data: *mut GLboolean) {
+  if let Some(v) = with(|s| s.get(pname, "glGetBooleanv")) {
+    let v: Vec<GLboolean> = v.iter().map(|&f| gl_bool(f != 0.0)).collect();
+    unsafe { write_slice_out(&v, data) }
+  }
+}
+
+extern "system" fn glGetFloatv(pname: GLenum, data: *mut GLfloat) {
+  if let Some(v) = with(|s| s.get(pname, "glGetFloatv")) {
+    let v: Vec<GLfloat> = v.iter().map(|&f| f as GLfloat).collect();
+    unsafe { write_slice_out(&v, data) }
+  }
+}
+
+extern "system" fn glGetIntegerv(pname: GLenum, data: *mut GLint) {
+  if let Some(v) = with(|s| s.get(pname, "glGetIntegerv")) {
+    let v: Vec<GLint> = v.iter().map(|&f| f.round() as GLint).collect();
+    unsafe { write_slice_out(&v, data) }
+  }
+}
+
+extern "system" fn glGetInteger64v(pname: GLenum, data: *mut GLint64) {
+  if let Some(v) = with(|s| s.get(pname, "glGetInteger64v")) {
+    let v: Vec<GLint64> = v.iter().map(|&f| f.round() as GLint64).collect();
+    unsafe { write_slice_out(&v, data) }
+  }
+}
+
+fn get_indexed(target: GLenum, index: GLuint, cmd: &str) -> Option<GLint64> {
+  with(|s| {
+    let (generic, max) = match target {
+      GL_UNIFORM_BUFFER_BINDING => {
+        (GL_UNIFORM_BUFFER, MAX_UNIFORM_BUFFER_BINDINGS_VALUE)
+      }
+      GL_TRANSFORM_FEEDBACK_BUFFER_BINDING => {
+        (GL_TRANSFORM_FEEDBACK_BUFFER, MAX_TRANSFORM_FEEDBACK_BINDINGS_VALUE)
+      }
+      _ => {
+        s.error(GL_INVALID_ENUM, &format!("{}: invalid target", cmd));
+        return None;
+      }
+    };
+    if index as usize >= max {
+      s.error(GL_INVALID_VALUE, &format!("{}: invalid index", cmd));
+      return None;
+    }
+    Some(GLint64::from(s.bound_buffer_indexed(generic, index)))
+  })
+}
+
+extern "system" fn glGetIntegeri_v(
+  target: GLenum, index: GLuint, data: *mut GLint,
+) {
+  if let Some(v) = get_indexed(target, index, "glGetIntegeri_v") {
+    unsafe { write_slice_out(&[v as GLint], data) }
+  }
+}
+
+extern "system" fn glGetInteger64i_v(
+  target: GLenum, index: GLuint, data: *mut GLint64,
+) {
+  if let Some(v) = get_indexed(target, index, "glGetInteger64i_v") {
+    unsafe { write_slice_out(&[v], data) }
+  }
+}
+
+fn get_buffer_parameter(
+  target: GLenum, pname: GLenum, cmd: &str,
+) -> Option<GLint64> {
+  with(|s| {
+    let b = s.target_buffer(target, cmd)?;
+    let v = match pname {
+      GL_BUFFER_SIZE => b.data.len() as GLint64,
+      GL_BUFFER_USAGE => {
+        GLint64::from(if b.usage == 0 { GL_STATIC_DRAW } else { b.usage })
+      }
+      GL_BUFFER_MAPPED => GLint64::from(b.mapping.is_some() as u8),
+      GL_BUFFER_MAP_OFFSET => b.mapping.map(|m| m.0 as GLint64).unwrap_or(0),
+      GL_BUFFER_MAP_LENGTH => b.mapping.map(|m| m.1 as GLint64).unwrap_or(0),
+      GL_BUFFER_ACCESS_FLAGS => {
+        b.mapping.map(|m| GLint64::from(m.2)).unwrap_or(0)
+      }
+      _ => {
+        s.error(GL_INVALID_ENUM, &format!("{}: invalid pname", cmd));
+        return None;
+      }
+    };
+    Some(v)
+  })
+}
+
+extern "system" fn glGetBufferParameteriv(
+  target: GLenum, pname: GLenum, params: *mut GLint,
+) {
+  if let Some(v) = get_buffer_parameter(target, pname, "glGetBufferParameteriv")
+  {
+    unsafe { write_slice_out(&[v as GLint], params) }
+  }
+}
+
+extern "system" fn glGetBufferParameteri64v(
+  target: GLenum, pname: GLenum, params: *mut GLint64,
+) {
+  if let Some(v) =
+    get_buffer_parameter(target, pname, "glGetBufferParameteri64v")
+  {
+    unsafe { write_slice_out(&[v], params) }
+  }
+}
+
+extern "system" fn glGetBufferPointerv(
+  target: GLenum, pname: GLenum, params: *mut *mut c_void,
+) {
+  let p = with(|s| {
+    if pname != GL_BUFFER_MAP_POINTER {
+      s.error(GL_INVALID_ENUM, "glGetBufferPointerv: invalid pname");
+      return None;
+    }
+    let b = s.target_buffer(target, "glGetBufferPointerv")?;
+    Some(match b.mapping {
+      Some((offset, _, _)) => unsafe {
+        b.data.as_mut_slice().as_mut_ptr().add(offset) as *mut c_void
+      },
+      None => null_mut(),
+    })
+  });
+  if let Some(p) = p {
+    unsafe { write_slice_out(&[p], params) }
+  }
+}
+
+extern "system" fn glGetDebugMessageLogKHR(
+  count: GLuint, bufSize: GLsizei, sources: *mut GLenum, types: *mut GLenum,
+  ids: *mut GLuint, severities: *mut GLenum, lengths: *mut GLsizei,
+  messageLog: *mut GLchar,
+) -> GLuint {
+  with(|s| {
+    if bufSize < 0 && !messageLog.is_null() {
+      s.error(GL_INVALID_VALUE, "glGetDebugMessageLogKHR: negative bufSize");
+      return 0;
+    }
+    let mut written = 0;
+    let mut used = 0_usize;
+    while written < count {
+      let m = match s.debug_log.front() {
+        Some(m) => m,
+        None => break,
+      };
+      let bytes = m.message.as_bytes_with_nul();
+      if !messageLog.is_null() {
+        if used + bytes.len() > bufSize as usize {
+          break;
+        }
+        unsafe {
+          core::ptr::copy_nonoverlapping(
+            bytes.as_ptr() as *const GLchar,
+            messageLog.add(used),
+            bytes.len(),
+          )
+        };
+      }
+      used += bytes.len();
+      let i = written as usize;
+      unsafe {
+        if !sources.is_null() {
+          *sources.add(i) = m.source;
+        }
+        if !types.is_null() {
+          *types.add(i) = m.ty;
+        }
+        if !ids.is_null() {
+          *ids.add(i) = m.id;
+        }
+        if !severities.is_null() {
+          *severities.add(i) = m.severity;
+        }
+        if !lengths.is_null() {
+          *lengths.add(i) = bytes.len() as GLsizei;
+        }
+      }
+      s.debug_log.pop_front();
+      written += 1;
+    }
+    written
+  })
+}
+
+extern "system" fn glGetError() -> GLenum {
+  with(|s| if s.errors.is_empty() { GL_NO_ERROR } else { s.errors.remove(0) })
+}
+
+extern "system" fn glGetObjectLabelKHR(
+  identifier: GLenum, name: GLuint, bufSize: GLsizei, length: *mut GLsizei,
+  label: *mut GLchar,
+) {
+  let text = with(|s| {
+    if !s.object_exists(identifier, name, "glGetObjectLabelKHR") {
+      return None;
+    }
+    Some(s.labels.get(&(identifier, name)).cloned().unwrap_or_default())
+  });
+  if let Some(text) = text {
+    unsafe { write_str_out(&text, bufSize, length, label) }
+  }
+}
+
+extern "system" fn glGetObjectPtrLabelKHR(
+  ptr: *const c_void, bufSize: GLsizei, length: *mut GLsizei,
+  label: *mut GLchar,
+) {
+  let text = with(|s| {
+    if !s.syncs.contains(&(ptr as usize)) {
+      s.error(GL_INVALID_VALUE, "glGetObjectPtrLabelKHR: unknown sync");
+      return None;
+    }
+    Some(s.sync_labels.get(&(ptr as usize)).cloned().unwrap_or_default())
+  });
+  if let Some(text) = text {
+    unsafe { write_str_out(&text, bufSize, length, label) }
+  }
+}
+
+extern "system" fn glGetPointervKHR(pname: GLenum, params: *mut *mut c_void) {
+  let p = with(|s| match pname {
+    DEBUG_CALLBACK_FUNCTION => Some(s.debug_callback),
+    DEBUG_CALLBACK_USER_PARAM => Some(s.debug_user_param),
+    _ => {
+      s.error(GL_INVALID_ENUM, "glGetPointervKHR: invalid pname");
+      None
+    }
+  });
+  if let Some(p) = p {
+    unsafe { write_slice_out(&[p], params) }
+  }
+}
+
+extern "system" fn glGetProgramInfoLog(
+  program: GLuint, bufSize: GLsizei, length: *mut GLsizei, infoLog: *mut GLchar,
+) {
+  let log = with(|s| match s.programs.get(&program) {
+    Some(p) => Some(p.info_log.clone()),
+    None => {
+      s.error(GL_INVALID_VALUE, "glGetProgramInfoLog: unknown program");
+      None
+    }
+  });
+  if let Some(log) = log {
+    unsafe { write_str_out(&log, bufSize, length, infoLog) }
+  }
+}
+
+extern "system" fn glGetProgramiv(
+  program: GLuint, pname: GLenum, params: *mut GLint,
+) {
+  let v = with(|s| {
+    let p = match s.programs.get(&program) {
+      Some(p) => p,
+      None => {
+        s.error(GL_INVALID_VALUE, "glGetProgramiv: unknown program");
+        return None;
+      }
+    };
+    let log_len = |log: &str| if log.is_empty() { 0 } else { log.len() + 1 };
+    let v = match pname {
+      GL_DELETE_STATUS => p.delete_pending as GLint,
+      GL_LINK_STATUS => p.linked as GLint,
+      GL_VALIDATE_STATUS => p.linked as GLint,
+      GL_INFO_LOG_LENGTH => log_len(&p.info_log) as GLint,
+      GL_ATTACHED_SHADERS => p.shaders.len() as GLint,
+      GL_ACTIVE_ATTRIBUTES => p.attribs.len() as GLint,
+      GL_ACTIVE_UNIFORMS => p.uniforms.len() as GLint,
+      GL_ACTIVE_UNIFORM_BLOCKS
+      | GL_TRANSFORM_FEEDBACK_VARYINGS
+      | GL_PROGRAM_BINARY_RETRIEVABLE_HINT
+      | GL_PROGRAM_BINARY_LENGTH => 0,
+      GL_ACTIVE_ATTRIBUTE_MAX_LENGTH => {
+        p.attribs.keys().map(|k| k.len() as GLint + 1).max().unwrap_or(0)
+      }
+      GL_ACTIVE_UNIFORM_MAX_LENGTH => {
+        p.uniforms.keys().map(|k| k.len() as GLint + 1).max().unwrap_or(0)
+      }
+      GL_ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH
+      | GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH => 0,
+      GL_TRANSFORM_FEEDBACK_BUFFER_MODE => GL_INTERLEAVED_ATTRIBS as GLint,
+      _ => {
+        s.error(GL_INVALID_ENUM, "glGetProgramiv: invalid pname");
+        return None;
+      }
+    };
+    Some(v)
+  });
+  if let Some(v) = v {
+    unsafe { write_slice_out(&[v], params) }
+  }
+}
+
+extern "system" fn glGetShaderInfoLog(
+  shader: GLuint, bufSize: GLsizei, length: *mut GLsizei, infoLog: *mut GLchar,
+) {
+  let log = with(|s| match s.shaders.get(&shader) {
+    Some(sh) => Some(sh.info_log.clone()),
+    None => {
+      s.error(GL_INVALID_VALUE, "glGetShaderInfoLog: unknown shader");
+      None
+    }
+  });
+  if let Some(log) = log {
+    unsafe { write_str_out(&log, bufSize, length, infoLog) }
+  }
+}
+
+extern "system" fn glGetShaderSource(
+  shader: GLuint, bufSize: GLsizei, length: *mut GLsizei, source: *mut GLchar,
+) {
+  let src = with(|s| match s.shaders.get(&shader) {
+    Some(sh) => Some(sh.source.clone()),
+    None => {
+      s.error(GL_INVALID_VALUE, "glGetShaderSource: unknown shader");
+      None
+    }
+  });
+  if let Some(src) = src {
+    unsafe { write_str_out(&src, bufSize, length, source) }
+  }
+}
+
+extern "system" fn glGetShaderiv(
+  shader: GLuint, pname: GLenum, params: *mut GLint,
+) {
+  let v = with(|s| {
+    let sh = match s.shaders.get(&shader) {
+      Some(sh) => sh,
+      None => {
+        s.error(GL_INVALID_VALUE, "glGetShaderiv: unknown shader");
+        return None;
+      }
+    };
+    let text_len =
+      |t: &str| if t.is_empty() { 0 } else { t.len() as GLint + 1 };
+    let v = match pname {
+      GL_SHADER_TYPE => sh.kind as GLint,
+      GL_DELETE_STATUS => sh.delete_pending as GLint,
+      GL_COMPILE_STATUS => sh.compiled as GLint,
+      GL_INFO_LOG_LENGTH => text_len(&sh.info_log),
+      GL_SHADER_SOURCE_LENGTH => text_len(&sh.source),
+      _ => {
+        s.error(GL_INVALID_ENUM, "glGetShaderiv: invalid pname");
+        return None;
+      }
+    };
+    Some(v)
+  });
+  if let Some(v) = v {
+    unsafe { write_slice_out(&[v], params) }
+  }
+}
+
+extern "system" fn glGetString(name: GLenum) -> *const GLubyte {
+  let s: &'static [u8] = match name {
+    GL_VENDOR => b"gles30\0",
+    GL_RENDERER => b"gles30 mock\0",
+    GL_VERSION => b"OpenGL ES 3.0 gles30 mock\0",
+    GL_SHADING_LANGUAGE_VERSION => b"OpenGL ES GLSL ES 3.00 gles30 mock\0",
+    GL_EXTENSIONS => {
+      // extensions are only available through glGetStringi in ES 3.0, but
+      // many drivers still give the old style string too.
+      let joined = with(|s| {
+        let mut all = Vec::new();
+        for e in &s.extensions {
+          if !all.is_empty() {
+            all.push(b' ');
+          }
+          all.extend_from_slice(e.as_bytes());
+        }
+        CString::new(all).unwrap()
+      });
+      // keep the string alive until the next call.
+      return EXTENSIONS_STRING.with(|e| {
+        *e.borrow_mut() = joined;
+        e.borrow().as_ptr() as *const GLubyte
+      });
+    }
+    _ => {
+      with(|s| s.error(GL_INVALID_ENUM, "glGetString: invalid name"));
+      return core::ptr::null();
+    }
+  };
+  s.as_ptr()
+}
+
+thread_local! {
+  static EXTENSIONS_STRING: RefCell<CString> = RefCell::new(CString::default());
+}
+
+extern "system" fn glGetStringi(name: GLenum, index: GLuint) -> *const GLubyte {
+  with(|s| {
+    if name != GL_EXTENSIONS {
+      s.error(GL_INVALID_ENUM, "glGetStringi: invalid name");
+      return core::ptr::null();
+    }
+    match s.extensions.get(index as usize) {
+      Some(e) => e.as_ptr() as *const GLubyte,
+      None => {
+        s.error(GL_INVALID_VALUE, "glGetStringi: index out of range");
+        core::ptr::null()
+      }
+    }
+  })
+}
+
+extern "system" fn glGetSynciv(
+  sync: GLsync, pname: GLenum, count: GLsizei, length: *mut GLsizei,
+  values: *mut GLint,
+) {
+  let v = with(|s| {
+    if !s.syncs.contains(&(sync as usize)) {
+      s.error(GL_INVALID_VALUE, "glGetSynciv: unknown sync");
+      return None;
+    }
+    let v = match pname {
+      GL_OBJECT_TYPE => GL_SYNC_FENCE as GLint,
+      GL_SYNC_STATUS => GL_SIGNALED as GLint,
+      GL_SYNC_CONDITION => GL_SYNC_GPU_COMMANDS_COMPLETE as GLint,
+      GL_SYNC_FLAGS => 0,
+      _ => {
+        s.error(GL_INVALID_ENUM, "glGetSynciv: invalid pname");
+        return None;
+      }
+    };
+    Some(v)
+  });
+  if let Some(v) = v {
+    unsafe {
+      if count > 0 {
+        write_slice_out(&[v], values);
+      }
+      if !length.is_null() {
+        *length = count.min(1);
+      }
+    }
+  }
+}
+
+fn get_tex_parameter(
+  target: GLenum, pname: GLenum, cmd: &str,
+) -> Option<GLfloat> {
+  with(|s| {
+    let t = s.target_texture(target, cmd)?;
+    let v = match pname {
+      GL_TEXTURE_IMMUTABLE_FORMAT => (t.immutable_levels > 0) as u8 as GLfloat,
+      GL_TEXTURE_IMMUTABLE_LEVELS => t.immutable_levels as GLfloat,
+      _ => match t.params.get(&pname) {
+        Some(v) => *v,
+        None => match default_tex_parameter(pname) {
+          Some(v) => v,
+          None => {
+            s.error(GL_INVALID_ENUM, &format!("{}: invalid pname", cmd));
+            return None;
+          }
+        },
+      },
+    };
+    Some(v)
+  })
+}
+
+fn default_tex_parameter(pname: GLenum) -> Option<GLfloat> {
+  Some(match pname {
+    GL_TEXTURE_MIN_FILTER => GL_NEAREST_MIPMAP_LINEAR as GLfloat,
+    GL_TEXTURE_MAG_FILTER => GL_LINEAR as GLfloat,
+    GL_TEXTURE_WRAP_S | GL_TEXTURE_WRAP_T | GL_TEXTURE_WRAP_R => {
+      GL_REPEAT as GLfloat
+    }
+    GL_TEXTURE_MIN_LOD => -1000.0,
+    GL_TEXTURE_MAX_LOD => 1000.0,
+    GL_TEXTURE_BASE_LEVEL => 0.0,
+    GL_TEXTURE_MAX_LEVEL => 1000.0,
+    GL_TEXTURE_COMPARE_MODE => GL_NONE as GLfloat,
+    GL_TEXTURE_COMPARE_FUNC => GL_LEQUAL as GLfloat,
+    GL_TEXTURE_SWIZZLE_R => GL_RED as GLfloat,
+    GL_TEXTURE_SWIZZLE_G => GL_GREEN as GLfloat,
+    GL_TEXTURE_SWIZZLE_B => GL_BLUE as GLfloat,
+    GL_TEXTURE_SWIZZLE_A => GL_ALPHA as GLfloat,
+    _ => return None,
+  })
+}
+
+extern "system" fn glGetTexParameterfv(
+  target: GLenum, pname: GLenum, params: *mut GLfloat,
+) {
+  if let Some(v) = get_tex_parameter(target, pname, "glGetTexParameterfv") {
+    unsafe { write_slice_out(&[v], params) }
+  }
+}
+
+extern "system" fn glGetTexParameteriv(
+  target: GLenum, pname: GLenum, params: *mut GLint,
+) {
+  if let Some(v) = get_tex_parameter(target, pname, "glGetTexParameteriv") {
+    unsafe { write_slice_out(&[v as GLint], params) }
+  }
+}
+
+fn set_tex_parameter(target: GLenum, pname: GLenum, value: GLfloat, cmd: &str) {
+  with(|s| {
+    if default_tex_parameter(pname).is_none() {
+      return s.error(GL_INVALID_ENUM, &format!("{}: invalid pname", cmd));
+    }
+    if let Some(t) = s.target_texture(target, cmd) {
+      t.params.insert(pname, value);
+    }
+  })
+}
+
+extern "system" fn glTexParameterf(
+  target: GLenum, pname: GLenum, param: GLfloat,
+) {
+  set_tex_parameter(target, pname, param, "glTexParameterf")
+}
+
+extern "system" fn glTexParameterfv(
+  target: GLenum, pname: GLenum, params: *const GLfloat,
+) {
+  set_tex_parameter(target, pname, unsafe { *params }, "glTexParameterfv")
+}
+
+extern "system" fn glTexParameteri(
+  target: GLenum, pname: GLenum, param: GLint,
+) {
+  set_tex_parameter(target, pname, param as GLfloat, "glTexParameteri")
+}
+
+extern "system" fn glTexParameteriv(
+  target: GLenum, pname: GLenum, params: *const GLint,
+) {
+  set_tex_parameter(
+    target,
+    pname,
+    unsafe { *params } as GLfloat,
+    "glTexParameteriv",
+  )
+}
+
+fn set_sampler_parameter(
+  sampler: GLuint, pname: GLenum, value: GLfloat, cmd: &str,
+) {
+  with(|s| {
+    if default_tex_parameter(pname).is_none()
+      || pname == GL_TEXTURE_BASE_LEVEL
+      || pname == GL_TEXTURE_MAX_LEVEL
+    {
+      return s.error(GL_INVALID_ENUM, &format!("{}: invalid pname", cmd));
+    }
+    match s.samplers.get_mut(&sampler) {
+      Some(params) => {
+        params.insert(pname, value);
+      }
+      None => {
+        s.error(GL_INVALID_OPERATION, &format!("{}: unknown sampler", cmd))
+      }
+    }
+  })
+}
+
+extern "system" fn glSamplerParameterf(
+  sampler: GLuint, pname: GLenum, param: GLfloat,
+) {
+  set_sampler_parameter(sampler, pname, param, "glSamplerParameterf")
+}
+
+extern "system" fn glSamplerParameterfv(
+  sampler: GLuint, pname: GLenum, param: *const GLfloat,
+) {
+  set_sampler_parameter(
+    sampler,
+    pname,
+    unsafe { *param },
+    "glSamplerParameterfv",
+  )
+}
+
+extern "system" fn glSamplerParameteri(
+  sampler: GLuint, pname: GLenum, param: GLint,
+) {
+  set_sampler_parameter(sampler, pname, param as GLfloat, "glSamplerParameteri")
+}
+
+extern "system" fn glSamplerParameteriv(
+  sampler: GLuint, pname: GLenum, param: *const GLint,
+) {
+  set_sampler_parameter(
+    sampler,
+    pname,
+    unsafe { *param } as GLfloat,
+    "glSamplerParameteriv",
+  )
+}
+
+fn get_sampler_parameter(
+  sampler: GLuint, pname: GLenum, cmd: &str,
+) -> Option<GLfloat> {
+  with(|s| {
+    let v = match s.samplers.get(&sampler) {
+      Some(params) => {
+        params.get(&pname).cloned().or_else(|| default_tex_parameter(pname))
+      }
+      None => {
+        s.error(GL_INVALID_OPERATION, &format!("{}: unknown sampler", cmd));
+        return None;
+      }
+    };
+    if v.is_none() {
+      s.error(GL_INVALID_ENUM, &format!("{}: invalid pname", cmd));
+    }
+    v
+  })
+}
+
+extern "system" fn glGetSamplerParameterfv(
+  sampler: GLuint, pname: GLenum, params: *mut GLfloat,
+) {
+  if let Some(v) =
+    get_sampler_parameter(sampler, pname, "glGetSamplerParameterfv")
+  {
+    unsafe { write_slice_out(&[v], params) }
+  }
+}
+
+extern "system" fn glGetSamplerParameteriv(
+  sampler: GLuint, pname: GLenum, params: *mut GLint,
+) {
+  if let Some(v) =
+    get_sampler_parameter(sampler, pname, "glGetSamplerParameteriv")
+  {
+    unsafe { write_slice_out(&[v as GLint], params) }
+  }
+}
+
+macro_rules! is_object {
+  ($fn_name:ident($name:ident: $t:ty), |$s:ident| $test:expr) => {
+    extern "system" fn $fn_name($name: $t) -> GLboolean {
+      with(|$s| gl_bool($test))
+    }
+  };
+}
+
+is_object!(glIsBuffer(buffer: GLuint), |s| {
+  buffer != 0 && s.buffers.contains_key(&buffer)
+});
+is_object!(glIsFramebuffer(framebuffer: GLuint), |s| {
+  framebuffer != 0 && s.framebuffers.contains_key(&framebuffer)
+});
+is_object!(glIsProgram(program: GLuint), |s| s.programs.contains_key(&program));
+is_object!(glIsProgramPipeline(pipeline: GLuint), |s| {
+  s.program_pipelines.contains(&pipeline)
+});
+is_object!(glIsQuery(id: GLuint), |s| id != 0 && s.queries.contains_key(&id));
+is_object!(glIsRenderbuffer(renderbuffer: GLuint), |s| {
+  renderbuffer != 0 && s.renderbuffers.contains_key(&renderbuffer)
+});
+is_object!(glIsSampler(sampler: GLuint), |s| s.samplers.contains_key(&sampler));
+is_object!(glIsShader(shader: GLuint), |s| s.shaders.contains_key(&shader));
+is_object!(glIsSync(sync: GLsync), |s| s.syncs.contains(&(sync as usize)));
+is_object!(glIsTexture(texture: GLuint), |s| {
+  texture != 0 && s.textures.get(&texture).map(|t| t.target != 0) == Some(true)
+});
+is_object!(glIsTransformFeedback(id: GLuint), |s| {
+  id != 0 && s.transform_feedbacks.contains(&id)
+});
+is_object!(glIsVertexArray(array: GLuint), |s| {
+  array != 0 && s.vertex_arrays.contains_key(&array)
+});
+
+extern "system" fn glLinkProgram(program: GLuint) {
+  with(|s| {
+    let shaders = match s.programs.get(&program) {
+      Some(p) => p.shaders.clone(),
+      None => {
+        return s.error(GL_INVALID_VALUE, "glLinkProgram: unknown program")
+      }
+    };
+    let mut log = String::new();
+    let mut attribs = HashMap::new();
+    let mut uniforms = HashMap::new();
+    for kind in [GL_VERTEX_SHADER, GL_FRAGMENT_SHADER].iter() {
+      let label = if *kind == GL_VERTEX_SHADER { "vertex" } else { "fragment" };
+      match shaders.iter().map(|sh| &s.shaders[sh]).find(|sh| sh.kind == *kind)
+      {
+        None => log.push_str(&format!("error: no {} shader attached\n", label)),
+        Some(sh) if !sh.compiled => log
+          .push_str(&format!("error: the {} shader is not compiled\n", label)),
+        Some(sh) => {
+          for name in declared_uniforms(&sh.source) {
+            let next = uniforms.len() as GLint;
+            uniforms.entry(name).or_insert(next);
+          }
+          if *kind == GL_VERTEX_SHADER {
+            for name in declared_attribs(&sh.source) {
+              attribs.insert(name, -1);
+            }
+          }
+        }
+      }
+    }
+    let p = s.programs.get_mut(&program).unwrap();
+    // assign attribute locations, honoring glBindAttribLocation.
+    let mut used: HashSet<GLint> = HashSet::new();
+    let mut names: Vec<String> = attribs.keys().cloned().collect();
+    names.sort();
+    for name in names.iter() {
+      if let Some(&i) = p.attrib_bindings.get(name) {
+        attribs.insert(name.clone(), i as GLint);
+        used.insert(i as GLint);
+      }
+    }
+    let mut next = 0;
+    for name in names.iter() {
+      if attribs[name] < 0 {
+        while used.contains(&next) {
+          next += 1;
+        }
+        attribs.insert(name.clone(), next);
+        used.insert(next);
+      }
+    }
+    p.linked = log.is_empty();
+    p.info_log = log;
+    if p.linked {
+      p.attribs = attribs;
+      p.uniforms = uniforms;
+    }
+  })
+}
+
+extern "system" fn glMapBufferRange(
+  target: GLenum, offset: GLintptr, length: GLsizeiptr, access: GLbitfield,
+) -> *mut c_void {
+  with(|s| {
+    if offset < 0 || length <= 0 {
+      s.error(GL_INVALID_VALUE, "glMapBufferRange: invalid range");
+      return null_mut();
+    }
+    if access & (GL_MAP_READ_BIT | GL_MAP_WRITE_BIT) == 0 {
+      s.error(
+        GL_INVALID_OPERATION,
+        "glMapBufferRange: neither read nor write access was requested",
+      );
+      return null_mut();
+    }
+    let err = match s.target_buffer(target, "glMapBufferRange") {
+      None => return null_mut(),
+      Some(ref b) if b.mapping.is_some() => {
+        (GL_INVALID_OPERATION, "glMapBufferRange: buffer is already mapped")
+      }
+      Some(ref b) if (offset + length) as usize > b.data.len() => {
+        (GL_INVALID_VALUE, "glMapBufferRange: range is out of bounds")
+      }
+      Some(b) => {
+        b.mapping = Some((offset as usize, length as usize, access));
+        return unsafe {
+          b.data.as_mut_slice().as_mut_ptr().add(offset as usize) as *mut c_void
+        };
+      }
+    };
+    s.error(err.0, err.1);
+    null_mut()
+  })
+}
+
+extern "system" fn glUnmapBuffer(target: GLenum) -> GLboolean {
+  with(|s| {
+    let err = match s.target_buffer(target, "glUnmapBuffer") {
+      None => return gl_bool(false),
+      Some(ref b) if b.mapping.is_none() => {
+        (GL_INVALID_OPERATION, "glUnmapBuffer: buffer isn't mapped")
+      }
+      Some(b) => {
+        b.mapping = None;
+        return gl_bool(true);
+      }
+    };
+    s.error(err.0, err.1);
+    gl_bool(false)
+  })
+}
+
+impl MockState {
+  fn object_exists(
+    &mut self, identifier: GLenum, name: GLuint, cmd: &str,
+  ) -> bool {
+    let exists = match identifier {
+      BUFFER_IDENTIFIER => self.buffers.contains_key(&name),
+      SHADER_IDENTIFIER => self.shaders.contains_key(&name),
+      PROGRAM_IDENTIFIER => self.programs.contains_key(&name),
+      QUERY_IDENTIFIER => self.queries.contains_key(&name),
+      SAMPLER_IDENTIFIER => self.samplers.contains_key(&name),
+      VERTEX_ARRAY_IDENTIFIER => self.vertex_arrays.contains_key(&name),
+      PROGRAM_PIPELINE_IDENTIFIER => self.program_pipelines.contains(&name),
+      GL_TRANSFORM_FEEDBACK => self.transform_feedbacks.contains(&name),
+      GL_TEXTURE => self.textures.contains_key(&name),
+      GL_FRAMEBUFFER => self.framebuffers.contains_key(&name),
+      GL_RENDERBUFFER => self.renderbuffers.contains_key(&name),
+      _ => {
+        self.error(GL_INVALID_ENUM, &format!("{}: invalid identifier", cmd));
+        return false;
+      }
+    };
+    if !exists {
+      self.error(GL_INVALID_VALUE, &format!("{}: unknown object name", cmd));
+    }
+    exists
+  }
+}
+
+extern "system" fn glObjectLabelKHR(
+  identifier: GLenum, name: GLuint, length: GLsizei, label: *const GLchar,
+) {
+  let text = if label.is_null() {
+    None
+  } else {
+    Some(unsafe { read_str_in(label, length) })
+  };
+  with(|s| {
+    if !s.object_exists(identifier, name, "glObjectLabelKHR") {
+      return;
+    }
+    match text {
+      Some(ref t) if t.len() >= MAX_LABEL_LENGTH_VALUE => {
+        s.error(GL_INVALID_VALUE, "glObjectLabelKHR: label is too long")
+      }
+      Some(t) => {
+        s.labels.insert((identifier, name), t);
+      }
+      None => {
+        s.labels.remove(&(identifier, name));
+      }
+    }
+  })
+}
+
+extern "system" fn glObjectPtrLabelKHR(
+  ptr: *const c_void, length: GLsizei, label: *const GLchar,
+) {
+  let text = if label.is_null() {
+    None
+  } else {
+    Some(unsafe { read_str_in(label, length) })
+  };
+  with(|s| {
+    if !s.syncs.contains(&(ptr as usize)) {
+      return s.error(GL_INVALID_VALUE, "glObjectPtrLabelKHR: unknown sync");
+    }
+    match text {
+      Some(ref t) if t.len() >= MAX_LABEL_LENGTH_VALUE => {
+        s.error(GL_INVALID_VALUE, "glObjectPtrLabelKHR: label is too long")
+      }
+      Some(t) => {
+        s.sync_labels.insert(ptr as usize, t);
+      }
+      None => {
+        s.sync_labels.remove(&(ptr as usize));
+      }
+    }
+  })
+}
+
+extern "system" fn glPixelStorei(pname: GLenum, param: GLint) {
+  with(|s| {
+    if !s.pixel_store.contains_key(&pname) {
+      return s.error(GL_INVALID_ENUM, "glPixelStorei: invalid pname");
+    }
+    let alignment = pname == GL_PACK_ALIGNMENT || pname == GL_UNPACK_ALIGNMENT;
+    if param < 0 || (alignment && ![1, 2, 4, 8].contains(&param)) {
+      return s.error(GL_INVALID_VALUE, "glPixelStorei: invalid value");
+    }
+    s.pixel_store.insert(pname, param);
+  })
+}
+
+extern "system" fn glPopDebugGroupKHR() {
+  with(|s| match s.debug_groups.pop() {
+    Some((source, id, message)) => s.debug_message(
+      source,
+      DEBUG_TYPE_POP_GROUP,
+      id,
+      DEBUG_SEVERITY_NOTIFICATION,
+      &message,
+    ),
+    None => s.error(GL_STACK_UNDERFLOW, "glPopDebugGroupKHR: stack underflow"),
+  })
+}
+
+extern "system" fn glPushDebugGroupKHR(
+  source: GLenum, id: GLuint, length: GLsizei, message: *const GLchar,
+) {
+  let message = unsafe { read_str_in(message, length) };
+  with(|s| {
+    if source != DEBUG_SOURCE_APPLICATION && source != DEBUG_SOURCE_THIRD_PARTY
+    {
+      return s.error(GL_INVALID_ENUM, "glPushDebugGroupKHR: invalid source");
+    }
+    // the default group counts towards the limit.
+    if s.debug_groups.len() + 1 >= MAX_DEBUG_GROUP_DEPTH_VALUE {
+      return s.error(GL_STACK_OVERFLOW, "glPushDebugGroupKHR: stack overflow");
+    }
+    s.debug_message(
+      source,
+      DEBUG_TYPE_PUSH_GROUP,
+      id,
+      DEBUG_SEVERITY_NOTIFICATION,
+      &message,
+    );
+    s.debug_groups.push((source, id, message));
+  })
+}
+
+fn renderbuffer_storage(
+  target: GLenum, samples: GLsizei, internalformat: GLenum, width: GLsizei,
+  height: GLsizei, cmd: &str,
+) {
+  with(|s| {
+    if target != GL_RENDERBUFFER {
+      return s.error(GL_INVALID_ENUM, &format!("{}: invalid target", cmd));
+    }
+    if width < 0 || height < 0 || width > 2048 || height > 2048 || samples < 0 {
+      return s.error(GL_INVALID_VALUE, &format!("{}: invalid size", cmd));
+    }
+    if s.renderbuffer == 0 {
+      return s.error(
+        GL_INVALID_OPERATION,
+        &format!("{}: no renderbuffer is bound", cmd),
+      );
+    }
+    let r = s.renderbuffer;
+    s.renderbuffers.insert(
+      r,
+      Renderbuffer { internal_format: internalformat, width, height, samples },
+    );
+  })
+}
+
+extern "system" fn glRenderbufferStorage(
+  target: GLenum, internalformat: GLenum, width: GLsizei, height: GLsizei,
+) {
+  renderbuffer_storage(
+    target,
+    0,
+    internalformat,
+    width,
+    height,
+    "glRenderbufferStorage",
+  )
+}
+
+extern "system" fn glRenderbufferStorageMultisample(
+  target: GLenum, samples: GLsizei, internalformat: GLenum, width: GLsizei,
+  height: GLsizei,
+) {
+  renderbuffer_storage(
+    target,
+    samples,
+    internalformat,
+    width,
+    height,
+    "glRenderbufferStorageMultisample",
+  )
+}
+
+extern "system" fn glGetRenderbufferParameteriv(
+  target: GLenum, pname: GLenum, params: *mut GLint,
+) {
+  let v = with(|s| {
+    if target != GL_RENDERBUFFER {
+      s.error(GL_INVALID_ENUM, "glGetRenderbufferParameteriv: invalid target");
+      return None;
+    }
+    let r = match s.renderbuffers.get(&s.renderbuffer) {
+      Some(r) => r,
+      None => {
+        s.error(
+          GL_INVALID_OPERATION,
+          "glGetRenderbufferParameteriv: no renderbuffer is bound",
+        );
+        return None;
+      }
+    };
+    let v = match pname {
+      GL_RENDERBUFFER_WIDTH => r.width,
+      GL_RENDERBUFFER_HEIGHT => r.height,
+      GL_RENDERBUFFER_INTERNAL_FORMAT => {
+        if r.internal_format == 0 {
+          GL_RGBA4 as GLint
+        } else {
+          r.internal_format as GLint
+        }
+      }
+      GL_RENDERBUFFER_SAMPLES => r.samples,
+      GL_RENDERBUFFER_RED_SIZE
+      | GL_RENDERBUFFER_GREEN_SIZE
+      | GL_RENDERBUFFER_BLUE_SIZE
+      | GL_RENDERBUFFER_ALPHA_SIZE
+      | GL_RENDERBUFFER_DEPTH_SIZE
+      | GL_RENDERBUFFER_STENCIL_SIZE => 0,
+      _ => {
+        s.error(GL_INVALID_ENUM, "glGetRenderbufferParameteriv: invalid pname");
+        return None;
+      }
+    };
+    Some(v)
+  });
+  if let Some(v) = v {
+    unsafe { write_slice_out(&[v], params) }
+  }
+}
+
+extern "system" fn glScissor(
+  x: GLint, y: GLint, width: GLsizei, height: GLsizei,
+) {
+  with(|s| {
+    if width < 0 || height < 0 {
+      return s.error(GL_INVALID_VALUE, "glScissor: negative size");
+    }
+    s.scissor = [x, y, width, height];
+  })
+}
+
+extern "system" fn glShaderSource(
+  shader: GLuint, count: GLsizei, string: *const *const GLchar,
+  length: *const GLint,
+) {
+  let mut source = String::new();
+  for i in 0..count.max(0) as usize {
+    unsafe {
+      let len = if length.is_null() { -1 } else { *length.add(i) };
+      source.push_str(&read_str_in(*string.add(i), len));
+    }
+  }
+  with(|s| {
+    if count < 0 {
+      return s.error(GL_INVALID_VALUE, "glShaderSource: negative count");
+    }
+    match s.shaders.get_mut(&shader) {
+      Some(sh) => sh.source = source,
+      None => s.error(GL_INVALID_VALUE, "glShaderSource: unknown shader"),
+    }
+  })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn tex_image(
+  target: GLenum, level: GLint, internal_format: GLenum, width: GLsizei,
+  height: GLsizei, depth: GLsizei, cmd: &str, three_d: bool,
+) {
+  with(|s| {
+    let binding_target = texture_binding_target(target);
+    let valid_target = if three_d {
+      target == GL_TEXTURE_3D || target == GL_TEXTURE_2D_ARRAY
+    } else {
+      target == GL_TEXTURE_2D || binding_target == GL_TEXTURE_CUBE_MAP
+    };
+    if !valid_target {
+      return s.error(GL_INVALID_ENUM, &format!("{}: invalid target", cmd));
+    }
+    if level < 0 || width < 0 || height < 0 || depth < 0 {
+      return s.error(GL_INVALID_VALUE, &format!("{}: negative size", cmd));
+    }
+    let t = s.target_texture(binding_target, cmd).unwrap();
+    if t.immutable_levels > 0 {
+      return s.error(
+        GL_INVALID_OPERATION,
+        &format!("{}: texture storage is immutable", cmd),
+      );
+    }
+    t.levels.insert(
+      (target, level),
+      TexLevel { internal_format, width, height, depth },
+    );
+  })
+}
+
+extern "system" fn glTexImage2D(
+  target: GLenum, level: GLint, internalformat: GLint, width: GLsizei,
+  height: GLsizei, _border: GLint, _format: GLenum, _type_: GLenum,
+  _pixels: *const c_void,
+) {
+  tex_image(
+    target,
+    level,
+    internalformat as GLenum,
+    width,
+    height,
+    1,
+    "glTexImage2D",
+    false,
+  )
+}
+
+extern "system" fn glTexImage3D(
+  target: GLenum, level: GLint, internalformat: GLint, width: GLsizei,
+  height: GLsizei, depth: GLsizei, _border: GLint, _format: GLenum,
+  _type_: GLenum, _pixels: *const c_void,
+) {
+  tex_image(
+    target,
+    level,
+    internalformat as GLenum,
+    width,
+    height,
+    depth,
+    "glTexImage3D",
+    true,
+  )
+}
+
+extern "system" fn glCompressedTexImage2D(
+  target: GLenum, level: GLint, internalformat: GLenum, width: GLsizei,
+  height: GLsizei, _border: GLint, _imageSize: GLsizei, _data: *const c_void,
+) {
+  tex_image(
+    target,
+    level,
+    internalformat,
+    width,
+    height,
+    1,
+    "glCompressedTexImage2D",
+    false,
+  )
+}
+
+extern "system" fn glCompressedTexImage3D(
+  target: GLenum, level: GLint, internalformat: GLenum, width: GLsizei,
+  height: GLsizei, depth: GLsizei, _border: GLint, _imageSize: GLsizei,
+  _data: *const c_void,
+) {
+  tex_image(
+    target,
+    level,
+    internalformat,
+    width,
+    height,
+    depth,
+    "glCompressedTexImage3D",
+    true,
+  )
+}
+
+fn tex_storage(
+  target: GLenum, levels: GLsizei, internal_format: GLenum, width: GLsizei,
+  height: GLsizei, depth: GLsizei, cmd: &str,
+) {
+  with(|s| {
+    let three_d = depth > 0;
+    let valid_target = if three_d {
+      target == GL_TEXTURE_3D || target == GL_TEXTURE_2D_ARRAY
+    } else {
+      target == GL_TEXTURE_2D || target == GL_TEXTURE_CUBE_MAP
+    };
+    if !valid_target {
+      return s.error(GL_INVALID_ENUM, &format!("{}: invalid target", cmd));
+    }
+    if levels < 1 || width < 1 || height < 1 || (three_d && depth < 1) {
+      return s.error(GL_INVALID_VALUE, &format!("{}: invalid size", cmd));
+    }
+    let t = s.target_texture(target, cmd).unwrap();
+    if t.immutable_levels > 0 {
+      return s.error(
+        GL_INVALID_OPERATION,
+        &format!("{}: texture storage is immutable", cmd),
+      );
+    }
+    let faces: Vec<GLenum> = if target == GL_TEXTURE_CUBE_MAP {
+      (GL_TEXTURE_CUBE_MAP_POSITIVE_X..=GL_TEXTURE_CUBE_MAP_NEGATIVE_Z)
+        .collect()
+    } else {
+      vec![target]
+    };
+    t.levels.clear();
+    let depth = depth.max(1);
+    for face in faces {
+      for level in 0..levels {
+        let size = |x: GLsizei| (x >> level).max(1);
+        let d = if target == GL_TEXTURE_3D { size(depth) } else { depth };
+        t.levels.insert(
+          (face, level),
+          TexLevel {
+            internal_format,
+            width: size(width),
+            height: size(height),
+            depth: d,
+          },
+        );
+      }
+    }
+    t.immutable_levels = levels;
+  })
+}
+
+extern "system" fn glTexStorage2D(
+  target: GLenum, levels: GLsizei, internalformat: GLenum, width: GLsizei,
+  height: GLsizei,
+) {
+  tex_storage(
+    target,
+    levels,
+    internalformat,
+    width,
+    height,
+    0,
+    "glTexStorage2D",
+  )
+}
+
+extern "system" fn glTexStorage3D(
+  target: GLenum, levels: GLsizei, internalformat: GLenum, width: GLsizei,
+  height: GLsizei, depth: GLsizei,
+) {
+  tex_storage(
+    target,
+    levels,
+    internalformat,
+    width,
+    height,
+    depth,
+    "glTexStorage3D",
+  )
+}
+
+extern "system" fn glUseProgram(program: GLuint) {
+  with(|s| {
+    if program != 0 {
+      match s.programs.get(&program) {
+        Some(p) if p.linked => (),
+        Some(_) => {
+          return s
+            .error(GL_INVALID_OPERATION, "glUseProgram: program isn't linked")
+        }
+        None => {
+          return s.error(GL_INVALID_VALUE, "glUseProgram: unknown program")
+        }
+      }
+    }
+    let old = s.current_program;
+    s.current_program = program;
+    if s.programs.get(&old).map(|p| p.delete_pending).unwrap_or(false) {
+      s.delete_program(old);
+    }
+  })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn attrib_pointer(
+  index: GLuint, size: GLint, type_: GLenum, normalized: bool, integer: bool,
+  stride: GLsizei, pointer: *const c_void, cmd: &str,
+) {
+  with(|s| {
+    if index as usize >= MAX_VERTEX_ATTRIBS_VALUE
+      || size < 1
+      || size > 4
+      || stride < 0
+    {
+      return s.error(GL_INVALID_VALUE, &format!("{}: invalid value", cmd));
+    }
+    let buffer = s.bound_buffer(GL_ARRAY_BUFFER);
+    if buffer == 0 && s.vertex_array != 0 && !pointer.is_null() {
+      return s.error(
+        GL_INVALID_OPERATION,
+        &format!("{}: client arrays need the default vertex array", cmd),
+      );
+    }
+    s.vao_mut().attribs[index as usize] = VertexAttrib {
+      size,
+      ty: type_,
+      normalized,
+      integer,
+      stride,
+      pointer: pointer as usize,
+      buffer,
+      ..s.vao().attribs[index as usize]
+    };
+  })
+}
+
+extern "system" fn glVertexAttribPointer(
+  index: GLuint, size: GLint, type_: GLenum, normalized: GLboolean,
+  stride: GLsizei, pointer: *const c_void,
+) {
+  attrib_pointer(
+    index,
+    size,
+    type_,
+    normalized != 0,
+    false,
+    stride,
+    pointer,
+    "glVertexAttribPointer",
+  )
+}
+
+extern "system" fn glVertexAttribIPointer(
+  index: GLuint, size: GLint, type_: GLenum, stride: GLsizei,
+  pointer: *const c_void,
+) {
+  attrib_pointer(
+    index,
+    size,
+    type_,
+    false,
+    true,
+    stride,
+    pointer,
+    "glVertexAttribIPointer",
+  )
+}
+
+extern "system" fn glVertexAttribDivisor(index: GLuint, divisor: GLuint) {
+  with(|s| {
+    if index as usize >= MAX_VERTEX_ATTRIBS_VALUE {
+      return s.error(GL_INVALID_VALUE, "glVertexAttribDivisor: invalid index");
+    }
+    s.vao_mut().attribs[index as usize].divisor = divisor;
+  })
+}
+
+fn get_vertex_attrib(index: GLuint, pname: GLenum, cmd: &str) -> Option<GLint> {
+  with(|s| {
+    if index as usize >= MAX_VERTEX_ATTRIBS_VALUE {
+      s.error(GL_INVALID_VALUE, &format!("{}: invalid index", cmd));
+      return None;
+    }
+    let a = s.vao().attribs[index as usize];
+    let v = match pname {
+      GL_VERTEX_ATTRIB_ARRAY_ENABLED => a.enabled as GLint,
+      GL_VERTEX_ATTRIB_ARRAY_SIZE => a.size,
+      GL_VERTEX_ATTRIB_ARRAY_STRIDE => a.stride,
+      GL_VERTEX_ATTRIB_ARRAY_TYPE => a.ty as GLint,
+      GL_VERTEX_ATTRIB_ARRAY_NORMALIZED => a.normalized as GLint,
+      GL_VERTEX_ATTRIB_ARRAY_INTEGER => a.integer as GLint,
+      GL_VERTEX_ATTRIB_ARRAY_DIVISOR => a.divisor as GLint,
+      GL_VERTEX_ATTRIB_ARRAY_BUFFER_BINDING => a.buffer as GLint,
+      _ => {
+        s.error(GL_INVALID_ENUM, &format!("{}: invalid pname", cmd));
+        return None;
+      }
+    };
+    Some(v)
+  })
+}
+
+extern "system" fn glGetVertexAttribiv(
+  index: GLuint, pname: GLenum, params: *mut GLint,
+) {
+  if let Some(v) = get_vertex_attrib(index, pname, "glGetVertexAttribiv") {
+    unsafe { write_slice_out(&[v], params) }
+  }
+}
+
+extern "system" fn glGetVertexAttribPointerv(
+  index: GLuint, pname: GLenum, pointer: *mut *mut c_void,
+) {
+  let p = with(|s| {
+    if index as usize >= MAX_VERTEX_ATTRIBS_VALUE {
+      s.error(GL_INVALID_VALUE, "glGetVertexAttribPointerv: invalid index");
+      return None;
+    }
+    if pname != GL_VERTEX_ATTRIB_ARRAY_POINTER {
+      s.error(GL_INVALID_ENUM, "glGetVertexAttribPointerv: invalid pname");
+      return None;
+    }
+    Some(s.vao().attribs[index as usize].pointer as *mut c_void)
+  });
+  if let Some(p) = p {
+    unsafe { write_slice_out(&[p], pointer) }
+  }
+}
+
+extern "system" fn glViewport(
+  x: GLint, y: GLint, width: GLsizei, height: GLsizei,
+) {
+  with(|s| {
+    if width < 0 || height < 0 {
+      return s.error(GL_INVALID_VALUE, "glViewport: negative size");
+    }
+    s.viewport = [x, y, width.min(2048), height.min(2048)];
+  })
+}
+
+extern "system" fn glWaitSync(
+  sync: GLsync, flags: GLbitfield, timeout: GLuint64,
+) {
+  with(|s| {
+    if !s.syncs.contains(&(sync as usize)) {
+      return s.error(GL_INVALID_VALUE, "glWaitSync: unknown sync");
+    }
+    if flags != 0 || timeout != GL_TIMEOUT_IGNORED {
+      s.error(GL_INVALID_VALUE, "glWaitSync: invalid flags or timeout");
+    }
+  })
+}
+
+// // // // //
+// Accepted, but otherwise ignored
+// // // // //
+
+macro_rules! ignored {
+  ($(fn $fn_name:ident($($arg:ident: $t:ty),*) $(-> $ret:ty = $val:expr)?;)*) => {
+    $(
+      #[allow(unused_variables)]
+      extern "system" fn $fn_name($($arg: $t),*) $(-> $ret)? {
+        $($val)?
+      }
+    )*
+  };
+}
+
+ignored! {
+  fn glActiveShaderProgram(pipeline: GLuint, program: GLuint);
+  fn glBeginTransformFeedback(primitiveMode: GLenum);
+  fn glBindImageTexture(unit: GLuint, texture: GLuint, level: GLint, layered: GLboolean, layer: GLint, access: GLenum, format: GLenum);
+  fn glBindVertexBuffer(bindingindex: GLuint, buffer: GLuint, offset: GLintptr, stride: GLsizei);
+  fn glBlendBarrier();
+  fn glBlendColor(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat);
+  fn glBlendEquation(mode: GLenum);
+  fn glBlendEquationSeparate(modeRGB: GLenum, modeAlpha: GLenum);
+  fn glBlendEquationSeparatei(buf: GLuint, modeRGB: GLenum, modeAlpha: GLenum);
+  fn glBlendEquationi(buf: GLuint, mode: GLenum);
+  fn glBlendFunc(sfactor: GLenum, dfactor: GLenum);
+  fn glBlendFuncSeparate(sfactorRGB: GLenum, dfactorRGB: GLenum, sfactorAlpha: GLenum, dfactorAlpha: GLenum);
+  fn glBlendFuncSeparatei(buf: GLuint, srcRGB: GLenum, dstRGB: GLenum, srcAlpha: GLenum, dstAlpha: GLenum);
+  fn glBlendFunci(buf: GLuint, src: GLenum, dst: GLenum);
+  fn glBlitFramebuffer(srcX0: GLint, srcY0: GLint, srcX1: GLint, srcY1: GLint, dstX0: GLint, dstY0: GLint, dstX1: GLint, dstY1: GLint, mask: GLbitfield, filter: GLenum);
+  fn glBufferStorageEXT(target: GLenum, size: GLsizeiptr, data: *const c_void, flags: GLbitfield);
+  fn glClearBufferfi(buffer: GLenum, drawbuffer: GLint, depth: GLfloat, stencil: GLint);
+  fn glClearBufferfv(buffer: GLenum, drawbuffer: GLint, value: *const GLfloat);
+  fn glClearBufferiv(buffer: GLenum, drawbuffer: GLint, value: *const GLint);
+  fn glClearBufferuiv(buffer: GLenum, drawbuffer: GLint, value: *const GLuint);
+  fn glColorMask(red: GLboolean, green: GLboolean, blue: GLboolean, alpha: GLboolean);
+  fn glColorMaski(index: GLuint, r: GLboolean, g: GLboolean, b: GLboolean, a: GLboolean);
+  fn glCompressedTexSubImage2D(target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, width: GLsizei, height: GLsizei, format: GLenum, imageSize: GLsizei, data: *const c_void);
+  fn glCompressedTexSubImage3D(target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, zoffset: GLint, width: GLsizei, height: GLsizei, depth: GLsizei, format: GLenum, imageSize: GLsizei, data: *const c_void);
+  fn glCopyImageSubData(srcName: GLuint, srcTarget: GLenum, srcLevel: GLint, srcX: GLint, srcY: GLint, srcZ: GLint, dstName: GLuint, dstTarget: GLenum, dstLevel: GLint, dstX: GLint, dstY: GLint, dstZ: GLint, srcWidth: GLsizei, srcHeight: GLsizei, srcDepth: GLsizei);
+  fn glCopyTexImage2D(target: GLenum, level: GLint, internalformat: GLenum, x: GLint, y: GLint, width: GLsizei, height: GLsizei, border: GLint);
+  fn glCopyTexSubImage2D(target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, x: GLint, y: GLint, width: GLsizei, height: GLsizei);
+  fn glCopyTexSubImage3D(target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, zoffset: GLint, x: GLint, y: GLint, width: GLsizei, height: GLsizei);
+  fn glCreateShaderProgramv(type_: GLenum, count: GLsizei, strings: *const *const GLchar) -> GLuint = 0;
+  fn glCullFace(mode: GLenum);
+  fn glDepthFunc(func: GLenum);
+  fn glDepthMask(flag: GLboolean);
+  fn glDepthRangef(n: GLfloat, f: GLfloat);
+  fn glDisablei(target: GLenum, index: GLuint);
+  fn glDispatchCompute(num_groups_x: GLuint, num_groups_y: GLuint, num_groups_z: GLuint);
+  fn glDispatchComputeIndirect(indirect: GLintptr);
+  fn glDrawArraysIndirect(mode: GLenum, indirect: *const c_void);
+  fn glDrawBuffers(n: GLsizei, bufs: *const GLenum);
+  fn glDrawElementsBaseVertex(mode: GLenum, count: GLsizei, type_: GLenum, indices: *const c_void, basevertex: GLint);
+  fn glDrawElementsIndirect(mode: GLenum, type_: GLenum, indirect: *const c_void);
+  fn glDrawElementsInstancedBaseVertex(mode: GLenum, count: GLsizei, type_: GLenum, indices: *const c_void, instancecount: GLsizei, basevertex: GLint);
+  fn glDrawRangeElementsBaseVertex(mode: GLenum, start: GLuint, end: GLuint, count: GLsizei, type_: GLenum, indices: *const c_void, basevertex: GLint);
+  fn glEGLImageTargetRenderbufferStorageOES(target: GLenum, image: GLeglImageOES);
+  fn glEGLImageTargetTexture2DOES(target: GLenum, image: GLeglImageOES);
+  fn glEnablei(target: GLenum, index: GLuint);
+  fn glEndTransformFeedback();
+  fn glFinish();
+  fn glFlush();
+  fn glFlushMappedBufferRange(target: GLenum, offset: GLintptr, length: GLsizeiptr);
+  fn glFramebufferParameteri(target: GLenum, pname: GLenum, param: GLint);
+  fn glFramebufferTexture(target: GLenum, attachment: GLenum, texture: GLuint, level: GLint);
+  fn glFramebufferTexture2DMultisampleEXT(target: GLenum, attachment: GLenum, textarget: GLenum, texture: GLuint, level: GLint, samples: GLsizei);
+  fn glFramebufferTextureMultiviewOVR(target: GLenum, attachment: GLenum, texture: GLuint, level: GLint, baseViewIndex: GLint, numViews: GLsizei);
+  fn glFrontFace(mode: GLenum);
+  fn glGetActiveAttrib(program: GLuint, index: GLuint, bufSize: GLsizei, length: *mut GLsizei, size: *mut GLint, type_: *mut GLenum, name: *mut GLchar);
+  fn glGetActiveUniform(program: GLuint, index: GLuint, bufSize: GLsizei, length: *mut GLsizei, size: *mut GLint, type_: *mut GLenum, name: *mut GLchar);
+  fn glGetActiveUniformBlockName(program: GLuint, uniformBlockIndex: GLuint, bufSize: GLsizei, length: *mut GLsizei, uniformBlockName: *mut GLchar);
+  fn glGetActiveUniformBlockiv(program: GLuint, uniformBlockIndex: GLuint, pname: GLenum, params: *mut GLint);
+  fn glGetActiveUniformsiv(program: GLuint, uniformCount: GLsizei, uniformIndices: *const GLuint, pname: GLenum, params: *mut GLint);
+  fn glGetAttachedShaders(program: GLuint, maxCount: GLsizei, count: *mut GLsizei, shaders: *mut GLuint);
+  fn glGetBooleani_v(target: GLenum, index: GLuint, data: *mut GLboolean);
+  fn glGetFragDataLocation(program: GLuint, name: *const GLchar) -> GLint = -1;
+  fn glGetFramebufferAttachmentParameteriv(target: GLenum, attachment: GLenum, pname: GLenum, params: *mut GLint);
+  fn glGetFramebufferParameteriv(target: GLenum, pname: GLenum, params: *mut GLint);
+  fn glGetGraphicsResetStatus() -> GLenum = GL_NO_ERROR;
+  fn glGetInternalformativ(target: GLenum, internalformat: GLenum, pname: GLenum, count: GLsizei, params: *mut GLint);
+  fn glGetMultisamplefv(pname: GLenum, index: GLuint, val: *mut GLfloat);
+  fn glGetProgramBinary(program: GLuint, bufSize: GLsizei, length: *mut GLsizei, binaryFormat: *mut GLenum, binary: *mut c_void);
+  fn glGetProgramInterfaceiv(program: GLuint, programInterface: GLenum, pname: GLenum, params: *mut GLint);
+  fn glGetProgramPipelineInfoLog(pipeline: GLuint, bufSize: GLsizei, length: *mut GLsizei, infoLog: *mut GLchar);
+  fn glGetProgramPipelineiv(pipeline: GLuint, pname: GLenum, params: *mut GLint);
+  fn glGetProgramResourceIndex(program: GLuint, programInterface: GLenum, name: *const GLchar) -> GLuint = GL_INVALID_INDEX;
+  fn glGetProgramResourceLocation(program: GLuint, programInterface: GLenum, name: *const GLchar) -> GLint = -1;
+  fn glGetProgramResourceName(program: GLuint, programInterface: GLenum, index: GLuint, bufSize: GLsizei, length: *mut GLsizei, name: *mut GLchar);
+  fn glGetProgramResourceiv(program: GLuint, programInterface: GLenum, index: GLuint, propCount: GLsizei, props: *const GLenum, bufSize: GLsizei, length: *mut GLsizei, params: *mut GLint);
+  fn glGetQueryObjecti64vEXT(id: GLuint, pname: GLenum, params: *mut GLint64);
+  fn glGetQueryObjectivEXT(id: GLuint, pname: GLenum, params: *mut GLint);
+  fn glGetQueryObjectui64vEXT(id: GLuint, pname: GLenum, params: *mut GLuint64);
+  fn glGetQueryObjectuiv(id: GLuint, pname: GLenum, params: *mut GLuint);
+  fn glGetQueryiv(target: GLenum, pname: GLenum, params: *mut GLint);
+  fn glGetSamplerParameterIiv(sampler: GLuint, pname: GLenum, params: *mut GLint);
+  fn glGetSamplerParameterIuiv(sampler: GLuint, pname: GLenum, params: *mut GLuint);
+  fn glGetShaderPrecisionFormat(shadertype: GLenum, precisiontype: GLenum, range: *mut GLint, precision: *mut GLint);
+  fn glGetTexLevelParameterfv(target: GLenum, level: GLint, pname: GLenum, params: *mut GLfloat);
+  fn glGetTexLevelParameteriv(target: GLenum, level: GLint, pname: GLenum, params: *mut GLint);
+  fn glGetTexParameterIiv(target: GLenum, pname: GLenum, params: *mut GLint);
+  fn glGetTexParameterIuiv(target: GLenum, pname: GLenum, params: *mut GLuint);
+  fn glGetTransformFeedbackVarying(program: GLuint, index: GLuint, bufSize: GLsizei, length: *mut GLsizei, size: *mut GLsizei, type_: *mut GLenum, name: *mut GLchar);
+  fn glGetUniformBlockIndex(program: GLuint, uniformBlockName: *const GLchar) -> GLuint = GL_INVALID_INDEX;
+  fn glGetUniformIndices(program: GLuint, uniformCount: GLsizei, uniformNames: *const *const GLchar, uniformIndices: *mut GLuint);
+  fn glGetUniformfv(program: GLuint, location: GLint, params: *mut GLfloat);
+  fn glGetUniformiv(program: GLuint, location: GLint, params: *mut GLint);
+  fn glGetUniformuiv(program: GLuint, location: GLint, params: *mut GLuint);
+  fn glGetVertexAttribIiv(index: GLuint, pname: GLenum, params: *mut GLint);
+  fn glGetVertexAttribIuiv(index: GLuint, pname: GLenum, params: *mut GLuint);
+  fn glGetVertexAttribfv(index: GLuint, pname: GLenum, params: *mut GLfloat);
+  fn glGetnUniformfv(program: GLuint, location: GLint, bufSize: GLsizei, params: *mut GLfloat);
+  fn glGetnUniformiv(program: GLuint, location: GLint, bufSize: GLsizei, params: *mut GLint);
+  fn glGetnUniformuiv(program: GLuint, location: GLint, bufSize: GLsizei, params: *mut GLuint);
+  fn glHint(target: GLenum, mode: GLenum);
+  fn glInvalidateFramebuffer(target: GLenum, numAttachments: GLsizei, attachments: *const GLenum);
+  fn glInvalidateSubFramebuffer(target: GLenum, numAttachments: GLsizei, attachments: *const GLenum, x: GLint, y: GLint, width: GLsizei, height: GLsizei);
+  fn glIsEnabledi(target: GLenum, index: GLuint) -> GLboolean = 0;
+  fn glLineWidth(width: GLfloat);
+  fn glMemoryBarrier(barriers: GLbitfield);
+  fn glMemoryBarrierByRegion(barriers: GLbitfield);
+  fn glMinSampleShading(value: GLfloat);
+  fn glPatchParameteri(pname: GLenum, value: GLint);
+  fn glPauseTransformFeedback();
+  fn glPolygonOffset(factor: GLfloat, units: GLfloat);
+  fn glPrimitiveBoundingBox(minX: GLfloat, minY: GLfloat, minZ: GLfloat, minW: GLfloat, maxX: GLfloat, maxY: GLfloat, maxZ: GLfloat, maxW: GLfloat);
+  fn glProgramBinary(program: GLuint, binaryFormat: GLenum, binary: *const c_void, length: GLsizei);
+  fn glProgramParameteri(program: GLuint, pname: GLenum, value: GLint);
+  fn glProgramUniform1f(program: GLuint, location: GLint, v0: GLfloat);
+  fn glProgramUniform1fv(program: GLuint, location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glProgramUniform1i(program: GLuint, location: GLint, v0: GLint);
+  fn glProgramUniform1iv(program: GLuint, location: GLint, count: GLsizei, value: *const GLint);
+  fn glProgramUniform1ui(program: GLuint, location: GLint, v0: GLuint);
+  fn glProgramUniform1uiv(program: GLuint, location: GLint, count: GLsizei, value: *const GLuint);
+  fn glProgramUniform2f(program: GLuint, location: GLint, v0: GLfloat, v1: GLfloat);
+  fn glProgramUniform2fv(program: GLuint, location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glProgramUniform2i(program: GLuint, location: GLint, v0: GLint, v1: GLint);
+  fn glProgramUniform2iv(program: GLuint, location: GLint, count: GLsizei, value: *const GLint);
+  fn glProgramUniform2ui(program: GLuint, location: GLint, v0: GLuint, v1: GLuint);
+  fn glProgramUniform2uiv(program: GLuint, location: GLint, count: GLsizei, value: *const GLuint);
+  fn glProgramUniform3f(program: GLuint, location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat);
+  fn glProgramUniform3fv(program: GLuint, location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glProgramUniform3i(program: GLuint, location: GLint, v0: GLint, v1: GLint, v2: GLint);
+  fn glProgramUniform3iv(program: GLuint, location: GLint, count: GLsizei, value: *const GLint);
+  fn glProgramUniform3ui(program: GLuint, location: GLint, v0: GLuint, v1: GLuint, v2: GLuint);
+  fn glProgramUniform3uiv(program: GLuint, location: GLint, count: GLsizei, value: *const GLuint);
+  fn glProgramUniform4f(program: GLuint, location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat);
+  fn glProgramUniform4fv(program: GLuint, location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glProgramUniform4i(program: GLuint, location: GLint, v0: GLint, v1: GLint, v2: GLint, v3: GLint);
+  fn glProgramUniform4iv(program: GLuint, location: GLint, count: GLsizei, value: *const GLint);
+  fn glProgramUniform4ui(program: GLuint, location: GLint, v0: GLuint, v1: GLuint, v2: GLuint, v3: GLuint);
+  fn glProgramUniform4uiv(program: GLuint, location: GLint, count: GLsizei, value: *const GLuint);
+  fn glProgramUniformMatrix2fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix2x3fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix2x4fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix3fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix3x2fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix3x4fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix4fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix4x2fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glProgramUniformMatrix4x3fv(program: GLuint, location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glQueryCounterEXT(id: GLuint, target: GLenum);
+  fn glReadBuffer(src: GLenum);
+  fn glReadPixels(x: GLint, y: GLint, width: GLsizei, height: GLsizei, format: GLenum, type_: GLenum, pixels: *mut c_void);
+  fn glReadnPixels(x: GLint, y: GLint, width: GLsizei, height: GLsizei, format: GLenum, type_: GLenum, bufSize: GLsizei, data: *mut c_void);
+  fn glReleaseShaderCompiler();
+  fn glRenderbufferStorageMultisampleEXT(target: GLenum, samples: GLsizei, internalformat: GLenum, width: GLsizei, height: GLsizei);
+  fn glResumeTransformFeedback();
+  fn glSampleCoverage(value: GLfloat, invert: GLboolean);
+  fn glSampleMaski(maskNumber: GLuint, mask: GLbitfield);
+  fn glSamplerParameterIiv(sampler: GLuint, pname: GLenum, param: *const GLint);
+  fn glSamplerParameterIuiv(sampler: GLuint, pname: GLenum, param: *const GLuint);
+  fn glShaderBinary(count: GLsizei, shaders: *const GLuint, binaryformat: GLenum, binary: *const c_void, length: GLsizei);
+  fn glStencilFunc(func: GLenum, ref_: GLint, mask: GLuint);
+  fn glStencilFuncSeparate(face: GLenum, func: GLenum, ref_: GLint, mask: GLuint);
+  fn glStencilMask(mask: GLuint);
+  fn glStencilMaskSeparate(face: GLenum, mask: GLuint);
+  fn glStencilOp(fail: GLenum, zfail: GLenum, zpass: GLenum);
+  fn glStencilOpSeparate(face: GLenum, sfail: GLenum, dpfail: GLenum, dppass: GLenum);
+  fn glTexBuffer(target: GLenum, internalformat: GLenum, buffer: GLuint);
+  fn glTexBufferRange(target: GLenum, internalformat: GLenum, buffer: GLuint, offset: GLintptr, size: GLsizeiptr);
+  fn glTexParameterIiv(target: GLenum, pname: GLenum, params: *const GLint);
+  fn glTexParameterIuiv(target: GLenum, pname: GLenum, params: *const GLuint);
+  fn glTexStorage2DMultisample(target: GLenum, samples: GLsizei, internalformat: GLenum, width: GLsizei, height: GLsizei, fixedsamplelocations: GLboolean);
+  fn glTexStorage3DMultisample(target: GLenum, samples: GLsizei, internalformat: GLenum, width: GLsizei, height: GLsizei, depth: GLsizei, fixedsamplelocations: GLboolean);
+  fn glTexSubImage2D(target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, width: GLsizei, height: GLsizei, format: GLenum, type_: GLenum, pixels: *const c_void);
+  fn glTexSubImage3D(target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, zoffset: GLint, width: GLsizei, height: GLsizei, depth: GLsizei, format: GLenum, type_: GLenum, pixels: *const c_void);
+  fn glTransformFeedbackVaryings(program: GLuint, count: GLsizei, varyings: *const *const GLchar, bufferMode: GLenum);
+  fn glUniform1f(location: GLint, v0: GLfloat);
+  fn glUniform1fv(location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glUniform1i(location: GLint, v0: GLint);
+  fn glUniform1iv(location: GLint, count: GLsizei, value: *const GLint);
+  fn glUniform1ui(location: GLint, v0: GLuint);
+  fn glUniform1uiv(location: GLint, count: GLsizei, value: *const GLuint);
+  fn glUniform2f(location: GLint, v0: GLfloat, v1: GLfloat);
+  fn glUniform2fv(location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glUniform2i(location: GLint, v0: GLint, v1: GLint);
+  fn glUniform2iv(location: GLint, count: GLsizei, value: *const GLint);
+  fn glUniform2ui(location: GLint, v0: GLuint, v1: GLuint);
+  fn glUniform2uiv(location: GLint, count: GLsizei, value: *const GLuint);
+  fn glUniform3f(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat);
+  fn glUniform3fv(location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glUniform3i(location: GLint, v0: GLint, v1: GLint, v2: GLint);
+  fn glUniform3iv(location: GLint, count: GLsizei, value: *const GLint);
+  fn glUniform3ui(location: GLint, v0: GLuint, v1: GLuint, v2: GLuint);
+  fn glUniform3uiv(location: GLint, count: GLsizei, value: *const GLuint);
+  fn glUniform4f(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat);
+  fn glUniform4fv(location: GLint, count: GLsizei, value: *const GLfloat);
+  fn glUniform4i(location: GLint, v0: GLint, v1: GLint, v2: GLint, v3: GLint);
+  fn glUniform4iv(location: GLint, count: GLsizei, value: *const GLint);
+  fn glUniform4ui(location: GLint, v0: GLuint, v1: GLuint, v2: GLuint, v3: GLuint);
+  fn glUniform4uiv(location: GLint, count: GLsizei, value: *const GLuint);
+  fn glUniformBlockBinding(program: GLuint, uniformBlockIndex: GLuint, uniformBlockBinding: GLuint);
+  fn glUniformMatrix2fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix2x3fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix2x4fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix3fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix3x2fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix3x4fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix4fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix4x2fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUniformMatrix4x3fv(location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat);
+  fn glUseProgramStages(pipeline: GLuint, stages: GLbitfield, program: GLuint);
+  fn glValidateProgram(program: GLuint);
+  fn glValidateProgramPipeline(pipeline: GLuint);
+  fn glVertexAttrib1f(index: GLuint, x: GLfloat);
+  fn glVertexAttrib1fv(index: GLuint, v: *const GLfloat);
+  fn glVertexAttrib2f(index: GLuint, x: GLfloat, y: GLfloat);
+  fn glVertexAttrib2fv(index: GLuint, v: *const GLfloat);
+  fn glVertexAttrib3f(index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat);
+  fn glVertexAttrib3fv(index: GLuint, v: *const GLfloat);
+  fn glVertexAttrib4f(index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat);
+  fn glVertexAttrib4fv(index: GLuint, v: *const GLfloat);
+  fn glVertexAttribBinding(attribindex: GLuint, bindingindex: GLuint);
+  fn glVertexAttribFormat(attribindex: GLuint, size: GLint, type_: GLenum, normalized: GLboolean, relativeoffset: GLuint);
+  fn glVertexAttribI4i(index: GLuint, x: GLint, y: GLint, z: GLint, w: GLint);
+  fn glVertexAttribI4iv(index: GLuint, v: *const GLint);
+  fn glVertexAttribI4ui(index: GLuint, x: GLuint, y: GLuint, z: GLuint, w: GLuint);
+  fn glVertexAttribI4uiv(index: GLuint, v: *const GLuint);
+  fn glVertexAttribIFormat(attribindex: GLuint, size: GLint, type_: GLenum, relativeoffset: GLuint);
+  fn glVertexBindingDivisor(bindingindex: GLuint, divisor: GLuint);
+}
+
+/// Finds the mock function for a command name.
+fn lookup(name: &[u8]) -> *mut c_void {
+  macro_rules! table {
+    ($($f:ident),* $(,)?; $($alias:ident => $g:ident),* $(,)?) => {
+      match name {
+        $(n if n == stringify!($f).as_bytes() => $f as *const () as *mut c_void,)*
+        $(n if n == stringify!($alias).as_bytes() => $g as *const () as *mut c_void,)*
+        _ => null_mut(),
+      }
+    };
+  }
+  table![
+    glActiveShaderProgram,
+    glActiveTexture,
+    glAttachShader,
+    glBeginQuery,
+    glBeginTransformFeedback,
+    glBindAttribLocation,
+    glBindBuffer,
+    glBindBufferBase,
+    glBindBufferRange,
+    glBindFramebuffer,
+    glBindImageTexture,
+    glBindProgramPipeline,
+    glBindRenderbuffer,
+    glBindSampler,
+    glBindTexture,
+    glBindTransformFeedback,
+    glBindVertexArray,
+    glBindVertexBuffer,
+    glBlendBarrier,
+    glBlendColor,
+    glBlendEquation,
+    glBlendEquationSeparate,
+    glBlendEquationSeparatei,
+    glBlendEquationi,
+    glBlendFunc,
+    glBlendFuncSeparate,
+    glBlendFuncSeparatei,
+    glBlendFunci,
+    glBlitFramebuffer,
+    glBufferData,
+    glBufferStorageEXT,
+    glBufferSubData,
+    glCheckFramebufferStatus,
+    glClear,
+    glClearBufferfi,
+    glClearBufferfv,
+    glClearBufferiv,
+    glClearBufferuiv,
+    glClearColor,
+    glClearDepthf,
+    glClearStencil,
+    glClientWaitSync,
+    glColorMask,
+    glColorMaski,
+    glCompileShader,
+    glCompressedTexImage2D,
+    glCompressedTexImage3D,
+    glCompressedTexSubImage2D,
+    glCompressedTexSubImage3D,
+    glCopyBufferSubData,
+    glCopyImageSubData,
+    glCopyTexImage2D,
+    glCopyTexSubImage2D,
+    glCopyTexSubImage3D,
+    glCreateProgram,
+    glCreateShader,
+    glCreateShaderProgramv,
+    glCullFace,
+    glDebugMessageCallbackKHR,
+    glDebugMessageControlKHR,
+    glDebugMessageInsertKHR,
+    glDeleteBuffers,
+    glDeleteFramebuffers,
+    glDeleteProgram,
+    glDeleteProgramPipelines,
+    glDeleteQueries,
+    glDeleteRenderbuffers,
+    glDeleteSamplers,
+    glDeleteShader,
+    glDeleteSync,
+    glDeleteTextures,
+    glDeleteTransformFeedbacks,
+    glDeleteVertexArrays,
+    glDepthFunc,
+    glDepthMask,
+    glDepthRangef,
+    glDetachShader,
+    glDisable,
+    glDisableVertexAttribArray,
+    glDisablei,
+    glDispatchCompute,
+    glDispatchComputeIndirect,
+    glDrawArrays,
+    glDrawArraysIndirect,
+    glDrawArraysInstanced,
+    glDrawBuffers,
+    glDrawElements,
+    glDrawElementsBaseVertex,
+    glDrawElementsIndirect,
+    glDrawElementsInstanced,
+    glDrawElementsInstancedBaseVertex,
+    glDrawRangeElements,
+    glDrawRangeElementsBaseVertex,
+    glEGLImageTargetRenderbufferStorageOES,
+    glEGLImageTargetTexture2DOES,
+    glEnable,
+    glEnableVertexAttribArray,
+    glEnablei,
+    glEndQuery,
+    glEndTransformFeedback,
+    glFenceSync,
+    glFinish,
+    glFlush,
+    glFlushMappedBufferRange,
+    glFramebufferParameteri,
+    glFramebufferRenderbuffer,
+    glFramebufferTexture,
+    glFramebufferTexture2D,
+    glFramebufferTexture2DMultisampleEXT,
+    glFramebufferTextureLayer,
+    glFramebufferTextureMultiviewOVR,
+    glFrontFace,
+    glGenBuffers,
+    glGenFramebuffers,
+    glGenProgramPipelines,
+    glGenQueries,
+    glGenRenderbuffers,
+    glGenSamplers,
+    glGenTextures,
+    glGenTransformFeedbacks,
+    glGenVertexArrays,
+    glGenerateMipmap,
+    glGetActiveAttrib,
+    glGetActiveUniform,
+    glGetActiveUniformBlockName,
+    glGetActiveUniformBlockiv,
+    glGetActiveUniformsiv,
+    glGetAttachedShaders,
+    glGetAttribLocation,
+    glGetBooleani_v,
+    glGetBooleanv,
+    glGetBufferParameteri64v,
+    glGetBufferParameteriv,
+    glGetBufferPointerv,
+    glGetDebugMessageLogKHR,
+    glGetError,
+    glGetFloatv,
+    glGetFragDataLocation,
+    glGetFramebufferAttachmentParameteriv,
+    glGetFramebufferParameteriv,
+    glGetGraphicsResetStatus,
+    glGetInteger64i_v,
+    glGetInteger64v,
+    glGetIntegeri_v,
+    glGetIntegerv,
+    glGetInternalformativ,
+    glGetMultisamplefv,
+    glGetObjectLabelKHR,
+    glGetObjectPtrLabelKHR,
+    glGetPointervKHR,
+    glGetProgramBinary,
+    glGetProgramInfoLog,
+    glGetProgramInterfaceiv,
+    glGetProgramPipelineInfoLog,
+    glGetProgramPipelineiv,
+    glGetProgramResourceIndex,
+    glGetProgramResourceLocation,
+    glGetProgramResourceName,
+    glGetProgramResourceiv,
+    glGetProgramiv,
+    glGetQueryObjecti64vEXT,
+    glGetQueryObjectivEXT,
+    glGetQueryObjectui64vEXT,
+    glGetQueryObjectuiv,
+    glGetQueryiv,
+    glGetRenderbufferParameteriv,
+    glGetSamplerParameterIiv,
+    glGetSamplerParameterIuiv,
+    glGetSamplerParameterfv,
+    glGetSamplerParameteriv,
+    glGetShaderInfoLog,
+    glGetShaderPrecisionFormat,
+    glGetShaderSource,
+    glGetShaderiv,
+    glGetString,
+    glGetStringi,
+    glGetSynciv,
+    glGetTexLevelParameterfv,
+    glGetTexLevelParameteriv,
+    glGetTexParameterIiv,
+    glGetTexParameterIuiv,
+    glGetTexParameterfv,
+    glGetTexParameteriv,
+    glGetTransformFeedbackVarying,
+    glGetUniformBlockIndex,
+    glGetUniformIndices,
+    glGetUniformLocation,
+    glGetUniformfv,
+    glGetUniformiv,
+    glGetUniformuiv,
+    glGetVertexAttribIiv,
+    glGetVertexAttribIuiv,
+    glGetVertexAttribPointerv,
+    glGetVertexAttribfv,
+    glGetVertexAttribiv,
+    glGetnUniformfv,
+    glGetnUniformiv,
+    glGetnUniformuiv,
+    glHint,
+    glInvalidateFramebuffer,
+    glInvalidateSubFramebuffer,
+    glIsBuffer,
+    glIsEnabled,
+    glIsEnabledi,
+    glIsFramebuffer,
+    glIsProgram,
+    glIsProgramPipeline,
+    glIsQuery,
+    glIsRenderbuffer,
+    glIsSampler,
+    glIsShader,
+    glIsSync,
+    glIsTexture,
+    glIsTransformFeedback,
+    glIsVertexArray,
+    glLineWidth,
+    glLinkProgram,
+    glMapBufferRange,
+    glMemoryBarrier,
+    glMemoryBarrierByRegion,
+    glMinSampleShading,
+    glObjectLabelKHR,
+    glObjectPtrLabelKHR,
+    glPatchParameteri,
+    glPauseTransformFeedback,
+    glPixelStorei,
+    glPolygonOffset,
+    glPopDebugGroupKHR,
+    glPrimitiveBoundingBox,
+    glProgramBinary,
+    glProgramParameteri,
+    glProgramUniform1f,
+    glProgramUniform1fv,
+    glProgramUniform1i,
+    glProgramUniform1iv,
+    glProgramUniform1ui,
+    glProgramUniform1uiv,
+    glProgramUniform2f,
+    glProgramUniform2fv,
+    glProgramUniform2i,
+    glProgramUniform2iv,
+    glProgramUniform2ui,
+    glProgramUniform2uiv,
+    glProgramUniform3f,
+    glProgramUniform3fv,
+    glProgramUniform3i,
+    glProgramUniform3iv,
+    glProgramUniform3ui,
+    glProgramUniform3uiv,
+    glProgramUniform4f,
+    glProgramUniform4fv,
+    glProgramUniform4i,
+    glProgramUniform4iv,
+    glProgramUniform4ui,
+    glProgramUniform4uiv,
+    glProgramUniformMatrix2fv,
+    glProgramUniformMatrix2x3fv,
+    glProgramUniformMatrix2x4fv,
+    glProgramUniformMatrix3fv,
+    glProgramUniformMatrix3x2fv,
+    glProgramUniformMatrix3x4fv,
+    glProgramUniformMatrix4fv,
+    glProgramUniformMatrix4x2fv,
+    glProgramUniformMatrix4x3fv,
+    glPushDebugGroupKHR,
+    glQueryCounterEXT,
+    glReadBuffer,
+    glReadPixels,
+    glReadnPixels,
+    glReleaseShaderCompiler,
+    glRenderbufferStorage,
+    glRenderbufferStorageMultisample,
+    glRenderbufferStorageMultisampleEXT,
+    glResumeTransformFeedback,
+    glSampleCoverage,
+    glSampleMaski,
+    glSamplerParameterIiv,
+    glSamplerParameterIuiv,
+    glSamplerParameterf,
+    glSamplerParameterfv,
+    glSamplerParameteri,
+    glSamplerParameteriv,
+    glScissor,
+    glShaderBinary,
+    glShaderSource,
+    glStencilFunc,
+    glStencilFuncSeparate,
+    glStencilMask,
+    glStencilMaskSeparate,
+    glStencilOp,
+    glStencilOpSeparate,
+    glTexBuffer,
+    glTexBufferRange,
+    glTexImage2D,
+    glTexImage3D,
+    glTexParameterIiv,
+    glTexParameterIuiv,
+    glTexParameterf,
+    glTexParameterfv,
+    glTexParameteri,
+    glTexParameteriv,
+    glTexStorage2D,
+    glTexStorage2DMultisample,
+    glTexStorage3D,
+    glTexStorage3DMultisample,
+    glTexSubImage2D,
+    glTexSubImage3D,
+    glTransformFeedbackVaryings,
+    glUniform1f,
+    glUniform1fv,
+    glUniform1i,
+    glUniform1iv,
+    glUniform1ui,
+    glUniform1uiv,
+    glUniform2f,
+    glUniform2fv,
+    glUniform2i,
+    glUniform2iv,
+    glUniform2ui,
+    glUniform2uiv,
+    glUniform3f,
+    glUniform3fv,
+    glUniform3i,
+    glUniform3iv,
+    glUniform3ui,
+    glUniform3uiv,
+    glUniform4f,
+    glUniform4fv,
+    glUniform4i,
+    glUniform4iv,
+    glUniform4ui,
+    glUniform4uiv,
+    glUniformBlockBinding,
+    glUniformMatrix2fv,
+    glUniformMatrix2x3fv,
+    glUniformMatrix2x4fv,
+    glUniformMatrix3fv,
+    glUniformMatrix3x2fv,
+    glUniformMatrix3x4fv,
+    glUniformMatrix4fv,
+    glUniformMatrix4x2fv,
+    glUniformMatrix4x3fv,
+    glUnmapBuffer,
+    glUseProgram,
+    glUseProgramStages,
+    glValidateProgram,
+    glValidateProgramPipeline,
+    glVertexAttrib1f,
+    glVertexAttrib1fv,
+    glVertexAttrib2f,
+    glVertexAttrib2fv,
+    glVertexAttrib3f,
+    glVertexAttrib3fv,
+    glVertexAttrib4f,
+    glVertexAttrib4fv,
+    glVertexAttribBinding,
+    glVertexAttribDivisor,
+    glVertexAttribFormat,
+    glVertexAttribI4i,
+    glVertexAttribI4iv,
+    glVertexAttribI4ui,
+    glVertexAttribI4uiv,
+    glVertexAttribIFormat,
+    glVertexAttribIPointer,
+    glVertexAttribPointer,
+    glVertexBindingDivisor,
+    glViewport,
+    glWaitSync;
+    // GLES 3.2 made `GL_KHR_debug` core, dropping the suffix
+    glDebugMessageCallback => glDebugMessageCallbackKHR,
+    glDebugMessageControl => glDebugMessageControlKHR,
+    glDebugMessageInsert => glDebugMessageInsertKHR,
+    glGetDebugMessageLog => glGetDebugMessageLogKHR,
+    glGetObjectLabel => glGetObjectLabelKHR,
+    glGetObjectPtrLabel => glGetObjectPtrLabelKHR,
+    glGetPointerv => glGetPointervKHR,
+    glObjectLabel => glObjectLabelKHR,
+    glObjectPtrLabel => glObjectPtrLabelKHR,
+    glPopDebugGroup => glPopDebugGroupKHR,
+    glPushDebugGroup => glPushDebugGroupKHR,
+    // `GL_EXT_disjoint_timer_query` repeats the GLES 3.0 query commands
+    glBeginQueryEXT => glBeginQuery,
+    glDeleteQueriesEXT => glDeleteQueries,
+    glEndQueryEXT => glEndQuery,
+    glGenQueriesEXT => glGenQueries,
+    glGetQueryObjectuivEXT => glGetQueryObjectuiv,
+    glGetQueryivEXT => glGetQueryiv,
+    glIsQueryEXT => glIsQuery,
+  ]
+}
@@ -0,0 +1,426 @@
+//! Generating and deleting object names in batches.
+//!
+//! The `glGen*` and `glDelete*` commands all take a count and a pointer. The
+//! helpers here take arrays and slices of the typed handles from [`objects`]
+//! instead, for each kind of object:
+//! * `gen_buffers::<N>()` gives an array of `N` new [`Buffer`]s.
+//! * `gen_buffers_into(&mut slots)` fills a slice of `Option<Buffer>` with new
+//!   buffers.
+//! * `delete_buffers(buffers)` deletes all the buffers in a `Vec` or other
+//!   iterator (or an array, on Rust 1.53 or later).
+//!
+//! These are methods on [`GlFns`], and free functions with `global` in the
+//! name (such as [`gen_global_buffers`]) for the global loader. There's the
+//! same three for textures, samplers, framebuffers, renderbuffers, vertex
+//! arrays, queries, and transform feedbacks.
+//!
+//! A [`NamePool`] goes one step further, and generates names a whole chunk at
+//! a time, then hands them out one by one. That makes fewer trips to the
+//! driver when lots of objects get made in a burst, such as while streaming
+//! in a level.
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f(gl: &gles30::GlFns) {
+//! use gles30::{names::*, objects::*};
+//! let [vbo, ibo] = gl.gen_buffers::<2>().unwrap();
+//! let mut pool = NamePool::<Texture>::new(64);
+//! let albedo = pool.alloc(gl).unwrap();
+//! let normals = pool.alloc(gl).unwrap();
+//! // ...
+//! gl.delete_buffers(vec![vbo, ibo]);
+//! gl.delete_textures(vec![albedo, normals]);
+//! pool.delete_unused(gl);
+//! # }
+//! ```
+
+use super::*;
+use crate::objects::*;
+use std::vec::Vec;
+
+/// The kinds of object that have `glGen*` and `glDelete*` commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectKind {
+  /// `glGenBuffers` / `glDeleteBuffers`
+  Buffer,
+  /// `glGenTextures` / `glDeleteTextures`
+  Texture,
+  /// `glGenSamplers` / `glDeleteSamplers`
+  Sampler,
+  /// `glGenFramebuffers` / `glDeleteFramebuffers`
+  Framebuffer,
+  /// `glGenRenderbuffers` / `glDeleteRenderbuffers`
+  Renderbuffer,
+  /// `glGenVertexArrays` / `glDeleteVertexArrays`
+  VertexArray,
+  /// `glGenQueries` / `glDeleteQueries`
+  Query,
+  /// `glGenTransformFeedbacks` / `glDeleteTransformFeedbacks`
+  TransformFeedback,
+}
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+/// A handle type whose names are made with a `glGen*` command, such as
+/// [`Buffer`] or [`Texture`].
+///
+/// This is only for the handle types in [`objects`], it can't be implemented
+/// outside of this crate.
+pub trait GenObject: Object + sealed::Sealed {
+  /// The kind of object that the handle is for.
+  const KIND: ObjectKind;
+}
+
+/// The most names that one `glGen*` or `glDelete*` call can take.
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+const MAX_PER_CALL: usize = core::i32::MAX as usize;
+
+macro_rules! kinds {
+  ($($kind:ident => $gen:ident / $delete:ident;)*) => {
+    $(
+      impl sealed::Sealed for $kind {}
+      impl GenObject for $kind {
+        const KIND: ObjectKind = ObjectKind::$kind;
+      }
+    )*
+
+    #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+    impl Target<'_> {
+      /// Fills `names` with new objects. A slot is left as `None` if the GL
+      /// didn't make a name for it.
+      unsafe fn gen_names<T: GenObject>(&self, names: &mut [Option<T>]) {
+        // Anything already in the slots is leaked, same as overwriting it.
+        names.iter_mut().for_each(|slot| *slot = None);
+        for chunk in names.chunks_mut(MAX_PER_CALL) {
+          // The handles are `#[repr(transparent)]` around a `NonZeroU32`, so
+          // an `Option` of one is laid out as a `GLuint`, with 0 as `None`.
+          let (n, p) = (chunk.len() as GLsizei, chunk.as_mut_ptr() as *mut GLuint);
+          match T::KIND {
+            $( ObjectKind::$kind => self.$gen(n, p), )*
+          }
+        }
+      }
+
+      unsafe fn delete_names<T: GenObject>(&self, names: &[T]) {
+        for chunk in names.chunks(MAX_PER_CALL) {
+          let (n, p) = (chunk.len() as GLsizei, chunk.as_ptr() as *const GLuint);
+          match T::KIND {
+            $( ObjectKind::$kind => self.$delete(n, p), )*
+          }
+        }
+      }
+    }
+  };
+}
+
+kinds! {
+  Buffer => gen_buffers / delete_buffers;
+  Texture => gen_textures / delete_textures;
+  Sampler => gen_samplers / delete_samplers;
+  Framebuffer => gen_framebuffers / delete_framebuffers;
+  Renderbuffer => gen_renderbuffers / delete_renderbuffers;
+  VertexArray => gen_vertex_arrays / delete_vertex_arrays;
+  Query => gen_queries / delete_queries;
+  TransformFeedback => gen_transform_feedbacks / delete_transform_feedbacks;
+}
+
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl Target<'_> {
+  /// Makes `N` new objects, or none at all if the GL didn't make every one.
+  unsafe fn gen_array<T: GenObject, const N: usize>(&self) -> Option<[T; N]> {
+    let mut names: Vec<Option<T>> = (0..N).map(|_| None).collect();
+    self.gen_names(&mut names);
+    let made: Vec<T> = names.into_iter().flatten().collect();
+    if made.len() == N {
+      core::convert::TryFrom::try_from(made).ok()
+    } else {
+      self.delete_names(&made);
+      None
+    }
+  }
+
+  unsafe fn delete_all<T: GenObject>(
+    &self, objects: impl IntoIterator<Item = T>,
+  ) {
+    let objects: Vec<T> = objects.into_iter().collect();
+    self.delete_names(&objects)
+  }
+}
+
+macro_rules! helpers {
+  ($(
+    $kind:ident,
+    $gen:ident, $gen_into:ident, $delete:ident,
+    $global_gen:ident, $global_gen_into:ident, $global_delete:ident;
+  )*) => {
+    #[cfg(feature = "struct_loader")]
+    impl GlFns {
+      $(
+        /// Makes `N` new objects.
+        ///
+        /// Gives `None` if the GL didn't make all `N`, such as when there's no
+        /// current context. Any that it did make are deleted again.
+        ///
+        /// ## Safety
+        /// * The `GlFns` must be loaded for the GL context that's current on
+        ///   this thread.
+        pub unsafe fn $gen<const N: usize>(&self) -> Option<[$kind; N]> {
+          Target::Struct(self).gen_array()
+        }
+
+        /// Fills the slice with new objects.
+        ///
+        /// A slot is left as `None` if the GL didn't make a name for it. Any
+        /// handles already in the slice are overwritten without being deleted.
+        ///
+        /// ## Safety
+        /// * The `GlFns` must be loaded for the GL context that's current on
+        ///   this thread.
+        pub unsafe fn $gen_into(&self, slots: &mut [Option<$kind>]) {
+          Target::Struct(self).gen_names(slots)
+        }
+
+        /// Deletes all of the objects given.
+        ///
+        /// ## Safety
+        /// * The `GlFns` must be loaded for the GL context that's current on
+        ///   this thread.
+        /// * Each object must be from that context (or a context that shares
+        ///   objects with it), and must not be used after this.
+        pub unsafe fn $delete(&self, objects: impl IntoIterator<Item = $kind>) {
+          Target::Struct(self).delete_all(objects)
+        }
+      )*
+    }
+
+    $(
+      /// Makes `N` new objects with the global loader.
+      ///
+      /// Gives `None` if the GL didn't make all `N`, such as when there's no
+      /// current context. Any that it did make are deleted again.
+      ///
+      /// ## Safety
+      /// * The global functions must be loaded for the GL context that's
+      ///   current on this thread.
+      #[cfg(feature = "global_loader")]
+      #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+      pub unsafe fn $global_gen<const N: usize>() -> Option<[$kind; N]> {
+        Target::Global(core::marker::PhantomData).gen_array()
+      }
+
+      /// Fills the slice with new objects with the global loader.
+      ///
+      /// A slot is left as `None` if the GL didn't make a name for it. Any
+      /// handles already in the slice are overwritten without being deleted.
+      ///
+      /// ## Safety
+      /// * The global functions must be loaded for the GL context that's
+      ///   current on this thread.
+      #[cfg(feature = "global_loader")]
+      #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+      pub unsafe fn $global_gen_into(slots: &mut [Option<$kind>]) {
+        Target::Global(core::marker::PhantomData).gen_names(slots)
+      }
+
+      /// Deletes all of the objects given with the global loader.
+      ///
+      /// ## Safety
+      /// * The global functions must be loaded for the GL context that's
+      ///   current on this thread.
+      /// * Each object must be from that context (or a context that shares
+      ///   objects with it), and must not be used after this.
+      #[cfg(feature = "global_loader")]
+      #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+      pub unsafe fn $global_delete(objects: impl IntoIterator<Item = $kind>) {
+        Target::Global(core::marker::PhantomData).delete_all(objects)
+      }
+    )*
+  };
+}
+
+helpers! {
+  Buffer,
+  gen_buffers, gen_buffers_into, delete_buffers,
+  gen_global_buffers, gen_global_buffers_into, delete_global_buffers;
+  Texture,
+  gen_textures, gen_textures_into, delete_textures,
+  gen_global_textures, gen_global_textures_into, delete_global_textures;
+  Sampler,
+  gen_samplers, gen_samplers_into, delete_samplers,
+  gen_global_samplers, gen_global_samplers_into, delete_global_samplers;
+  Framebuffer,
+  gen_framebuffers, gen_framebuffers_into, delete_framebuffers,
+  gen_global_framebuffers, gen_global_framebuffers_into,
+  delete_global_framebuffers;
+  Renderbuffer,
+  gen_renderbuffers, gen_renderbuffers_into, delete_renderbuffers,
+  gen_global_renderbuffers, gen_global_renderbuffers_into,
+  delete_global_renderbuffers;
+  VertexArray,
+  gen_vertex_arrays, gen_vertex_arrays_into, delete_vertex_arrays,
+  gen_global_vertex_arrays, gen_global_vertex_arrays_into,
+  delete_global_vertex_arrays;
+  Query,
+  gen_queries, gen_queries_into, delete_queries,
+  gen_global_queries, gen_global_queries_into, delete_global_queries;
+  TransformFeedback,
+  gen_transform_feedbacks, gen_transform_feedbacks_into,
+  delete_transform_feedbacks,
+  gen_global_transform_feedbacks, gen_global_transform_feedbacks_into,
+  delete_global_transform_feedbacks;
+}
+
+/// Hands out one kind of object, which it generates a chunk at a time.
+///
+/// Objects that the pool hands out are yours, delete them as normal once
+/// you're done with them. The objects that are still in the pool aren't
+/// deleted when the pool drops (it has no GL to do that with), so call
+/// [`delete_unused`](NamePool::delete_unused) before dropping the pool to not
+/// leak them.
+#[derive(Debug)]
+pub struct NamePool<T: GenObject> {
+  chunk_size: usize,
+  unused: Vec<T>,
+}
+impl<T: GenObject> NamePool<T> {
+  /// Makes an empty pool, which generates `chunk_size` names whenever it runs
+  /// out.
+  ///
+  /// A `chunk_size` of 0 counts as 1.
+  pub fn new(chunk_size: usize) -> Self {
+    Self { chunk_size: chunk_size.max(1), unused: Vec::new() }
+  }
+
+  /// The kind of object that the pool hands out.
+  pub fn kind(&self) -> ObjectKind {
+    T::KIND
+  }
+
+  /// How many names the pool generates at once.
+  pub fn chunk_size(&self) -> usize {
+    self.chunk_size
+  }
+
+  /// How many generated objects are in the pool, waiting to be handed out.
+  pub fn unused(&self) -> usize {
+    self.unused.len()
+  }
+
+  #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+  unsafe fn reserve_with(&mut self, target: &Target<'_>, count: usize) {
+    let have = self.unused.len();
+    if have >= count {
+      return;
+    }
+    // Always at least a full chunk, so that small requests are batched.
+    let more = (count - have).max(self.chunk_size);
+    let mut fresh: Vec<Option<T>> = (0..more).map(|_| None).collect();
+    target.gen_names(&mut fresh);
+    // Objects are popped off the end, so this hands them out in order. A
+    // `None` means the GL didn't make a name, such as with no context.
+    self.unused.extend(fresh.into_iter().rev().flatten());
+  }
+
+  #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+  unsafe fn alloc_with(&mut self, target: &Target<'_>) -> Option<T> {
+    self.reserve_with(target, 1);
+    self.unused.pop()
+  }
+
+  #[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+  unsafe fn delete_unused_with(&mut self, target: &Target<'_>) {
+    target.delete_names(&self.unused);
+    self.unused.clear();
+  }
+
+  /// Takes an object out of the pool, generating another chunk first if the
+  /// pool is empty.
+  ///
+  /// Gives `None` if the GL didn't make any names, such as when there's no
+  /// current context.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  /// * The pool must only be used with that context (or contexts that share
+  ///   objects with it).
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn alloc(&mut self, gl: &GlFns) -> Option<T> {
+    self.alloc_with(&Target::Struct(gl))
+  }
+
+  /// Makes sure that at least `count` objects are in the pool, generating
+  /// them now so that later calls to [`alloc`](NamePool::alloc) don't have
+  /// to.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  /// * The pool must only be used with that context (or contexts that share
+  ///   objects with it).
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn reserve(&mut self, gl: &GlFns, count: usize) {
+    self.reserve_with(&Target::Struct(gl), count)
+  }
+
+  /// Deletes all the objects that are still in the pool.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  /// * The pool must only be used with that context (or contexts that share
+  ///   objects with it).
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn delete_unused(&mut self, gl: &GlFns) {
+    self.delete_unused_with(&Target::Struct(gl))
+  }
+
+  /// Takes an object out of the pool with the global loader.
+  ///
+  /// This works like [`alloc`](NamePool::alloc).
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current
+  ///   on this thread.
+  /// * The pool must only be used with that context (or contexts that share
+  ///   objects with it).
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn alloc_global(&mut self) -> Option<T> {
+    self.alloc_with(&Target::Global(core::marker::PhantomData))
+  }
+
+  /// Fills the pool with the global loader.
+  ///
+  /// This works like [`reserve`](NamePool::reserve).
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current
+  ///   on this thread.
+  /// * The pool must only be used with that context (or contexts that share
+  ///   objects with it).
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn reserve_global(&mut self, count: usize) {
+    self.reserve_with(&Target::Global(core::marker::PhantomData), count)
+  }
+
+  /// Deletes all the objects that are still in the pool with the global
+  /// loader.
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current
+  ///   on this thread.
+  /// * The pool must only be used with that context (or contexts that share
+  ///   objects with it).
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn delete_unused_global(&mut self) {
+    self.delete_unused_with(&Target::Global(core::marker::PhantomData))
+  }
+}
@@ -0,0 +1,244 @@
+//! Loading GL straight from the system's `libEGL` and `libGLESv2`.
+//!
+//! Instead of getting a `get_proc_address` from a windowing library, a
+//! [`NativeGl`] opens the EGL and GLES libraries with `dlopen`, and looks up
+//! commands with `eglGetProcAddress`, falling back to `dlsym` on the GLES
+//! library for anything that `eglGetProcAddress` doesn't give (older EGL
+//! versions only give extension commands).
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f() -> Result<(), gles30::native::NativeLoadError> {
+//! use gles30::{native::NativeGl, GlFns};
+//! let native = NativeGl::open()?;
+//! // ... make an EGL context current ...
+//! let gl = GlFns::load_with(|name| native.get_proc_address(name));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! This only works on Unix-like systems.
+
+use super::*;
+use std::{
+  ffi::{CStr, CString, OsStr},
+  os::unix::ffi::OsStrExt,
+  string::{String, ToString},
+};
+
+#[cfg_attr(any(target_os = "linux", target_os = "android"), link(name = "dl"))]
+extern "C" {
+  fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+  fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+  fn dlclose(handle: *mut c_void) -> c_int;
+  fn dlerror() -> *mut c_char;
+}
+
+/// Resolve all symbols when the library is opened. This is the same value on
+/// all the Unix systems that we support.
+const RTLD_NOW: c_int = 2;
+
+/// The EGL library names that [`NativeGl::open`] tries, in order.
+#[cfg(target_os = "macos")]
+pub const DEFAULT_EGL_NAMES: &[&str] = &["libEGL.dylib"];
+/// The EGL library names that [`NativeGl::open`] tries, in order.
+#[cfg(not(target_os = "macos"))]
+pub const DEFAULT_EGL_NAMES: &[&str] = &["libEGL.so.1", "libEGL.so"];
+
+/// The GLES library names that [`NativeGl::open`] tries, in order.
+#[cfg(target_os = "macos")]
+pub const DEFAULT_GLES_NAMES: &[&str] = &["libGLESv2.dylib"];
+/// The GLES library names that [`NativeGl::open`] tries, in order.
+#[cfg(not(target_os = "macos"))]
+pub const DEFAULT_GLES_NAMES: &[&str] = &["libGLESv2.so.2", "libGLESv2.so"];
+
+/// An error from opening a library, or from finding a symbol in it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NativeLoadError {
+  /// The library (or symbol) that couldn't be found.
+  pub name: String,
+  /// The error message from `dlerror`.
+  pub message: String,
+}
+impl core::fmt::Display for NativeLoadError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "couldn't load `{}`: {}", self.name, self.message)
+  }
+}
+impl std::error::Error for NativeLoadError {}
+
+/// Gets the `dlerror` message, for an error about `name`.
+fn last_error(name: &str) -> NativeLoadError {
+  // Safety: `dlerror` gives null or a null-terminated string.
+  let message = unsafe {
+    let p = dlerror();
+    if p.is_null() {
+      String::from("unknown error")
+    } else {
+      CStr::from_ptr(p).to_string_lossy().into_owned()
+    }
+  };
+  NativeLoadError { name: name.to_string(), message }
+}
+
+/// A shared library opened with `dlopen`, which is closed when dropped.
+#[derive(Debug)]
+pub struct Library {
+  handle: *mut c_void,
+}
+// Safety: the `dl` functions can be used from any thread.
+unsafe impl Send for Library {}
+unsafe impl Sync for Library {}
+impl Library {
+  /// Opens a library by file name or path.
+  ///
+  /// Just a file name (with no `/`) searches the system's library paths.
+  pub fn open<P: AsRef<OsStr>>(path: P) -> Result<Self, NativeLoadError> {
+    let path = path.as_ref();
+    let name = path.to_string_lossy();
+    let c_path =
+      CString::new(path.as_bytes()).map_err(|_| NativeLoadError {
+        name: name.to_string(),
+        message: String::from("the path has a null byte in it"),
+      })?;
+    // Safety: the path is null-terminated. Loading a library runs its
+    // initializers, which we trust to be sound for GL libraries.
+    let handle = unsafe { dlopen(c_path.as_ptr(), RTLD_NOW) };
+    if handle.is_null() {
+      Err(last_error(&name))
+    } else {
+      Ok(Self { handle })
+    }
+  }
+
+  /// Opens the first library in `names` that can be opened.
+  ///
+  /// If none can be opened, the error is from the last one.
+  pub fn open_first(names: &[&str]) -> Result<Self, NativeLoadError> {
+    let mut err = NativeLoadError {
+      name: String::new(),
+      message: String::from("no library names given"),
+    };
+    for name in names {
+      match Self::open(name) {
+        Ok(lib) => return Ok(lib),
+        Err(e) => err = e,
+      }
+    }
+    Err(err)
+  }
+
+  /// Looks up a symbol, giving null if it's not in the library.
+  pub fn symbol(&self, name: &CStr) -> *mut c_void {
+    // Safety: the handle is open, and the name is null-terminated.
+    unsafe { dlsym(self.handle, name.as_ptr()) }
+  }
+
+  /// The raw handle from `dlopen`.
+  pub fn handle(&self) -> *mut c_void {
+    self.handle
+  }
+}
+impl Drop for Library {
+  fn drop(&mut self) {
+    // Safety: the handle is open, and nothing can use it after this.
+    unsafe { dlclose(self.handle) };
+  }
+}
+
+type EglGetProcAddress =
+  unsafe extern "system" fn(*const c_char) -> *mut c_void;
+
+/// The system's EGL and GLES libraries, for loading GL commands.
+///
+/// The libraries stay open as long as this does, so keep it alive for as long
+/// as you use any GL (or EGL) pointers obtained through it.
+pub struct NativeGl {
+  egl: Library,
+  gles: Library,
+  egl_get_proc_address: EglGetProcAddress,
+}
+impl core::fmt::Debug for NativeGl {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("NativeGl")
+      .field("egl", &self.egl)
+      .field("gles", &self.gles)
+      .field(
+        "egl_get_proc_address",
+        &(self.egl_get_proc_address as *const c_void),
+      )
+      .finish()
+  }
+}
+impl NativeGl {
+  /// Opens the system's libraries, trying the names in
+  /// [`DEFAULT_EGL_NAMES`] and [`DEFAULT_GLES_NAMES`].
+  pub fn open() -> Result<Self, NativeLoadError> {
+    Self::from_libraries(
+      Library::open_first(DEFAULT_EGL_NAMES)?,
+      Library::open_first(DEFAULT_GLES_NAMES)?,
+    )
+  }
+
+  /// Opens the EGL and GLES libraries at the paths given.
+  pub fn open_paths<E, G>(egl: E, gles: G) -> Result<Self, NativeLoadError>
+  where
+    E: AsRef<OsStr>,
+    G: AsRef<OsStr>,
+  {
+    Self::from_libraries(Library::open(egl)?, Library::open(gles)?)
+  }
+
+  /// Uses libraries that are already open.
+  ///
+  /// Fails if the EGL library doesn't have `eglGetProcAddress`.
+  pub fn from_libraries(
+    egl: Library, gles: Library,
+  ) -> Result<Self, NativeLoadError> {
+    let name = CStr::from_bytes_with_nul(b"eglGetProcAddress\0").unwrap();
+    let p = egl.symbol(name);
+    if p.is_null() {
+      return Err(last_error("eglGetProcAddress"));
+    }
+    // Safety: this is the signature of `eglGetProcAddress`.
+    let egl_get_proc_address =
+      unsafe { transmute::<*mut c_void, EglGetProcAddress>(p) };
+    Ok(Self { egl, gles, egl_get_proc_address })
+  }
+
+  /// Gets a GL (or EGL) command by its null-terminated name.
+  ///
+  /// This asks `eglGetProcAddress` first, then looks in the GLES library with
+  /// `dlsym`, and then in the EGL library. This can be given to
+  /// [`GlFns::load_with`] or [`load_global_gl_with`] (in a closure). Unknown
+  /// names give a null pointer.
+  ///
+  /// ## Safety
+  /// * `name` must be null, or point to a null-terminated string, as with any
+  ///   other `get_proc_address` function.
+  pub unsafe fn get_proc_address(&self, name: *const c_char) -> *mut c_void {
+    if name.is_null() {
+      return null_mut();
+    }
+    let p = (self.egl_get_proc_address)(name);
+    if !p.is_null() {
+      return p;
+    }
+    let name = CStr::from_ptr(name);
+    let p = self.gles.symbol(name);
+    if !p.is_null() {
+      return p;
+    }
+    self.egl.symbol(name)
+  }
+
+  /// The opened EGL library.
+  pub fn egl(&self) -> &Library {
+    &self.egl
+  }
+
+  /// The opened GLES library.
+  pub fn gles(&self) -> &Library {
+    &self.gles
+  }
+}
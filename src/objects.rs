@@ -0,0 +1,486 @@
+//! Typed handles for GL objects.
+//!
+//! Each kind of object gets its own type, such as [`Buffer`] or [`Texture`],
+//! so that passing a texture where a buffer is expected is a compile error
+//! instead of a GL error. The handles are `#[repr(transparent)]` wrappers
+//! around a non-zero name, so an `Option` of one is still the size of a
+//! `GLuint` (or a `GLsync`, for [`Fence`]).
+//!
+//! A handle doesn't delete its object on its own, because that needs a GL to
+//! call. There's two ways to delete one:
+//! * Explicitly, with the handle's `delete` (or `delete_global`) method.
+//! * When an [`Owned`] wrapping the handle is dropped.
+//!
+//! Dropping a bare handle leaks the object, just like losing a `GLuint` name
+//! would.
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f(gl: &gles30::GlFns) {
+//! use gles30::{objects::*, *};
+//! let vbo = Owned::new(gl, Buffer::gen(gl).unwrap());
+//! gl.BindBuffer(GL_ARRAY_BUFFER, vbo.name());
+//! let tex = Texture::gen(gl).unwrap();
+//! gl.BindTexture(GL_TEXTURE_2D, tex.name());
+//! tex.delete(gl);
+//! // `vbo` is deleted here, when it drops.
+//! # }
+//! ```
+
+use super::*;
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+use core::{mem::ManuallyDrop, ops::Deref};
+use core::{num::NonZeroU32, ptr::NonNull};
+
+/// A handle to a GL object, which can be deleted.
+///
+/// This is what lets an [`Owned`] delete any kind of object.
+pub trait Object: Sized {
+  /// Deletes the object with a [`GlFns`].
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  /// * The object must be from that context (or a context that shares objects
+  ///   with it), and it must not be used after this.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  unsafe fn delete(self, gl: &GlFns);
+
+  /// Deletes the object with the global loader.
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current on
+  ///   this thread.
+  /// * The object must be from that context (or a context that shares objects
+  ///   with it), and it must not be used after this.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  unsafe fn delete_global(self);
+}
+
+macro_rules! name_objects {
+  ($(
+    $(#[$m:meta])*
+    $name:ident, $gl_gen:ident, $gl_delete:ident, $fn_gen:ident, $fn_delete:ident;
+  )*) => {
+    $(
+      $(#[$m])*
+      #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+      #[repr(transparent)]
+      pub struct $name(NonZeroU32);
+      impl $name {
+        /// Wraps an existing name, or gives `None` if the name is 0.
+        ///
+        /// Like any handle, this doesn't delete the object when it's dropped.
+        /// Use [`delete`](Self::delete) or an [`Owned`] for that.
+        pub fn from_raw(name: GLuint) -> Option<Self> {
+          NonZeroU32::new(name).map(Self)
+        }
+
+        /// The GL name of the object.
+        pub const fn name(&self) -> GLuint {
+          self.0.get()
+        }
+
+        /// Gives up the handle without deleting the object.
+        pub const fn into_raw(self) -> GLuint {
+          self.0.get()
+        }
+
+        /// Makes a new name with the `glGen*` command for this kind of object.
+        ///
+        /// Gives `None` if the GL gave back 0, such as when there's no
+        /// current context.
+        ///
+        /// ## Safety
+        /// * The `GlFns` must be loaded for the GL context that's current on
+        ///   this thread.
+        #[cfg(feature = "struct_loader")]
+        #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+        pub unsafe fn gen(gl: &GlFns) -> Option<Self> {
+          let mut name = 0;
+          gl.$fn_gen(1, &mut name);
+          Self::from_raw(name)
+        }
+
+        /// Makes a new name with the global loader.
+        ///
+        /// This works like [`gen`](Self::gen).
+        ///
+        /// ## Safety
+        /// * The global functions must be loaded for the GL context that's
+        ///   current on this thread.
+        #[cfg(feature = "global_loader")]
+        #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+        pub unsafe fn gen_global() -> Option<Self> {
+          let mut name = 0;
+          global_commands::$gl_gen(1, &mut name);
+          Self::from_raw(name)
+        }
+
+        /// Deletes the object with the `glDelete*` command for this kind of
+        /// object.
+        ///
+        /// ## Safety
+        /// * The `GlFns` must be loaded for the GL context that's current on
+        ///   this thread.
+        /// * The object must be from that context (or a context that shares
+        ///   objects with it), and it must not be used after this.
+        #[cfg(feature = "struct_loader")]
+        #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+        pub unsafe fn delete(self, gl: &GlFns) {
+          gl.$fn_delete(1, &self.into_raw());
+        }
+
+        /// Deletes the object with the global loader.
+        ///
+        /// ## Safety
+        /// * The global functions must be loaded for the GL context that's
+        ///   current on this thread.
+        /// * The object must be from that context (or a context that shares
+        ///   objects with it), and it must not be used after this.
+        #[cfg(feature = "global_loader")]
+        #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+        pub unsafe fn delete_global(self) {
+          global_commands::$gl_delete(1, &self.into_raw());
+        }
+      }
+      impl Object for $name {
+        #[cfg(feature = "struct_loader")]
+        unsafe fn delete(self, gl: &GlFns) {
+          $name::delete(self, gl)
+        }
+        #[cfg(feature = "global_loader")]
+        unsafe fn delete_global(self) {
+          $name::delete_global(self)
+        }
+      }
+    )*
+  };
+}
+
+name_objects! {
+  /// A buffer object.
+  Buffer, glGenBuffers, glDeleteBuffers, GenBuffers, DeleteBuffers;
+  /// A texture object.
+  Texture, glGenTextures, glDeleteTextures, GenTextures, DeleteTextures;
+  /// A sampler object.
+  Sampler, glGenSamplers, glDeleteSamplers, GenSamplers, DeleteSamplers;
+  /// A framebuffer object.
+  Framebuffer, glGenFramebuffers, glDeleteFramebuffers, GenFramebuffers,
+  DeleteFramebuffers;
+  /// A renderbuffer object.
+  Renderbuffer, glGenRenderbuffers, glDeleteRenderbuffers, GenRenderbuffers,
+  DeleteRenderbuffers;
+  /// A vertex array object.
+  VertexArray, glGenVertexArrays, glDeleteVertexArrays, GenVertexArrays,
+  DeleteVertexArrays;
+  /// A query object.
+  Query, glGenQueries, glDeleteQueries, GenQueries, DeleteQueries;
+  /// A transform feedback object.
+  TransformFeedback, glGenTransformFeedbacks, glDeleteTransformFeedbacks,
+  GenTransformFeedbacks, DeleteTransformFeedbacks;
+}
+
+macro_rules! created_objects {
+  ($(
+    $(#[$m:meta])*
+    $name:ident, $gl_delete:ident, $fn_delete:ident;
+  )*) => {
+    $(
+      $(#[$m])*
+      #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+      #[repr(transparent)]
+      pub struct $name(NonZeroU32);
+      impl $name {
+        /// Wraps an existing name, or gives `None` if the name is 0.
+        ///
+        /// Like any handle, this doesn't delete the object when it's dropped.
+        /// Use [`delete`](Self::delete) or an [`Owned`] for that.
+        pub fn from_raw(name: GLuint) -> Option<Self> {
+          NonZeroU32::new(name).map(Self)
+        }
+
+        /// The GL name of the object.
+        pub const fn name(&self) -> GLuint {
+          self.0.get()
+        }
+
+        /// Gives up the handle without deleting the object.
+        pub const fn into_raw(self) -> GLuint {
+          self.0.get()
+        }
+
+        /// Deletes the object with the `glDelete*` command for this kind of
+        /// object.
+        ///
+        /// ## Safety
+        /// * The `GlFns` must be loaded for the GL context that's current on
+        ///   this thread.
+        /// * The object must be from that context (or a context that shares
+        ///   objects with it), and it must not be used after this.
+        #[cfg(feature = "struct_loader")]
+        #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+        pub unsafe fn delete(self, gl: &GlFns) {
+          gl.$fn_delete(self.into_raw());
+        }
+
+        /// Deletes the object with the global loader.
+        ///
+        /// ## Safety
+        /// * The global functions must be loaded for the GL context that's
+        ///   current on this thread.
+        /// * The object must be from that context (or a context that shares
+        ///   objects with it), and it must not be used after this.
+        #[cfg(feature = "global_loader")]
+        #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+        pub unsafe fn delete_global(self) {
+          global_commands::$gl_delete(self.into_raw());
+        }
+      }
+      impl Object for $name {
+        #[cfg(feature = "struct_loader")]
+        unsafe fn delete(self, gl: &GlFns) {
+          $name::delete(self, gl)
+        }
+        #[cfg(feature = "global_loader")]
+        unsafe fn delete_global(self) {
+          $name::delete_global(self)
+        }
+      }
+    )*
+  };
+}
+
+created_objects! {
+  /// A shader object.
+  Shader, glDeleteShader, DeleteShader;
+  /// A program object.
+  Program, glDeleteProgram, DeleteProgram;
+}
+
+impl Shader {
+  /// Makes a new shader of the `kind` given (eg: `GL_VERTEX_SHADER`) with
+  /// `glCreateShader`.
+  ///
+  /// Gives `None` if the GL gave back 0, such as when `kind` isn't a shader
+  /// type.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn create(gl: &GlFns, kind: GLenum) -> Option<Self> {
+    Self::from_raw(gl.CreateShader(kind))
+  }
+
+  /// Makes a new shader with the global loader.
+  ///
+  /// This works like [`create`](Self::create).
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current on
+  ///   this thread.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn create_global(kind: GLenum) -> Option<Self> {
+    Self::from_raw(global_commands::glCreateShader(kind))
+  }
+}
+
+impl Program {
+  /// Makes a new program with `glCreateProgram`.
+  ///
+  /// Gives `None` if the GL gave back 0, such as when there's no current
+  /// context.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn create(gl: &GlFns) -> Option<Self> {
+    Self::from_raw(gl.CreateProgram())
+  }
+
+  /// Makes a new program with the global loader.
+  ///
+  /// This works like [`create`](Self::create).
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current on
+  ///   this thread.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn create_global() -> Option<Self> {
+    Self::from_raw(global_commands::glCreateProgram())
+  }
+}
+
+/// A sync object.
+///
+/// GLES 3.0 only has one kind of sync object, the fence made by
+/// `glFenceSync`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Fence(NonNull<__GLsync>);
+impl Fence {
+  /// Wraps an existing sync, or gives `None` if it's null.
+  ///
+  /// Like any handle, this doesn't delete the object when it's dropped. Use
+  /// [`delete`](Self::delete) or an [`Owned`] for that.
+  pub fn from_raw(sync: GLsync) -> Option<Self> {
+    NonNull::new(sync).map(Self)
+  }
+
+  /// The `GLsync` of the object.
+  pub const fn as_raw(&self) -> GLsync {
+    self.0.as_ptr()
+  }
+
+  /// Gives up the handle without deleting the object.
+  pub const fn into_raw(self) -> GLsync {
+    self.0.as_ptr()
+  }
+
+  /// Inserts a new fence into the command stream with `glFenceSync`.
+  ///
+  /// Gives `None` if the GL gave back null, such as when there's no current
+  /// context.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn insert(gl: &GlFns) -> Option<Self> {
+    Self::from_raw(gl.FenceSync(GL_SYNC_GPU_COMMANDS_COMPLETE, 0))
+  }
+
+  /// Inserts a new fence with the global loader.
+  ///
+  /// This works like [`insert`](Self::insert).
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current on
+  ///   this thread.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn insert_global() -> Option<Self> {
+    Self::from_raw(global_commands::glFenceSync(
+      GL_SYNC_GPU_COMMANDS_COMPLETE,
+      0,
+    ))
+  }
+
+  /// Deletes the object with `glDeleteSync`.
+  ///
+  /// ## Safety
+  /// * The `GlFns` must be loaded for the GL context that's current on this
+  ///   thread.
+  /// * The object must be from that context (or a context that shares objects
+  ///   with it), and it must not be used after this.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub unsafe fn delete(self, gl: &GlFns) {
+    gl.DeleteSync(self.into_raw());
+  }
+
+  /// Deletes the object with the global loader.
+  ///
+  /// ## Safety
+  /// * The global functions must be loaded for the GL context that's current on
+  ///   this thread.
+  /// * The object must be from that context (or a context that shares objects
+  ///   with it), and it must not be used after this.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub unsafe fn delete_global(self) {
+    global_commands::glDeleteSync(self.into_raw());
+  }
+}
+impl Object for Fence {
+  #[cfg(feature = "struct_loader")]
+  unsafe fn delete(self, gl: &GlFns) {
+    Fence::delete(self, gl)
+  }
+  #[cfg(feature = "global_loader")]
+  unsafe fn delete_global(self) {
+    Fence::delete_global(self)
+  }
+}
+
+/// An object that's deleted when this is dropped.
+///
+/// This derefs to the handle inside, so methods like `name` can be called
+/// on it directly. The GL context must still be current when this drops. If
+/// the context is already gone, use [`into_inner`](Owned::into_inner) or
+/// [`core::mem::forget`] on this instead.
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+pub struct Owned<'gl, T: Object> {
+  target: Target<'gl>,
+  object: ManuallyDrop<T>,
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl<'gl, T: Object> Owned<'gl, T> {
+  /// Wraps an object so that it's deleted with `gl` when dropped.
+  #[cfg(feature = "struct_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+  pub fn new(gl: &'gl GlFns, object: T) -> Self {
+    Self { target: Target::Struct(gl), object: ManuallyDrop::new(object) }
+  }
+
+  /// Wraps an object so that it's deleted with the global loader when
+  /// dropped.
+  #[cfg(feature = "global_loader")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+  pub fn new_global(object: T) -> Self {
+    Self {
+      target: Target::Global(core::marker::PhantomData),
+      object: ManuallyDrop::new(object),
+    }
+  }
+
+  /// Gives back the handle without deleting the object.
+  pub fn into_inner(self) -> T {
+    let this = ManuallyDrop::new(self);
+    // Safety: `this` is never dropped, so the object is only read out once.
+    unsafe { core::ptr::read(&*this.object) }
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl<T: Object + core::fmt::Debug> core::fmt::Debug for Owned<'_, T> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_tuple("Owned").field(&*self.object).finish()
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl<T: Object> Deref for Owned<'_, T> {
+  type Target = T;
+  fn deref(&self) -> &T {
+    &self.object
+  }
+}
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl<T: Object> Drop for Owned<'_, T> {
+  fn drop(&mut self) {
+    // Safety: the type's docs require the context to still be current, and
+    // the object isn't used again after this.
+    unsafe { self.target.delete_object(core::ptr::read(&*self.object)) }
+  }
+}
+
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl Target<'_> {
+  /// Deletes any kind of object with this loader.
+  pub(crate) unsafe fn delete_object<T: Object>(&self, object: T) {
+    match self {
+      #[cfg(feature = "global_loader")]
+      Target::Global(_) => object.delete_global(),
+      #[cfg(feature = "struct_loader")]
+      Target::Struct(gl) => object.delete(gl),
+    }
+  }
+}
@@ -0,0 +1,210 @@
+//! Recording of GL calls as [`GlCall`] values.
+//!
+//! With the `record_calls` feature enabled, every GL command sends a
+//! [`GlCall`] to the active [`CallSink`] right after the command returns. This
+//! works with both loaders:
+//! * For the global loader, use [`set_global_call_sink`].
+//! * For the struct loader, use [`GlFns::set_call_sink`]. Each `GlFns` has its
+//!   own sink.
+//!
+//! When no sink is set the recording does nothing (beyond a quick check).
+//!
+//! The [`CallLog`] sink is the simplest way to check what a piece of code
+//! sends to GL:
+//!
+//! ```
+//! # #[cfg(all(feature = "struct_loader", feature = "mock"))]
+//! # unsafe {
+//! use gles30::{record::CallLog, *};
+//! let mut gl = GlFns::load_with(|name| mock::get_proc_address(name));
+//! let log = CallLog::new();
+//! gl.set_call_sink(log.clone());
+//! gl.ClearColor(0.0, 0.0, 0.0, 1.0);
+//! gl.DrawArrays(GL_TRIANGLES, 0, 3);
+//! assert_eq!(
+//!   log.take(),
+//!   &[
+//!     GlCall::ClearColor { red: 0.0, green: 0.0, blue: 0.0, alpha: 1.0 },
+//!     GlCall::DrawArrays { mode: GL_TRIANGLES, first: 0, count: 3 },
+//!   ]
+//! );
+//! # }
+//! ```
+//!
+//! A sink must not call GL itself (through the same loader that is recording
+//! into it), or it will deadlock.
+
+use super::*;
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+use std::boxed::Box;
+use std::{
+  sync::{Arc, Mutex, PoisonError},
+  vec::Vec,
+};
+
+/// Something that [`GlCall`] values can be recorded into.
+///
+/// This is implemented for all `FnMut(GlCall)` closures that are `Send`, and
+/// for `Arc<Mutex<S>>` of any sink `S`, which lets you keep a handle to a sink
+/// after giving it to a loader.
+pub trait CallSink: Send {
+  /// Records one call. This happens after the call has been made.
+  fn record(&mut self, call: GlCall);
+}
+
+impl<F> CallSink for F
+where
+  F: FnMut(GlCall) + Send,
+{
+  fn record(&mut self, call: GlCall) {
+    self(call)
+  }
+}
+
+impl<S> CallSink for Arc<Mutex<S>>
+where
+  S: CallSink,
+{
+  fn record(&mut self, call: GlCall) {
+    self.lock().unwrap_or_else(PoisonError::into_inner).record(call)
+  }
+}
+
+/// A [`CallSink`] that collects calls into a list.
+///
+/// Clones of a `CallLog` share the same list, so you can keep one clone and
+/// give another to the loader.
+#[derive(Debug, Clone, Default)]
+pub struct CallLog {
+  calls: Arc<Mutex<Vec<GlCall>>>,
+}
+impl CallLog {
+  /// Makes a new, empty log.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Takes all calls recorded so far, leaving the log empty.
+  pub fn take(&self) -> Vec<GlCall> {
+    core::mem::replace(&mut *self.lock(), Vec::new())
+  }
+
+  /// Gets a copy of all calls recorded so far.
+  pub fn calls(&self) -> Vec<GlCall> {
+    self.lock().clone()
+  }
+
+  /// The number of calls recorded so far.
+  pub fn len(&self) -> usize {
+    self.lock().len()
+  }
+
+  /// If no calls have been recorded.
+  pub fn is_empty(&self) -> bool {
+    self.lock().is_empty()
+  }
+
+  /// Clears the log.
+  pub fn clear(&self) {
+    self.lock().clear()
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, Vec<GlCall>> {
+    self.calls.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+impl CallSink for CallLog {
+  fn record(&mut self, call: GlCall) {
+    self.lock().push(call)
+  }
+}
+
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+type BoxedSink = Box<dyn CallSink>;
+
+#[cfg(feature = "global_loader")]
+static GLOBAL_SINK: LazyStatic<Mutex<Option<BoxedSink>>> = LazyStatic::new();
+#[cfg(feature = "global_loader")]
+static GLOBAL_SINK_IS_SET: core::sync::atomic::AtomicBool =
+  core::sync::atomic::AtomicBool::new(false);
+
+/// Sets the sink that the global GL functions record into.
+///
+/// Any previous sink is replaced and returned.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn set_global_call_sink<S: CallSink + 'static>(
+  sink: S,
+) -> Option<Box<dyn CallSink>> {
+  let mut guard =
+    GLOBAL_SINK.get().lock().unwrap_or_else(PoisonError::into_inner);
+  GLOBAL_SINK_IS_SET.store(true, RELAX);
+  guard.replace(Box::new(sink))
+}
+
+/// Stops the global GL functions from recording calls.
+///
+/// The previous sink is returned, if there was one.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn clear_global_call_sink() -> Option<Box<dyn CallSink>> {
+  let mut guard =
+    GLOBAL_SINK.get().lock().unwrap_or_else(PoisonError::into_inner);
+  GLOBAL_SINK_IS_SET.store(false, RELAX);
+  guard.take()
+}
+
+#[cfg(feature = "global_loader")]
+pub(crate) fn record_global_call(call: GlCall) {
+  if !GLOBAL_SINK_IS_SET.load(RELAX) {
+    return;
+  }
+  let mut guard =
+    GLOBAL_SINK.get().lock().unwrap_or_else(PoisonError::into_inner);
+  if let Some(sink) = guard.as_mut() {
+    sink.record(call)
+  }
+}
+
+#[cfg(feature = "struct_loader")]
+impl GlFns {
+  /// Sets the sink that this `GlFns` records calls into.
+  ///
+  /// Any previous sink is replaced and returned.
+  pub fn set_call_sink<S: CallSink + 'static>(
+    &mut self, sink: S,
+  ) -> Option<Box<dyn CallSink>> {
+    let new: Box<Mutex<BoxedSink>> = Box::new(Mutex::new(Box::new(sink)));
+    let old = self.call_sink.swap(Box::into_raw(new) as *mut c_void, RELAX);
+    Self::unbox_sink(old)
+  }
+
+  /// Stops this `GlFns` from recording calls.
+  ///
+  /// The previous sink is returned, if there was one.
+  pub fn clear_call_sink(&mut self) -> Option<Box<dyn CallSink>> {
+    let old = self.call_sink.swap(null_mut(), RELAX);
+    Self::unbox_sink(old)
+  }
+
+  fn unbox_sink(p: *mut c_void) -> Option<BoxedSink> {
+    if p.is_null() {
+      None
+    } else {
+      // Safety: non-null sink pointers always come from `Box::into_raw` in
+      // `set_call_sink`, and the `&mut self` there and in `clear_call_sink`
+      // means that no call can be using the sink right now.
+      let b = unsafe { Box::from_raw(p as *mut Mutex<BoxedSink>) };
+      Some(b.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+  }
+
+  pub(crate) fn record_call(&self, call: GlCall) {
+    let p = self.call_sink.load(RELAX) as *mut Mutex<BoxedSink>;
+    if !p.is_null() {
+      // Safety: the sink can only be freed through `&mut self`.
+      let sink = unsafe { &*p };
+      sink.lock().unwrap_or_else(PoisonError::into_inner).record(call)
+    }
+  }
+}
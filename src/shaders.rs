@@ -0,0 +1,400 @@
+//! Compiling shaders and linking programs, with their info logs parsed.
+//!
+//! [`compile_shader`] and [`link_program`] (or their global versions) do all
+//! the usual steps, and give a typed [`Shader`] or [`Program`] on success. On
+//! failure, the error has the info log, along with that log split into
+//! [`Diagnostic`]s, so that a tool can jump straight to each failing line.
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f(gl: &gles30::GlFns, vs_src: &str, fs_src: &str) {
+//! use gles30::{shaders::*, *};
+//! let header = "#version 300 es\nprecision mediump float;\n";
+//! let vs = match compile_shader(gl, GL_VERTEX_SHADER, &[header, vs_src]) {
+//!   Ok(vs) => vs,
+//!   Err(e) => {
+//!     for d in &e.diagnostics {
+//!       eprintln!("vs {}", d);
+//!     }
+//!     return;
+//!   }
+//! };
+//! let fs = compile_shader(gl, GL_FRAGMENT_SHADER, &[header, fs_src]).unwrap();
+//! let program = link_program(gl, &[vs, fs], &[(0, "position")]).unwrap();
+//! # }
+//! ```
+//!
+//! The log formats of Mesa, Adreno, Mali, and ANGLE (which covers most of the
+//! other drivers too) are understood. See [`parse_info_log`] for the details.
+
+use super::*;
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+use crate::objects::{Program, Shader};
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+use std::ffi::CString;
+use std::{string::String, vec::Vec};
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+  /// The shader or program failed because of this.
+  Error,
+  /// Something that's allowed, but likely a mistake.
+  Warning,
+  /// Extra info, usually about a nearby error.
+  Note,
+}
+
+/// One message from an info log.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+  /// The source string ("file") number that the message is about.
+  ///
+  /// Drivers differ on this. Some give the index of the string within the
+  /// strings given to [`compile_shader`], and others (such as Mesa) always
+  /// give 0 and count the lines of all the strings as if they were one.
+  ///
+  /// This is `None` for messages that aren't about a place in the source.
+  pub file: Option<u32>,
+  /// The line (starting from 1).
+  ///
+  /// A `#line` directive changes what the GL reports here.
+  pub line: Option<u32>,
+  /// The column (starting from 1), for the drivers that report it.
+  pub column: Option<u32>,
+  /// How serious the message is.
+  pub severity: Severity,
+  /// The message itself, without the location or severity.
+  pub message: String,
+}
+impl core::fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(file) = self.file {
+      write!(f, "{}:", file)?;
+    }
+    if let Some(line) = self.line {
+      write!(f, "{}:", line)?;
+    }
+    if let Some(column) = self.column {
+      write!(f, "{}:", column)?;
+    }
+    if self.file.is_some() || self.line.is_some() {
+      write!(f, " ")?;
+    }
+    let severity = match self.severity {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+      Severity::Note => "note",
+    };
+    write!(f, "{}: {}", severity, self.message)
+  }
+}
+
+/// Splits an info log into its messages.
+///
+/// Each line of the log becomes one [`Diagnostic`]. These line formats are
+/// understood:
+/// * Mesa: `0:12(5): error: message`
+/// * Adreno and ANGLE: `ERROR: 0:12: message`
+/// * Mali: `0:12: L0002: message` (or with an `ERROR:` in front)
+/// * Lines without a location, such as `error: message` from a link.
+///
+/// A line with no severity counts as an error. The "N compilation errors"
+/// summary line that some drivers add at the end is skipped, as are blank
+/// lines.
+pub fn parse_info_log(log: &str) -> Vec<Diagnostic> {
+  log.lines().filter_map(parse_line).collect()
+}
+
+/// Takes a severity word (and its `:`) off the front of `s`.
+fn take_severity(s: &str) -> (Option<Severity>, &str) {
+  let s = s.trim_start();
+  let word_end = s.find(':').unwrap_or(0);
+  let severity = match s[..word_end].to_ascii_lowercase().as_str() {
+    "error" | "fatal error" => Severity::Error,
+    "warning" => Severity::Warning,
+    "note" | "info" | "information" => Severity::Note,
+    _ => return (None, s),
+  };
+  (Some(severity), s[word_end + 1..].trim_start())
+}
+
+/// Takes a number off the front of `s`.
+fn take_number(s: &str) -> Option<(u32, &str)> {
+  let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+  let n = s[..end].parse().ok()?;
+  Some((n, &s[end..]))
+}
+
+/// Takes a `file:line:` or `file:line(column):` location off the front of `s`.
+fn take_location(s: &str) -> Option<(u32, u32, Option<u32>, &str)> {
+  let (file, rest) = take_number(s)?;
+  let rest = strip_prefix(rest, ":")?;
+  let (line, rest) = take_number(rest)?;
+  let (column, rest) = match strip_prefix(rest, "(") {
+    Some(rest) => {
+      let (column, rest) = take_number(rest)?;
+      (Some(column), strip_prefix(rest, ")")?)
+    }
+    None => (None, rest),
+  };
+  let rest = strip_prefix(rest, ":")?;
+  Some((file, line, column, rest.trim_start()))
+}
+
+fn parse_line(line: &str) -> Option<Diagnostic> {
+  let line = line.trim();
+  if line.is_empty() {
+    return None;
+  }
+  let (mut severity, rest) = take_severity(line);
+  let (file, line_number, column, mut message) = match take_location(rest) {
+    Some((file, line, column, rest)) => (Some(file), Some(line), column, rest),
+    None => (None, None, None, rest),
+  };
+  if severity.is_none() {
+    // Mesa puts the severity after the location instead.
+    let (after, rest) = take_severity(message);
+    severity = after;
+    message = rest;
+  }
+  if file.is_none() && is_summary(message) {
+    return None;
+  }
+  Some(Diagnostic {
+    file,
+    line: line_number,
+    column,
+    severity: severity.unwrap_or(Severity::Error),
+    message: String::from(message),
+  })
+}
+
+/// If the message is a count like "2 compilation errors.  No code generated."
+fn is_summary(message: &str) -> bool {
+  match take_number(message) {
+    Some((_, rest)) => rest.trim_start().starts_with("compilation error"),
+    None => false,
+  }
+}
+
+/// The error when a shader doesn't compile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderError {
+  /// The kind of shader, such as `GL_VERTEX_SHADER`.
+  pub kind: GLenum,
+  /// The shader's info log, as the GL gave it.
+  ///
+  /// If `glCreateShader` failed this is a description of that instead.
+  pub log: String,
+  /// The info log split into its messages.
+  pub diagnostics: Vec<Diagnostic>,
+}
+impl core::fmt::Display for ShaderError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self.kind {
+      GL_VERTEX_SHADER => write!(f, "vertex shader")?,
+      GL_FRAGMENT_SHADER => write!(f, "fragment shader")?,
+      other => write!(f, "shader {:#X}", other)?,
+    }
+    write!(f, " failed to compile:\n{}", self.log)
+  }
+}
+impl std::error::Error for ShaderError {}
+
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl ShaderError {
+  fn new(kind: GLenum, log: String) -> Self {
+    Self { kind, diagnostics: parse_info_log(&log), log }
+  }
+}
+
+/// The error when a program doesn't link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkError {
+  /// The program's info log, as the GL gave it.
+  ///
+  /// If `glCreateProgram` failed, or an attribute name couldn't be passed to
+  /// GL, this is a description of that instead.
+  pub log: String,
+  /// The info log split into its messages.
+  pub diagnostics: Vec<Diagnostic>,
+}
+impl core::fmt::Display for LinkError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "program failed to link:\n{}", self.log)
+  }
+}
+impl std::error::Error for LinkError {}
+
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl LinkError {
+  fn new(log: String) -> Self {
+    Self { diagnostics: parse_info_log(&log), log }
+  }
+}
+
+#[cfg(any(feature = "global_loader", feature = "struct_loader"))]
+impl Target<'_> {
+  unsafe fn compile_sources(
+    &self, kind: GLenum, sources: &[&str],
+  ) -> Result<Shader, ShaderError> {
+    let shader = match Shader::from_raw(self.create_shader(kind)) {
+      Some(shader) => shader,
+      None => {
+        return Err(ShaderError::new(
+          kind,
+          String::from("error: glCreateShader failed"),
+        ))
+      }
+    };
+    let strings: Vec<*const GLchar> =
+      sources.iter().map(|s| s.as_ptr() as *const GLchar).collect();
+    let lengths: Vec<GLint> = sources
+      .iter()
+      .map(|s| {
+        core::convert::TryFrom::try_from(s.len()).unwrap_or(core::i32::MAX)
+      })
+      .collect();
+    self.shader_source(
+      shader.name(),
+      strings.len() as GLsizei,
+      strings.as_slice().as_ptr(),
+      lengths.as_slice().as_ptr(),
+    );
+    self.compile_shader(shader.name());
+    let mut status = 0;
+    self.get_shaderiv(shader.name(), GL_COMPILE_STATUS, &mut status);
+    if status != 0 {
+      return Ok(shader);
+    }
+    let mut log_length = 0;
+    self.get_shaderiv(shader.name(), GL_INFO_LOG_LENGTH, &mut log_length);
+    let log = read_gl_string(log_length, |size, length, buf| {
+      self.get_shader_info_log(shader.name(), size, length, buf)
+    });
+    self.delete_object(shader);
+    Err(ShaderError::new(kind, log))
+  }
+
+  unsafe fn link_shaders(
+    &self, shaders: &[Shader], attrib_bindings: &[(GLuint, &str)],
+  ) -> Result<Program, LinkError> {
+    // Check the names first, so there's nothing to clean up if one is bad.
+    let mut names = Vec::with_capacity(attrib_bindings.len());
+    for &(index, name) in attrib_bindings {
+      match CString::new(name) {
+        Ok(c_name) => names.push((index, c_name)),
+        Err(_) => {
+          return Err(LinkError::new(std::format!(
+            "error: attribute name {:?} contains a null byte",
+            name
+          )))
+        }
+      }
+    }
+    let program = match Program::from_raw(self.create_program()) {
+      Some(program) => program,
+      None => {
+        return Err(LinkError::new(String::from(
+          "error: glCreateProgram failed",
+        )))
+      }
+    };
+    for shader in shaders {
+      self.attach_shader(program.name(), shader.name());
+    }
+    for (index, c_name) in &names {
+      self.bind_attrib_location(program.name(), *index, c_name.as_ptr());
+    }
+    self.link_program(program.name());
+    // Detaching lets the shaders be deleted whenever the caller likes.
+    for shader in shaders {
+      self.detach_shader(program.name(), shader.name());
+    }
+    let mut status = 0;
+    self.get_programiv(program.name(), GL_LINK_STATUS, &mut status);
+    if status != 0 {
+      return Ok(program);
+    }
+    let mut log_length = 0;
+    self.get_programiv(program.name(), GL_INFO_LOG_LENGTH, &mut log_length);
+    let log = read_gl_string(log_length, |size, length, buf| {
+      self.get_program_info_log(program.name(), size, length, buf)
+    });
+    self.delete_object(program);
+    Err(LinkError::new(log))
+  }
+}
+
+/// Compiles a shader of the `kind` given (eg: `GL_VERTEX_SHADER`) from the
+/// source strings given.
+///
+/// The strings are given to `glShaderSource` in order, so they act like one
+/// long string. If the shader doesn't compile, it's deleted and the error has
+/// its info log.
+///
+/// ## Safety
+/// * The `GlFns` must be loaded for the GL context that's current on this
+///   thread.
+#[cfg(feature = "struct_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+pub unsafe fn compile_shader(
+  gl: &GlFns, kind: GLenum, sources: &[&str],
+) -> Result<Shader, ShaderError> {
+  Target::Struct(gl).compile_sources(kind, sources)
+}
+
+/// Links a program from the shaders given.
+///
+/// Each `(index, name)` in `attrib_bindings` is bound with
+/// `glBindAttribLocation` before linking. The shaders are detached again
+/// after linking, so they can be deleted (or reused) without affecting the
+/// program. If the program doesn't link, it's deleted and the error has its
+/// info log.
+///
+/// ## Safety
+/// * The `GlFns` must be loaded for the GL context that's current on this
+///   thread.
+/// * Each shader must be a compiled shader of that context (or a context
+///   that shares objects with it).
+#[cfg(feature = "struct_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+pub unsafe fn link_program(
+  gl: &GlFns, shaders: &[Shader], attrib_bindings: &[(GLuint, &str)],
+) -> Result<Program, LinkError> {
+  Target::Struct(gl).link_shaders(shaders, attrib_bindings)
+}
+
+/// Compiles a shader with the global loader.
+///
+/// This works like [`compile_shader`].
+///
+/// ## Safety
+/// * The global functions must be loaded for the GL context that's current
+///   on this thread.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub unsafe fn compile_global_shader(
+  kind: GLenum, sources: &[&str],
+) -> Result<Shader, ShaderError> {
+  Target::Global(core::marker::PhantomData).compile_sources(kind, sources)
+}
+
+/// Links a program with the global loader.
+///
+/// This works like [`link_program`].
+///
+/// ## Safety
+/// * The global functions must be loaded for the GL context that's current
+///   on this thread.
+/// * Each shader must be a compiled shader of that context (or a context
+///   that shares objects with it).
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub unsafe fn link_global_program(
+  shaders: &[Shader], attrib_bindings: &[(GLuint, &str)],
+) -> Result<Program, LinkError> {
+  Target::Global(core::marker::PhantomData)
+    .link_shaders(shaders, attrib_bindings)
+}
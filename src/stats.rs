@@ -0,0 +1,229 @@
+//! Per-command call statistics, for profiling.
+//!
+//! With the `call_stats` feature enabled, every GL command counts the call,
+//! and adds the CPU time spent inside the driver's function to that command's
+//! total. Draw calls (every `glDraw*` command except `glDrawBuffers`) are also
+//! counted, along with how many primitives they draw, based on their mode,
+//! vertex count, and instance count. The indirect draws read their counts from
+//! a buffer on the GPU, so they add to the draw calls but not the primitives.
+//! This works with both loaders:
+//! * For the global loader, use [`global_call_stats`] and
+//!   [`take_global_call_stats`].
+//! * For the struct loader, use [`GlFns::call_stats`] and
+//!   [`GlFns::take_call_stats`]. Each `GlFns` has its own counters.
+//!
+//! To get numbers for each frame, take the stats (which also resets them)
+//! once per frame:
+//!
+//! ```no_run
+//! # #[cfg(feature = "struct_loader")]
+//! # unsafe fn f(gl: &gles30::GlFns) {
+//! // ... draw the frame ...
+//! let stats = gl.take_call_stats();
+//! println!(
+//!   "{} calls, {} draws, {} primitives, {:?} in the driver",
+//!   stats.total_calls(),
+//!   stats.draw_calls,
+//!   stats.primitives,
+//!   stats.total_time()
+//! );
+//! if let Some(uniforms) = stats.get("glUniform4fv") {
+//!   println!("glUniform4fv: {} calls", uniforms.calls);
+//! }
+//! # }
+//! ```
+//!
+//! The time is measured on the CPU, so it's how long the driver took to accept
+//! the command, not how long the GPU took to run it.
+
+// Without a loader, nothing ever records into the counters.
+#![cfg_attr(
+  not(any(feature = "global_loader", feature = "struct_loader")),
+  allow(dead_code)
+)]
+
+use super::*;
+use core::{sync::atomic::AtomicU64, time::Duration};
+use std::{time::Instant, vec::Vec};
+
+/// The stats of one GL command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CommandStats {
+  /// The name of the command, such as `"glDrawArrays"`.
+  pub name: &'static str,
+  /// How many times the command was called.
+  pub calls: u64,
+  /// The total time spent inside the command.
+  pub time: Duration,
+}
+
+/// The stats of all GL commands, as of when they were read.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CallStats {
+  /// The stats of each command that was called at least once, in the same
+  /// order as the commands in [`LoadReport`].
+  pub commands: Vec<CommandStats>,
+  /// How many draw calls were made.
+  pub draw_calls: u64,
+  /// How many primitives (points, lines, or triangles) the draw calls drew,
+  /// over all instances.
+  ///
+  /// Indirect draws aren't included, their counts are only on the GPU.
+  pub primitives: u64,
+}
+impl CallStats {
+  /// The stats of one command, if it was called.
+  pub fn get(&self, name: &str) -> Option<&CommandStats> {
+    self.commands.iter().find(|c| c.name == name)
+  }
+
+  /// How many calls were made, over all commands.
+  pub fn total_calls(&self) -> u64 {
+    self.commands.iter().map(|c| c.calls).sum()
+  }
+
+  /// The time spent inside GL commands, over all commands.
+  pub fn total_time(&self) -> Duration {
+    self.commands.iter().map(|c| c.time).sum()
+  }
+}
+
+/// How many primitives a draw with `vertices` vertices makes, for each
+/// instance.
+///
+/// Unknown modes give 0.
+pub fn primitive_count(mode: GLenum, vertices: GLsizei) -> u64 {
+  if vertices <= 0 {
+    return 0;
+  }
+  let n = vertices as u64;
+  match mode {
+    GL_POINTS => n,
+    GL_LINES => n / 2,
+    GL_LINE_LOOP if n >= 2 => n,
+    GL_LINE_STRIP => n.saturating_sub(1),
+    GL_TRIANGLES => n / 3,
+    GL_TRIANGLE_STRIP | GL_TRIANGLE_FAN => n.saturating_sub(2),
+    _ => 0,
+  }
+}
+
+/// The counters that each command records into.
+///
+/// All zeroes is a valid (empty) value, which `GlFns` relies on.
+#[repr(C)]
+pub(crate) struct CallCounters {
+  calls: [AtomicU64; COMMAND_COUNT],
+  nanos: [AtomicU64; COMMAND_COUNT],
+  draw_calls: AtomicU64,
+  primitives: AtomicU64,
+}
+impl CallCounters {
+  #[cfg(feature = "global_loader")]
+  const fn new() -> Self {
+    Self {
+      calls: crate::ZEROED_COUNTERS,
+      nanos: crate::ZEROED_COUNTERS,
+      draw_calls: AtomicU64::new(0),
+      primitives: AtomicU64::new(0),
+    }
+  }
+
+  /// Records one call to the command at `index`, which started at `start`.
+  #[inline]
+  pub(crate) fn record(&self, index: usize, start: Instant) {
+    let elapsed = start.elapsed();
+    let nanos = elapsed
+      .as_secs()
+      .saturating_mul(1_000_000_000)
+      .saturating_add(u64::from(elapsed.subsec_nanos()));
+    self.calls[index].fetch_add(1, RELAX);
+    self.nanos[index].fetch_add(nanos, RELAX);
+  }
+
+  /// Records one draw call.
+  #[inline]
+  pub(crate) fn record_draw(
+    &self, mode: GLenum, count: GLsizei, instances: GLsizei,
+  ) {
+    let instances = instances.max(0) as u64;
+    self.draw_calls.fetch_add(1, RELAX);
+    self.primitives.fetch_add(primitive_count(mode, count) * instances, RELAX);
+  }
+
+  /// Records one indirect draw call, which draws an unknown number of
+  /// primitives.
+  #[inline]
+  pub(crate) fn record_indirect_draw(&self) {
+    self.draw_calls.fetch_add(1, RELAX);
+  }
+
+  /// Reads all the counters, and sets them back to 0 if `reset` is set.
+  fn read(&self, reset: bool) -> CallStats {
+    let get =
+      |a: &AtomicU64| if reset { a.swap(0, RELAX) } else { a.load(RELAX) };
+    let commands = (0..COMMAND_COUNT)
+      .filter_map(|i| {
+        let calls = get(&self.calls[i]);
+        let nanos = get(&self.nanos[i]);
+        if calls == 0 {
+          return None;
+        }
+        Some(CommandStats {
+          name: LOADABLE_COMMANDS[i].name,
+          calls,
+          time: Duration::from_nanos(nanos),
+        })
+      })
+      .collect();
+    CallStats {
+      commands,
+      draw_calls: get(&self.draw_calls),
+      primitives: get(&self.primitives),
+    }
+  }
+}
+
+/// Gets the time that a call starts at.
+#[inline]
+pub(crate) fn start() -> Instant {
+  Instant::now()
+}
+
+#[cfg(feature = "global_loader")]
+pub(crate) static GLOBAL_STATS: CallCounters = CallCounters::new();
+
+/// Gets the stats of calls made with the global GL functions.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn global_call_stats() -> CallStats {
+  GLOBAL_STATS.read(false)
+}
+
+/// Gets the stats of calls made with the global GL functions, and resets
+/// them to 0.
+///
+/// Calls made on other threads while this runs might be counted either
+/// before or after the reset.
+#[cfg(feature = "global_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "global_loader")))]
+pub fn take_global_call_stats() -> CallStats {
+  GLOBAL_STATS.read(true)
+}
+
+#[cfg(feature = "struct_loader")]
+impl GlFns {
+  /// Gets the stats of calls made through this `GlFns`.
+  pub fn call_stats(&self) -> CallStats {
+    self.stats.read(false)
+  }
+
+  /// Gets the stats of calls made through this `GlFns`, and resets them to
+  /// 0.
+  ///
+  /// Calls made on other threads while this runs might be counted either
+  /// before or after the reset.
+  pub fn take_call_stats(&self) -> CallStats {
+    self.stats.read(true)
+  }
+}
@@ -0,0 +1,6484 @@
+// The per-command parts of the trace format. This is generated along with
+// the commands in `lib.rs`, so that every command is covered.
+
+use super::*;
+
+impl Encoder {
+  /// Writes a call into the encoder's buffer.
+  ///
+  /// ## Safety
+  /// Any pointers in the call must still be valid to read from.
+  #[allow(unused_variables)]
+  pub(super) unsafe fn encode(&mut self, call: GlCall) {
+    let e = self;
+    match call {
+      #[cfg(feature = "gles31")]
+      GlCall::ActiveShaderProgram { pipeline, program } => {
+        e.command(0);
+        e.u32(pipeline);
+        e.u32(program);
+      }
+      GlCall::ActiveTexture { texture } => {
+        e.command(1);
+        e.u32(texture);
+      }
+      GlCall::AttachShader { program, shader } => {
+        e.command(2);
+        e.u32(program);
+        e.u32(shader);
+      }
+      GlCall::BeginQuery { target, id } => {
+        e.command(3);
+        e.u32(target);
+        e.u32(id);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::BeginQueryEXT { target, id } => {
+        e.command(4);
+        e.u32(target);
+        e.u32(id);
+      }
+      GlCall::BeginTransformFeedback { primitiveMode } => {
+        e.command(5);
+        e.u32(primitiveMode);
+      }
+      GlCall::BindAttribLocation { program, index, name } => {
+        e.command(6);
+        e.u32(program);
+        e.u32(index);
+        e.c_str(name, -1);
+      }
+      GlCall::BindBuffer { target, buffer } => {
+        e.command(7);
+        e.u32(target);
+        e.u32(buffer);
+      }
+      GlCall::BindBufferBase { target, index, buffer } => {
+        e.command(8);
+        e.u32(target);
+        e.u32(index);
+        e.u32(buffer);
+      }
+      GlCall::BindBufferRange { target, index, buffer, offset, size } => {
+        e.command(9);
+        e.u32(target);
+        e.u32(index);
+        e.u32(buffer);
+        e.i64(offset as i64);
+        e.i64(size as i64);
+      }
+      GlCall::BindFramebuffer { target, framebuffer } => {
+        e.command(10);
+        e.u32(target);
+        e.u32(framebuffer);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::BindImageTexture {
+        unit,
+        texture,
+        level,
+        layered,
+        layer,
+        access,
+        format,
+      } => {
+        e.command(11);
+        e.u32(unit);
+        e.u32(texture);
+        e.i32(level);
+        e.u8(layered);
+        e.i32(layer);
+        e.u32(access);
+        e.u32(format);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::BindProgramPipeline { pipeline } => {
+        e.command(12);
+        e.u32(pipeline);
+      }
+      GlCall::BindRenderbuffer { target, renderbuffer } => {
+        e.command(13);
+        e.u32(target);
+        e.u32(renderbuffer);
+      }
+      GlCall::BindSampler { unit, sampler } => {
+        e.command(14);
+        e.u32(unit);
+        e.u32(sampler);
+      }
+      GlCall::BindTexture { target, texture } => {
+        e.command(15);
+        e.u32(target);
+        e.u32(texture);
+      }
+      GlCall::BindTransformFeedback { target, id } => {
+        e.command(16);
+        e.u32(target);
+        e.u32(id);
+      }
+      GlCall::BindVertexArray { array } => {
+        e.command(17);
+        e.u32(array);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::BindVertexBuffer { bindingindex, buffer, offset, stride } => {
+        e.command(18);
+        e.u32(bindingindex);
+        e.u32(buffer);
+        e.i64(offset as i64);
+        e.i32(stride);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::BlendBarrier => {
+        e.command(19);
+      }
+      GlCall::BlendColor { red, green, blue, alpha } => {
+        e.command(20);
+        e.f32(red);
+        e.f32(green);
+        e.f32(blue);
+        e.f32(alpha);
+      }
+      GlCall::BlendEquation { mode } => {
+        e.command(21);
+        e.u32(mode);
+      }
+      GlCall::BlendEquationSeparate { modeRGB, modeAlpha } => {
+        e.command(22);
+        e.u32(modeRGB);
+        e.u32(modeAlpha);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::BlendEquationSeparatei { buf, modeRGB, modeAlpha } => {
+        e.command(23);
+        e.u32(buf);
+        e.u32(modeRGB);
+        e.u32(modeAlpha);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::BlendEquationi { buf, mode } => {
+        e.command(24);
+        e.u32(buf);
+        e.u32(mode);
+      }
+      GlCall::BlendFunc { sfactor, dfactor } => {
+        e.command(25);
+        e.u32(sfactor);
+        e.u32(dfactor);
+      }
+      GlCall::BlendFuncSeparate {
+        sfactorRGB,
+        dfactorRGB,
+        sfactorAlpha,
+        dfactorAlpha,
+      } => {
+        e.command(26);
+        e.u32(sfactorRGB);
+        e.u32(dfactorRGB);
+        e.u32(sfactorAlpha);
+        e.u32(dfactorAlpha);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::BlendFuncSeparatei {
+        buf,
+        srcRGB,
+        dstRGB,
+        srcAlpha,
+        dstAlpha,
+      } => {
+        e.command(27);
+        e.u32(buf);
+        e.u32(srcRGB);
+        e.u32(dstRGB);
+        e.u32(srcAlpha);
+        e.u32(dstAlpha);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::BlendFunci { buf, src, dst } => {
+        e.command(28);
+        e.u32(buf);
+        e.u32(src);
+        e.u32(dst);
+      }
+      GlCall::BlitFramebuffer {
+        srcX0,
+        srcY0,
+        srcX1,
+        srcY1,
+        dstX0,
+        dstY0,
+        dstX1,
+        dstY1,
+        mask,
+        filter,
+      } => {
+        e.command(29);
+        e.i32(srcX0);
+        e.i32(srcY0);
+        e.i32(srcX1);
+        e.i32(srcY1);
+        e.i32(dstX0);
+        e.i32(dstY0);
+        e.i32(dstX1);
+        e.i32(dstY1);
+        e.u32(mask);
+        e.u32(filter);
+      }
+      GlCall::BufferData { target, size, data, usage } => {
+        e.command(30);
+        e.u32(target);
+        e.i64(size as i64);
+        e.bytes(data, size.max(0) as usize);
+        e.u32(usage);
+      }
+      #[cfg(feature = "GL_EXT_buffer_storage")]
+      GlCall::BufferStorageEXT { target, size, data, flags } => {
+        e.command(31);
+        e.u32(target);
+        e.i64(size as i64);
+        e.bytes(data, size.max(0) as usize);
+        e.u32(flags);
+      }
+      GlCall::BufferSubData { target, offset, size, data } => {
+        e.command(32);
+        e.u32(target);
+        e.i64(offset as i64);
+        e.i64(size as i64);
+        e.bytes(data, size.max(0) as usize);
+      }
+      GlCall::CheckFramebufferStatus { target, ret } => {
+        e.command(33);
+        e.u32(target);
+        e.u32(ret);
+      }
+      GlCall::Clear { mask } => {
+        e.command(34);
+        e.u32(mask);
+      }
+      GlCall::ClearBufferfi { buffer, drawbuffer, depth, stencil } => {
+        e.command(35);
+        e.u32(buffer);
+        e.i32(drawbuffer);
+        e.f32(depth);
+        e.i32(stencil);
+      }
+      GlCall::ClearBufferfv { buffer, drawbuffer, value } => {
+        e.command(36);
+        e.u32(buffer);
+        e.i32(drawbuffer);
+        e.array(value, clear_buffer_len(buffer));
+      }
+      GlCall::ClearBufferiv { buffer, drawbuffer, value } => {
+        e.command(37);
+        e.u32(buffer);
+        e.i32(drawbuffer);
+        e.array(value, clear_buffer_len(buffer));
+      }
+      GlCall::ClearBufferuiv { buffer, drawbuffer, value } => {
+        e.command(38);
+        e.u32(buffer);
+        e.i32(drawbuffer);
+        e.array(value, clear_buffer_len(buffer));
+      }
+      GlCall::ClearColor { red, green, blue, alpha } => {
+        e.command(39);
+        e.f32(red);
+        e.f32(green);
+        e.f32(blue);
+        e.f32(alpha);
+      }
+      GlCall::ClearDepthf { d } => {
+        e.command(40);
+        e.f32(d);
+      }
+      GlCall::ClearStencil { s } => {
+        e.command(41);
+        e.i32(s);
+      }
+      GlCall::ClientWaitSync { sync, flags, timeout, ret } => {
+        e.command(42);
+        e.u64(sync as usize as u64);
+        e.u32(flags);
+        e.u64(timeout);
+        e.u32(ret);
+      }
+      GlCall::ColorMask { red, green, blue, alpha } => {
+        e.command(43);
+        e.u8(red);
+        e.u8(green);
+        e.u8(blue);
+        e.u8(alpha);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::ColorMaski { index, r, g, b, a } => {
+        e.command(44);
+        e.u32(index);
+        e.u8(r);
+        e.u8(g);
+        e.u8(b);
+        e.u8(a);
+      }
+      GlCall::CompileShader { shader } => {
+        e.command(45);
+        e.u32(shader);
+      }
+      GlCall::CompressedTexImage2D {
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        border,
+        imageSize,
+        data,
+      } => {
+        e.command(46);
+        e.u32(target);
+        e.i32(level);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+        e.i32(border);
+        e.i32(imageSize);
+        e.unpack_bytes(data, imageSize);
+      }
+      GlCall::CompressedTexImage3D {
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        depth,
+        border,
+        imageSize,
+        data,
+      } => {
+        e.command(47);
+        e.u32(target);
+        e.i32(level);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+        e.i32(depth);
+        e.i32(border);
+        e.i32(imageSize);
+        e.unpack_bytes(data, imageSize);
+      }
+      GlCall::CompressedTexSubImage2D {
+        target,
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        format,
+        imageSize,
+        data,
+      } => {
+        e.command(48);
+        e.u32(target);
+        e.i32(level);
+        e.i32(xoffset);
+        e.i32(yoffset);
+        e.i32(width);
+        e.i32(height);
+        e.u32(format);
+        e.i32(imageSize);
+        e.unpack_bytes(data, imageSize);
+      }
+      GlCall::CompressedTexSubImage3D {
+        target,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        imageSize,
+        data,
+      } => {
+        e.command(49);
+        e.u32(target);
+        e.i32(level);
+        e.i32(xoffset);
+        e.i32(yoffset);
+        e.i32(zoffset);
+        e.i32(width);
+        e.i32(height);
+        e.i32(depth);
+        e.u32(format);
+        e.i32(imageSize);
+        e.unpack_bytes(data, imageSize);
+      }
+      GlCall::CopyBufferSubData {
+        readTarget,
+        writeTarget,
+        readOffset,
+        writeOffset,
+        size,
+      } => {
+        e.command(50);
+        e.u32(readTarget);
+        e.u32(writeTarget);
+        e.i64(readOffset as i64);
+        e.i64(writeOffset as i64);
+        e.i64(size as i64);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::CopyImageSubData {
+        srcName,
+        srcTarget,
+        srcLevel,
+        srcX,
+        srcY,
+        srcZ,
+        dstName,
+        dstTarget,
+        dstLevel,
+        dstX,
+        dstY,
+        dstZ,
+        srcWidth,
+        srcHeight,
+        srcDepth,
+      } => {
+        e.command(51);
+        e.u32(srcName);
+        e.u32(srcTarget);
+        e.i32(srcLevel);
+        e.i32(srcX);
+        e.i32(srcY);
+        e.i32(srcZ);
+        e.u32(dstName);
+        e.u32(dstTarget);
+        e.i32(dstLevel);
+        e.i32(dstX);
+        e.i32(dstY);
+        e.i32(dstZ);
+        e.i32(srcWidth);
+        e.i32(srcHeight);
+        e.i32(srcDepth);
+      }
+      GlCall::CopyTexImage2D {
+        target,
+        level,
+        internalformat,
+        x,
+        y,
+        width,
+        height,
+        border,
+      } => {
+        e.command(52);
+        e.u32(target);
+        e.i32(level);
+        e.u32(internalformat);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+        e.i32(border);
+      }
+      GlCall::CopyTexSubImage2D {
+        target,
+        level,
+        xoffset,
+        yoffset,
+        x,
+        y,
+        width,
+        height,
+      } => {
+        e.command(53);
+        e.u32(target);
+        e.i32(level);
+        e.i32(xoffset);
+        e.i32(yoffset);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+      }
+      GlCall::CopyTexSubImage3D {
+        target,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        x,
+        y,
+        width,
+        height,
+      } => {
+        e.command(54);
+        e.u32(target);
+        e.i32(level);
+        e.i32(xoffset);
+        e.i32(yoffset);
+        e.i32(zoffset);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+      }
+      GlCall::CreateProgram { ret } => {
+        e.command(55);
+        e.u32(ret);
+      }
+      GlCall::CreateShader { type_, ret } => {
+        e.command(56);
+        e.u32(type_);
+        e.u32(ret);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::CreateShaderProgramv { type_, count, strings, ret } => {
+        e.command(57);
+        e.u32(type_);
+        e.i32(count);
+        e.strings(strings, count, null());
+        e.u32(ret);
+      }
+      GlCall::CullFace { mode } => {
+        e.command(58);
+        e.u32(mode);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::DebugMessageCallback { callback, userParam } => {
+        e.command(59);
+        e.u64(callback as usize as u64);
+        e.address(userParam);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::DebugMessageCallbackKHR { callback, userParam } => {
+        e.command(60);
+        e.u64(callback as usize as u64);
+        e.address(userParam);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::DebugMessageControl {
+        source,
+        type_,
+        severity,
+        count,
+        ids,
+        enabled,
+      } => {
+        e.command(61);
+        e.u32(source);
+        e.u32(type_);
+        e.u32(severity);
+        e.i32(count);
+        e.array(ids, count.max(0) as usize);
+        e.u8(enabled);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::DebugMessageControlKHR {
+        source,
+        type_,
+        severity,
+        count,
+        ids,
+        enabled,
+      } => {
+        e.command(62);
+        e.u32(source);
+        e.u32(type_);
+        e.u32(severity);
+        e.i32(count);
+        e.array(ids, count.max(0) as usize);
+        e.u8(enabled);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::DebugMessageInsert {
+        source,
+        type_,
+        id,
+        severity,
+        length,
+        buf,
+      } => {
+        e.command(63);
+        e.u32(source);
+        e.u32(type_);
+        e.u32(id);
+        e.u32(severity);
+        e.i32(length);
+        e.c_str(buf, length);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::DebugMessageInsertKHR {
+        source,
+        type_,
+        id,
+        severity,
+        length,
+        buf,
+      } => {
+        e.command(64);
+        e.u32(source);
+        e.u32(type_);
+        e.u32(id);
+        e.u32(severity);
+        e.i32(length);
+        e.c_str(buf, length);
+      }
+      GlCall::DeleteBuffers { n, buffers } => {
+        e.command(65);
+        e.i32(n);
+        e.array(buffers, n.max(0) as usize);
+      }
+      GlCall::DeleteFramebuffers { n, framebuffers } => {
+        e.command(66);
+        e.i32(n);
+        e.array(framebuffers, n.max(0) as usize);
+      }
+      GlCall::DeleteProgram { program } => {
+        e.command(67);
+        e.u32(program);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::DeleteProgramPipelines { n, pipelines } => {
+        e.command(68);
+        e.i32(n);
+        e.array(pipelines, n.max(0) as usize);
+      }
+      GlCall::DeleteQueries { n, ids } => {
+        e.command(69);
+        e.i32(n);
+        e.array(ids, n.max(0) as usize);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::DeleteQueriesEXT { n, ids } => {
+        e.command(70);
+        e.i32(n);
+        e.array(ids, n.max(0) as usize);
+      }
+      GlCall::DeleteRenderbuffers { n, renderbuffers } => {
+        e.command(71);
+        e.i32(n);
+        e.array(renderbuffers, n.max(0) as usize);
+      }
+      GlCall::DeleteSamplers { count, samplers } => {
+        e.command(72);
+        e.i32(count);
+        e.array(samplers, count.max(0) as usize);
+      }
+      GlCall::DeleteShader { shader } => {
+        e.command(73);
+        e.u32(shader);
+      }
+      GlCall::DeleteSync { sync } => {
+        e.command(74);
+        e.u64(sync as usize as u64);
+      }
+      GlCall::DeleteTextures { n, textures } => {
+        e.command(75);
+        e.i32(n);
+        e.array(textures, n.max(0) as usize);
+      }
+      GlCall::DeleteTransformFeedbacks { n, ids } => {
+        e.command(76);
+        e.i32(n);
+        e.array(ids, n.max(0) as usize);
+      }
+      GlCall::DeleteVertexArrays { n, arrays } => {
+        e.command(77);
+        e.i32(n);
+        e.array(arrays, n.max(0) as usize);
+      }
+      GlCall::DepthFunc { func } => {
+        e.command(78);
+        e.u32(func);
+      }
+      GlCall::DepthMask { flag } => {
+        e.command(79);
+        e.u8(flag);
+      }
+      GlCall::DepthRangef { n, f } => {
+        e.command(80);
+        e.f32(n);
+        e.f32(f);
+      }
+      GlCall::DetachShader { program, shader } => {
+        e.command(81);
+        e.u32(program);
+        e.u32(shader);
+      }
+      GlCall::Disable { cap } => {
+        e.command(82);
+        e.u32(cap);
+      }
+      GlCall::DisableVertexAttribArray { index } => {
+        e.command(83);
+        e.u32(index);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::Disablei { target, index } => {
+        e.command(84);
+        e.u32(target);
+        e.u32(index);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::DispatchCompute { num_groups_x, num_groups_y, num_groups_z } => {
+        e.command(85);
+        e.u32(num_groups_x);
+        e.u32(num_groups_y);
+        e.u32(num_groups_z);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::DispatchComputeIndirect { indirect } => {
+        e.command(86);
+        e.i64(indirect as i64);
+      }
+      GlCall::DrawArrays { mode, first, count } => {
+        e.command(87);
+        e.u32(mode);
+        e.i32(first);
+        e.i32(count);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::DrawArraysIndirect { mode, indirect } => {
+        e.command(88);
+        e.u32(mode);
+        e.address(indirect);
+      }
+      GlCall::DrawArraysInstanced { mode, first, count, instancecount } => {
+        e.command(89);
+        e.u32(mode);
+        e.i32(first);
+        e.i32(count);
+        e.i32(instancecount);
+      }
+      GlCall::DrawBuffers { n, bufs } => {
+        e.command(90);
+        e.i32(n);
+        e.array(bufs, n.max(0) as usize);
+      }
+      GlCall::DrawElements { mode, count, type_, indices } => {
+        e.command(91);
+        e.u32(mode);
+        e.i32(count);
+        e.u32(type_);
+        e.indices(indices, count, type_);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::DrawElementsBaseVertex {
+        mode,
+        count,
+        type_,
+        indices,
+        basevertex,
+      } => {
+        e.command(92);
+        e.u32(mode);
+        e.i32(count);
+        e.u32(type_);
+        e.indices(indices, count, type_);
+        e.i32(basevertex);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::DrawElementsIndirect { mode, type_, indirect } => {
+        e.command(93);
+        e.u32(mode);
+        e.u32(type_);
+        e.address(indirect);
+      }
+      GlCall::DrawElementsInstanced {
+        mode,
+        count,
+        type_,
+        indices,
+        instancecount,
+      } => {
+        e.command(94);
+        e.u32(mode);
+        e.i32(count);
+        e.u32(type_);
+        e.indices(indices, count, type_);
+        e.i32(instancecount);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::DrawElementsInstancedBaseVertex {
+        mode,
+        count,
+        type_,
+        indices,
+        instancecount,
+        basevertex,
+      } => {
+        e.command(95);
+        e.u32(mode);
+        e.i32(count);
+        e.u32(type_);
+        e.indices(indices, count, type_);
+        e.i32(instancecount);
+        e.i32(basevertex);
+      }
+      GlCall::DrawRangeElements { mode, start, end, count, type_, indices } => {
+        e.command(96);
+        e.u32(mode);
+        e.u32(start);
+        e.u32(end);
+        e.i32(count);
+        e.u32(type_);
+        e.indices(indices, count, type_);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::DrawRangeElementsBaseVertex {
+        mode,
+        start,
+        end,
+        count,
+        type_,
+        indices,
+        basevertex,
+      } => {
+        e.command(97);
+        e.u32(mode);
+        e.u32(start);
+        e.u32(end);
+        e.i32(count);
+        e.u32(type_);
+        e.indices(indices, count, type_);
+        e.i32(basevertex);
+      }
+      #[cfg(feature = "GL_OES_EGL_image")]
+      GlCall::EGLImageTargetRenderbufferStorageOES { target, image } => {
+        e.command(98);
+        e.u32(target);
+        e.u64(image as usize as u64);
+      }
+      #[cfg(feature = "GL_OES_EGL_image")]
+      GlCall::EGLImageTargetTexture2DOES { target, image } => {
+        e.command(99);
+        e.u32(target);
+        e.u64(image as usize as u64);
+      }
+      GlCall::Enable { cap } => {
+        e.command(100);
+        e.u32(cap);
+      }
+      GlCall::EnableVertexAttribArray { index } => {
+        e.command(101);
+        e.u32(index);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::Enablei { target, index } => {
+        e.command(102);
+        e.u32(target);
+        e.u32(index);
+      }
+      GlCall::EndQuery { target } => {
+        e.command(103);
+        e.u32(target);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::EndQueryEXT { target } => {
+        e.command(104);
+        e.u32(target);
+      }
+      GlCall::EndTransformFeedback => {
+        e.command(105);
+      }
+      GlCall::FenceSync { condition, flags, ret } => {
+        e.command(106);
+        e.u32(condition);
+        e.u32(flags);
+        e.u64(ret as usize as u64);
+      }
+      GlCall::Finish => {
+        e.command(107);
+      }
+      GlCall::Flush => {
+        e.command(108);
+      }
+      GlCall::FlushMappedBufferRange { target, offset, length } => {
+        e.command(109);
+        e.u32(target);
+        e.i64(offset as i64);
+        e.i64(length as i64);
+        e.mapped_range(target, offset, length);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::FramebufferParameteri { target, pname, param } => {
+        e.command(110);
+        e.u32(target);
+        e.u32(pname);
+        e.i32(param);
+      }
+      GlCall::FramebufferRenderbuffer {
+        target,
+        attachment,
+        renderbuffertarget,
+        renderbuffer,
+      } => {
+        e.command(111);
+        e.u32(target);
+        e.u32(attachment);
+        e.u32(renderbuffertarget);
+        e.u32(renderbuffer);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::FramebufferTexture { target, attachment, texture, level } => {
+        e.command(112);
+        e.u32(target);
+        e.u32(attachment);
+        e.u32(texture);
+        e.i32(level);
+      }
+      GlCall::FramebufferTexture2D {
+        target,
+        attachment,
+        textarget,
+        texture,
+        level,
+      } => {
+        e.command(113);
+        e.u32(target);
+        e.u32(attachment);
+        e.u32(textarget);
+        e.u32(texture);
+        e.i32(level);
+      }
+      #[cfg(feature = "GL_EXT_multisampled_render_to_texture")]
+      GlCall::FramebufferTexture2DMultisampleEXT {
+        target,
+        attachment,
+        textarget,
+        texture,
+        level,
+        samples,
+      } => {
+        e.command(114);
+        e.u32(target);
+        e.u32(attachment);
+        e.u32(textarget);
+        e.u32(texture);
+        e.i32(level);
+        e.i32(samples);
+      }
+      GlCall::FramebufferTextureLayer {
+        target,
+        attachment,
+        texture,
+        level,
+        layer,
+      } => {
+        e.command(115);
+        e.u32(target);
+        e.u32(attachment);
+        e.u32(texture);
+        e.i32(level);
+        e.i32(layer);
+      }
+      #[cfg(feature = "GL_OVR_multiview")]
+      GlCall::FramebufferTextureMultiviewOVR {
+        target,
+        attachment,
+        texture,
+        level,
+        baseViewIndex,
+        numViews,
+      } => {
+        e.command(116);
+        e.u32(target);
+        e.u32(attachment);
+        e.u32(texture);
+        e.i32(level);
+        e.i32(baseViewIndex);
+        e.i32(numViews);
+      }
+      GlCall::FrontFace { mode } => {
+        e.command(117);
+        e.u32(mode);
+      }
+      GlCall::GenBuffers { n, buffers } => {
+        e.command(118);
+        e.i32(n);
+        e.array(buffers as *const GLuint, n.max(0) as usize);
+      }
+      GlCall::GenFramebuffers { n, framebuffers } => {
+        e.command(119);
+        e.i32(n);
+        e.array(framebuffers as *const GLuint, n.max(0) as usize);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GenProgramPipelines { n, pipelines } => {
+        e.command(120);
+        e.i32(n);
+        e.array(pipelines as *const GLuint, n.max(0) as usize);
+      }
+      GlCall::GenQueries { n, ids } => {
+        e.command(121);
+        e.i32(n);
+        e.array(ids as *const GLuint, n.max(0) as usize);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::GenQueriesEXT { n, ids } => {
+        e.command(122);
+        e.i32(n);
+        e.array(ids as *const GLuint, n.max(0) as usize);
+      }
+      GlCall::GenRenderbuffers { n, renderbuffers } => {
+        e.command(123);
+        e.i32(n);
+        e.array(renderbuffers as *const GLuint, n.max(0) as usize);
+      }
+      GlCall::GenSamplers { count, samplers } => {
+        e.command(124);
+        e.i32(count);
+        e.array(samplers as *const GLuint, count.max(0) as usize);
+      }
+      GlCall::GenTextures { n, textures } => {
+        e.command(125);
+        e.i32(n);
+        e.array(textures as *const GLuint, n.max(0) as usize);
+      }
+      GlCall::GenTransformFeedbacks { n, ids } => {
+        e.command(126);
+        e.i32(n);
+        e.array(ids as *const GLuint, n.max(0) as usize);
+      }
+      GlCall::GenVertexArrays { n, arrays } => {
+        e.command(127);
+        e.i32(n);
+        e.array(arrays as *const GLuint, n.max(0) as usize);
+      }
+      GlCall::GenerateMipmap { target } => {
+        e.command(128);
+        e.u32(target);
+      }
+      GlCall::GetActiveAttrib {
+        program,
+        index,
+        bufSize,
+        length,
+        size,
+        type_,
+        name,
+      } => {
+        e.command(129);
+        e.u32(program);
+        e.u32(index);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(size);
+        e.address(type_);
+        e.address(name);
+      }
+      GlCall::GetActiveUniform {
+        program,
+        index,
+        bufSize,
+        length,
+        size,
+        type_,
+        name,
+      } => {
+        e.command(130);
+        e.u32(program);
+        e.u32(index);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(size);
+        e.address(type_);
+        e.address(name);
+      }
+      GlCall::GetActiveUniformBlockName {
+        program,
+        uniformBlockIndex,
+        bufSize,
+        length,
+        uniformBlockName,
+      } => {
+        e.command(131);
+        e.u32(program);
+        e.u32(uniformBlockIndex);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(uniformBlockName);
+      }
+      GlCall::GetActiveUniformBlockiv {
+        program,
+        uniformBlockIndex,
+        pname,
+        params,
+      } => {
+        e.command(132);
+        e.u32(program);
+        e.u32(uniformBlockIndex);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetActiveUniformsiv {
+        program,
+        uniformCount,
+        uniformIndices,
+        pname,
+        params,
+      } => {
+        e.command(133);
+        e.u32(program);
+        e.i32(uniformCount);
+        e.array(uniformIndices, uniformCount.max(0) as usize);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetAttachedShaders { program, maxCount, count, shaders } => {
+        e.command(134);
+        e.u32(program);
+        e.i32(maxCount);
+        e.address(count);
+        e.address(shaders);
+      }
+      GlCall::GetAttribLocation { program, name, ret } => {
+        e.command(135);
+        e.u32(program);
+        e.c_str(name, -1);
+        e.i32(ret);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetBooleani_v { target, index, data } => {
+        e.command(136);
+        e.u32(target);
+        e.u32(index);
+        e.address(data);
+      }
+      GlCall::GetBooleanv { pname, data } => {
+        e.command(137);
+        e.u32(pname);
+        e.address(data);
+      }
+      GlCall::GetBufferParameteri64v { target, pname, params } => {
+        e.command(138);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetBufferParameteriv { target, pname, params } => {
+        e.command(139);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetBufferPointerv { target, pname, params } => {
+        e.command(140);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetDebugMessageLog {
+        count,
+        bufSize,
+        sources,
+        types,
+        ids,
+        severities,
+        lengths,
+        messageLog,
+        ret,
+      } => {
+        e.command(141);
+        e.u32(count);
+        e.i32(bufSize);
+        e.address(sources);
+        e.address(types);
+        e.address(ids);
+        e.address(severities);
+        e.address(lengths);
+        e.address(messageLog);
+        e.u32(ret);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::GetDebugMessageLogKHR {
+        count,
+        bufSize,
+        sources,
+        types,
+        ids,
+        severities,
+        lengths,
+        messageLog,
+        ret,
+      } => {
+        e.command(142);
+        e.u32(count);
+        e.i32(bufSize);
+        e.address(sources);
+        e.address(types);
+        e.address(ids);
+        e.address(severities);
+        e.address(lengths);
+        e.address(messageLog);
+        e.u32(ret);
+      }
+      GlCall::GetError { ret } => {
+        e.command(143);
+        e.u32(ret);
+      }
+      GlCall::GetFloatv { pname, data } => {
+        e.command(144);
+        e.u32(pname);
+        e.address(data);
+      }
+      GlCall::GetFragDataLocation { program, name, ret } => {
+        e.command(145);
+        e.u32(program);
+        e.c_str(name, -1);
+        e.i32(ret);
+      }
+      GlCall::GetFramebufferAttachmentParameteriv {
+        target,
+        attachment,
+        pname,
+        params,
+      } => {
+        e.command(146);
+        e.u32(target);
+        e.u32(attachment);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetFramebufferParameteriv { target, pname, params } => {
+        e.command(147);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetGraphicsResetStatus { ret } => {
+        e.command(148);
+        e.u32(ret);
+      }
+      GlCall::GetInteger64i_v { target, index, data } => {
+        e.command(149);
+        e.u32(target);
+        e.u32(index);
+        e.address(data);
+      }
+      GlCall::GetInteger64v { pname, data } => {
+        e.command(150);
+        e.u32(pname);
+        e.address(data);
+      }
+      GlCall::GetIntegeri_v { target, index, data } => {
+        e.command(151);
+        e.u32(target);
+        e.u32(index);
+        e.address(data);
+      }
+      GlCall::GetIntegerv { pname, data } => {
+        e.command(152);
+        e.u32(pname);
+        e.address(data);
+      }
+      GlCall::GetInternalformativ {
+        target,
+        internalformat,
+        pname,
+        count,
+        params,
+      } => {
+        e.command(153);
+        e.u32(target);
+        e.u32(internalformat);
+        e.u32(pname);
+        e.i32(count);
+        e.address(params);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetMultisamplefv { pname, index, val } => {
+        e.command(154);
+        e.u32(pname);
+        e.u32(index);
+        e.address(val);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetObjectLabel { identifier, name, bufSize, length, label } => {
+        e.command(155);
+        e.u32(identifier);
+        e.u32(name);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(label);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::GetObjectLabelKHR {
+        identifier,
+        name,
+        bufSize,
+        length,
+        label,
+      } => {
+        e.command(156);
+        e.u32(identifier);
+        e.u32(name);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(label);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetObjectPtrLabel { ptr, bufSize, length, label } => {
+        e.command(157);
+        e.address(ptr);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(label);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::GetObjectPtrLabelKHR { ptr, bufSize, length, label } => {
+        e.command(158);
+        e.address(ptr);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(label);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetPointerv { pname, params } => {
+        e.command(159);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::GetPointervKHR { pname, params } => {
+        e.command(160);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetProgramBinary {
+        program,
+        bufSize,
+        length,
+        binaryFormat,
+        binary,
+      } => {
+        e.command(161);
+        e.u32(program);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(binaryFormat);
+        e.address(binary);
+      }
+      GlCall::GetProgramInfoLog { program, bufSize, length, infoLog } => {
+        e.command(162);
+        e.u32(program);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(infoLog);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetProgramInterfaceiv {
+        program,
+        programInterface,
+        pname,
+        params,
+      } => {
+        e.command(163);
+        e.u32(program);
+        e.u32(programInterface);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetProgramPipelineInfoLog {
+        pipeline,
+        bufSize,
+        length,
+        infoLog,
+      } => {
+        e.command(164);
+        e.u32(pipeline);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(infoLog);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetProgramPipelineiv { pipeline, pname, params } => {
+        e.command(165);
+        e.u32(pipeline);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetProgramResourceIndex {
+        program,
+        programInterface,
+        name,
+        ret,
+      } => {
+        e.command(166);
+        e.u32(program);
+        e.u32(programInterface);
+        e.c_str(name, -1);
+        e.u32(ret);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetProgramResourceLocation {
+        program,
+        programInterface,
+        name,
+        ret,
+      } => {
+        e.command(167);
+        e.u32(program);
+        e.u32(programInterface);
+        e.c_str(name, -1);
+        e.i32(ret);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetProgramResourceName {
+        program,
+        programInterface,
+        index,
+        bufSize,
+        length,
+        name,
+      } => {
+        e.command(168);
+        e.u32(program);
+        e.u32(programInterface);
+        e.u32(index);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(name);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetProgramResourceiv {
+        program,
+        programInterface,
+        index,
+        propCount,
+        props,
+        bufSize,
+        length,
+        params,
+      } => {
+        e.command(169);
+        e.u32(program);
+        e.u32(programInterface);
+        e.u32(index);
+        e.i32(propCount);
+        e.array(props, propCount.max(0) as usize);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(params);
+      }
+      GlCall::GetProgramiv { program, pname, params } => {
+        e.command(170);
+        e.u32(program);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::GetQueryObjecti64vEXT { id, pname, params } => {
+        e.command(171);
+        e.u32(id);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::GetQueryObjectivEXT { id, pname, params } => {
+        e.command(172);
+        e.u32(id);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::GetQueryObjectui64vEXT { id, pname, params } => {
+        e.command(173);
+        e.u32(id);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetQueryObjectuiv { id, pname, params } => {
+        e.command(174);
+        e.u32(id);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::GetQueryObjectuivEXT { id, pname, params } => {
+        e.command(175);
+        e.u32(id);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetQueryiv { target, pname, params } => {
+        e.command(176);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::GetQueryivEXT { target, pname, params } => {
+        e.command(177);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetRenderbufferParameteriv { target, pname, params } => {
+        e.command(178);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetSamplerParameterIiv { sampler, pname, params } => {
+        e.command(179);
+        e.u32(sampler);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetSamplerParameterIuiv { sampler, pname, params } => {
+        e.command(180);
+        e.u32(sampler);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetSamplerParameterfv { sampler, pname, params } => {
+        e.command(181);
+        e.u32(sampler);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetSamplerParameteriv { sampler, pname, params } => {
+        e.command(182);
+        e.u32(sampler);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetShaderInfoLog { shader, bufSize, length, infoLog } => {
+        e.command(183);
+        e.u32(shader);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(infoLog);
+      }
+      GlCall::GetShaderPrecisionFormat {
+        shadertype,
+        precisiontype,
+        range,
+        precision,
+      } => {
+        e.command(184);
+        e.u32(shadertype);
+        e.u32(precisiontype);
+        e.address(range);
+        e.address(precision);
+      }
+      GlCall::GetShaderSource { shader, bufSize, length, source } => {
+        e.command(185);
+        e.u32(shader);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(source);
+      }
+      GlCall::GetShaderiv { shader, pname, params } => {
+        e.command(186);
+        e.u32(shader);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetString { name, ret } => {
+        e.command(187);
+        e.u32(name);
+        e.u64(ret as usize as u64);
+      }
+      GlCall::GetStringi { name, index, ret } => {
+        e.command(188);
+        e.u32(name);
+        e.u32(index);
+        e.u64(ret as usize as u64);
+      }
+      GlCall::GetSynciv { sync, pname, count, length, values } => {
+        e.command(189);
+        e.u64(sync as usize as u64);
+        e.u32(pname);
+        e.i32(count);
+        e.address(length);
+        e.address(values);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetTexLevelParameterfv { target, level, pname, params } => {
+        e.command(190);
+        e.u32(target);
+        e.i32(level);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::GetTexLevelParameteriv { target, level, pname, params } => {
+        e.command(191);
+        e.u32(target);
+        e.i32(level);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetTexParameterIiv { target, pname, params } => {
+        e.command(192);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetTexParameterIuiv { target, pname, params } => {
+        e.command(193);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetTexParameterfv { target, pname, params } => {
+        e.command(194);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetTexParameteriv { target, pname, params } => {
+        e.command(195);
+        e.u32(target);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetTransformFeedbackVarying {
+        program,
+        index,
+        bufSize,
+        length,
+        size,
+        type_,
+        name,
+      } => {
+        e.command(196);
+        e.u32(program);
+        e.u32(index);
+        e.i32(bufSize);
+        e.address(length);
+        e.address(size);
+        e.address(type_);
+        e.address(name);
+      }
+      GlCall::GetUniformBlockIndex { program, uniformBlockName, ret } => {
+        e.command(197);
+        e.u32(program);
+        e.c_str(uniformBlockName, -1);
+        e.u32(ret);
+      }
+      GlCall::GetUniformIndices {
+        program,
+        uniformCount,
+        uniformNames,
+        uniformIndices,
+      } => {
+        e.command(198);
+        e.u32(program);
+        e.i32(uniformCount);
+        e.strings(uniformNames, uniformCount, null());
+        e.address(uniformIndices);
+      }
+      GlCall::GetUniformLocation { program, name, ret } => {
+        e.command(199);
+        e.u32(program);
+        e.c_str(name, -1);
+        e.i32(ret);
+      }
+      GlCall::GetUniformfv { program, location, params } => {
+        e.command(200);
+        e.u32(program);
+        e.i32(location);
+        e.address(params);
+      }
+      GlCall::GetUniformiv { program, location, params } => {
+        e.command(201);
+        e.u32(program);
+        e.i32(location);
+        e.address(params);
+      }
+      GlCall::GetUniformuiv { program, location, params } => {
+        e.command(202);
+        e.u32(program);
+        e.i32(location);
+        e.address(params);
+      }
+      GlCall::GetVertexAttribIiv { index, pname, params } => {
+        e.command(203);
+        e.u32(index);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetVertexAttribIuiv { index, pname, params } => {
+        e.command(204);
+        e.u32(index);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetVertexAttribPointerv { index, pname, pointer } => {
+        e.command(205);
+        e.u32(index);
+        e.u32(pname);
+        e.address(pointer);
+      }
+      GlCall::GetVertexAttribfv { index, pname, params } => {
+        e.command(206);
+        e.u32(index);
+        e.u32(pname);
+        e.address(params);
+      }
+      GlCall::GetVertexAttribiv { index, pname, params } => {
+        e.command(207);
+        e.u32(index);
+        e.u32(pname);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetnUniformfv { program, location, bufSize, params } => {
+        e.command(208);
+        e.u32(program);
+        e.i32(location);
+        e.i32(bufSize);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetnUniformiv { program, location, bufSize, params } => {
+        e.command(209);
+        e.u32(program);
+        e.i32(location);
+        e.i32(bufSize);
+        e.address(params);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::GetnUniformuiv { program, location, bufSize, params } => {
+        e.command(210);
+        e.u32(program);
+        e.i32(location);
+        e.i32(bufSize);
+        e.address(params);
+      }
+      GlCall::Hint { target, mode } => {
+        e.command(211);
+        e.u32(target);
+        e.u32(mode);
+      }
+      GlCall::InvalidateFramebuffer { target, numAttachments, attachments } => {
+        e.command(212);
+        e.u32(target);
+        e.i32(numAttachments);
+        e.array(attachments, numAttachments.max(0) as usize);
+      }
+      GlCall::InvalidateSubFramebuffer {
+        target,
+        numAttachments,
+        attachments,
+        x,
+        y,
+        width,
+        height,
+      } => {
+        e.command(213);
+        e.u32(target);
+        e.i32(numAttachments);
+        e.array(attachments, numAttachments.max(0) as usize);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+      }
+      GlCall::IsBuffer { buffer, ret } => {
+        e.command(214);
+        e.u32(buffer);
+        e.u8(ret);
+      }
+      GlCall::IsEnabled { cap, ret } => {
+        e.command(215);
+        e.u32(cap);
+        e.u8(ret);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::IsEnabledi { target, index, ret } => {
+        e.command(216);
+        e.u32(target);
+        e.u32(index);
+        e.u8(ret);
+      }
+      GlCall::IsFramebuffer { framebuffer, ret } => {
+        e.command(217);
+        e.u32(framebuffer);
+        e.u8(ret);
+      }
+      GlCall::IsProgram { program, ret } => {
+        e.command(218);
+        e.u32(program);
+        e.u8(ret);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::IsProgramPipeline { pipeline, ret } => {
+        e.command(219);
+        e.u32(pipeline);
+        e.u8(ret);
+      }
+      GlCall::IsQuery { id, ret } => {
+        e.command(220);
+        e.u32(id);
+        e.u8(ret);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::IsQueryEXT { id, ret } => {
+        e.command(221);
+        e.u32(id);
+        e.u8(ret);
+      }
+      GlCall::IsRenderbuffer { renderbuffer, ret } => {
+        e.command(222);
+        e.u32(renderbuffer);
+        e.u8(ret);
+      }
+      GlCall::IsSampler { sampler, ret } => {
+        e.command(223);
+        e.u32(sampler);
+        e.u8(ret);
+      }
+      GlCall::IsShader { shader, ret } => {
+        e.command(224);
+        e.u32(shader);
+        e.u8(ret);
+      }
+      GlCall::IsSync { sync, ret } => {
+        e.command(225);
+        e.u64(sync as usize as u64);
+        e.u8(ret);
+      }
+      GlCall::IsTexture { texture, ret } => {
+        e.command(226);
+        e.u32(texture);
+        e.u8(ret);
+      }
+      GlCall::IsTransformFeedback { id, ret } => {
+        e.command(227);
+        e.u32(id);
+        e.u8(ret);
+      }
+      GlCall::IsVertexArray { array, ret } => {
+        e.command(228);
+        e.u32(array);
+        e.u8(ret);
+      }
+      GlCall::LineWidth { width } => {
+        e.command(229);
+        e.f32(width);
+      }
+      GlCall::LinkProgram { program } => {
+        e.command(230);
+        e.u32(program);
+      }
+      GlCall::MapBufferRange { target, offset, length, access, ret } => {
+        e.command(231);
+        e.u32(target);
+        e.i64(offset as i64);
+        e.i64(length as i64);
+        e.u32(access);
+        e.u64(ret as usize as u64);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::MemoryBarrier { barriers } => {
+        e.command(232);
+        e.u32(barriers);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::MemoryBarrierByRegion { barriers } => {
+        e.command(233);
+        e.u32(barriers);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::MinSampleShading { value } => {
+        e.command(234);
+        e.f32(value);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::ObjectLabel { identifier, name, length, label } => {
+        e.command(235);
+        e.u32(identifier);
+        e.u32(name);
+        e.i32(length);
+        e.c_str(label, length);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::ObjectLabelKHR { identifier, name, length, label } => {
+        e.command(236);
+        e.u32(identifier);
+        e.u32(name);
+        e.i32(length);
+        e.c_str(label, length);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::ObjectPtrLabel { ptr, length, label } => {
+        e.command(237);
+        e.address(ptr);
+        e.i32(length);
+        e.c_str(label, length);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::ObjectPtrLabelKHR { ptr, length, label } => {
+        e.command(238);
+        e.address(ptr);
+        e.i32(length);
+        e.c_str(label, length);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::PatchParameteri { pname, value } => {
+        e.command(239);
+        e.u32(pname);
+        e.i32(value);
+      }
+      GlCall::PauseTransformFeedback => {
+        e.command(240);
+      }
+      GlCall::PixelStorei { pname, param } => {
+        e.command(241);
+        e.u32(pname);
+        e.i32(param);
+      }
+      GlCall::PolygonOffset { factor, units } => {
+        e.command(242);
+        e.f32(factor);
+        e.f32(units);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::PopDebugGroup => {
+        e.command(243);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::PopDebugGroupKHR => {
+        e.command(244);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::PrimitiveBoundingBox {
+        minX,
+        minY,
+        minZ,
+        minW,
+        maxX,
+        maxY,
+        maxZ,
+        maxW,
+      } => {
+        e.command(245);
+        e.f32(minX);
+        e.f32(minY);
+        e.f32(minZ);
+        e.f32(minW);
+        e.f32(maxX);
+        e.f32(maxY);
+        e.f32(maxZ);
+        e.f32(maxW);
+      }
+      GlCall::ProgramBinary { program, binaryFormat, binary, length } => {
+        e.command(246);
+        e.u32(program);
+        e.u32(binaryFormat);
+        e.bytes(binary, length.max(0) as usize);
+        e.i32(length);
+      }
+      GlCall::ProgramParameteri { program, pname, value } => {
+        e.command(247);
+        e.u32(program);
+        e.u32(pname);
+        e.i32(value);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform1f { program, location, v0 } => {
+        e.command(248);
+        e.u32(program);
+        e.i32(location);
+        e.f32(v0);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform1fv { program, location, count, value } => {
+        e.command(249);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform1i { program, location, v0 } => {
+        e.command(250);
+        e.u32(program);
+        e.i32(location);
+        e.i32(v0);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform1iv { program, location, count, value } => {
+        e.command(251);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform1ui { program, location, v0 } => {
+        e.command(252);
+        e.u32(program);
+        e.i32(location);
+        e.u32(v0);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform1uiv { program, location, count, value } => {
+        e.command(253);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform2f { program, location, v0, v1 } => {
+        e.command(254);
+        e.u32(program);
+        e.i32(location);
+        e.f32(v0);
+        e.f32(v1);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform2fv { program, location, count, value } => {
+        e.command(255);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 2);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform2i { program, location, v0, v1 } => {
+        e.command(256);
+        e.u32(program);
+        e.i32(location);
+        e.i32(v0);
+        e.i32(v1);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform2iv { program, location, count, value } => {
+        e.command(257);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 2);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform2ui { program, location, v0, v1 } => {
+        e.command(258);
+        e.u32(program);
+        e.i32(location);
+        e.u32(v0);
+        e.u32(v1);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform2uiv { program, location, count, value } => {
+        e.command(259);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 2);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform3f { program, location, v0, v1, v2 } => {
+        e.command(260);
+        e.u32(program);
+        e.i32(location);
+        e.f32(v0);
+        e.f32(v1);
+        e.f32(v2);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform3fv { program, location, count, value } => {
+        e.command(261);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 3);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform3i { program, location, v0, v1, v2 } => {
+        e.command(262);
+        e.u32(program);
+        e.i32(location);
+        e.i32(v0);
+        e.i32(v1);
+        e.i32(v2);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform3iv { program, location, count, value } => {
+        e.command(263);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 3);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform3ui { program, location, v0, v1, v2 } => {
+        e.command(264);
+        e.u32(program);
+        e.i32(location);
+        e.u32(v0);
+        e.u32(v1);
+        e.u32(v2);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform3uiv { program, location, count, value } => {
+        e.command(265);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 3);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform4f { program, location, v0, v1, v2, v3 } => {
+        e.command(266);
+        e.u32(program);
+        e.i32(location);
+        e.f32(v0);
+        e.f32(v1);
+        e.f32(v2);
+        e.f32(v3);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform4fv { program, location, count, value } => {
+        e.command(267);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform4i { program, location, v0, v1, v2, v3 } => {
+        e.command(268);
+        e.u32(program);
+        e.i32(location);
+        e.i32(v0);
+        e.i32(v1);
+        e.i32(v2);
+        e.i32(v3);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform4iv { program, location, count, value } => {
+        e.command(269);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform4ui { program, location, v0, v1, v2, v3 } => {
+        e.command(270);
+        e.u32(program);
+        e.i32(location);
+        e.u32(v0);
+        e.u32(v1);
+        e.u32(v2);
+        e.u32(v3);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniform4uiv { program, location, count, value } => {
+        e.command(271);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix2fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(272);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix2x3fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(273);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 6);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix2x4fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(274);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 8);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix3fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(275);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 9);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix3x2fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(276);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 6);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix3x4fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(277);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 12);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix4fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(278);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 16);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix4x2fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(279);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 8);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ProgramUniformMatrix4x3fv {
+        program,
+        location,
+        count,
+        transpose,
+        value,
+      } => {
+        e.command(280);
+        e.u32(program);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 12);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::PushDebugGroup { source, id, length, message } => {
+        e.command(281);
+        e.u32(source);
+        e.u32(id);
+        e.i32(length);
+        e.c_str(message, length);
+      }
+      #[cfg(feature = "GL_KHR_debug")]
+      GlCall::PushDebugGroupKHR { source, id, length, message } => {
+        e.command(282);
+        e.u32(source);
+        e.u32(id);
+        e.i32(length);
+        e.c_str(message, length);
+      }
+      #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+      GlCall::QueryCounterEXT { id, target } => {
+        e.command(283);
+        e.u32(id);
+        e.u32(target);
+      }
+      GlCall::ReadBuffer { src } => {
+        e.command(284);
+        e.u32(src);
+      }
+      GlCall::ReadPixels { x, y, width, height, format, type_, pixels } => {
+        e.command(285);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+        e.u32(format);
+        e.u32(type_);
+        e.address(pixels);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::ReadnPixels {
+        x,
+        y,
+        width,
+        height,
+        format,
+        type_,
+        bufSize,
+        data,
+      } => {
+        e.command(286);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+        e.u32(format);
+        e.u32(type_);
+        e.i32(bufSize);
+        e.address(data);
+      }
+      GlCall::ReleaseShaderCompiler => {
+        e.command(287);
+      }
+      GlCall::RenderbufferStorage { target, internalformat, width, height } => {
+        e.command(288);
+        e.u32(target);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+      }
+      GlCall::RenderbufferStorageMultisample {
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+      } => {
+        e.command(289);
+        e.u32(target);
+        e.i32(samples);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+      }
+      #[cfg(feature = "GL_EXT_multisampled_render_to_texture")]
+      GlCall::RenderbufferStorageMultisampleEXT {
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+      } => {
+        e.command(290);
+        e.u32(target);
+        e.i32(samples);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+      }
+      GlCall::ResumeTransformFeedback => {
+        e.command(291);
+      }
+      GlCall::SampleCoverage { value, invert } => {
+        e.command(292);
+        e.f32(value);
+        e.u8(invert);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::SampleMaski { maskNumber, mask } => {
+        e.command(293);
+        e.u32(maskNumber);
+        e.u32(mask);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::SamplerParameterIiv { sampler, pname, param } => {
+        e.command(294);
+        e.u32(sampler);
+        e.u32(pname);
+        e.array(param, parameter_len(pname));
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::SamplerParameterIuiv { sampler, pname, param } => {
+        e.command(295);
+        e.u32(sampler);
+        e.u32(pname);
+        e.array(param, parameter_len(pname));
+      }
+      GlCall::SamplerParameterf { sampler, pname, param } => {
+        e.command(296);
+        e.u32(sampler);
+        e.u32(pname);
+        e.f32(param);
+      }
+      GlCall::SamplerParameterfv { sampler, pname, param } => {
+        e.command(297);
+        e.u32(sampler);
+        e.u32(pname);
+        e.array(param, parameter_len(pname));
+      }
+      GlCall::SamplerParameteri { sampler, pname, param } => {
+        e.command(298);
+        e.u32(sampler);
+        e.u32(pname);
+        e.i32(param);
+      }
+      GlCall::SamplerParameteriv { sampler, pname, param } => {
+        e.command(299);
+        e.u32(sampler);
+        e.u32(pname);
+        e.array(param, parameter_len(pname));
+      }
+      GlCall::Scissor { x, y, width, height } => {
+        e.command(300);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+      }
+      GlCall::ShaderBinary { count, shaders, binaryformat, binary, length } => {
+        e.command(301);
+        e.i32(count);
+        e.array(shaders, count.max(0) as usize);
+        e.u32(binaryformat);
+        e.bytes(binary, length.max(0) as usize);
+        e.i32(length);
+      }
+      GlCall::ShaderSource { shader, count, string, length } => {
+        e.command(302);
+        e.u32(shader);
+        e.i32(count);
+        e.strings(string, count, length);
+        e.address(length);
+      }
+      GlCall::StencilFunc { func, ref_, mask } => {
+        e.command(303);
+        e.u32(func);
+        e.i32(ref_);
+        e.u32(mask);
+      }
+      GlCall::StencilFuncSeparate { face, func, ref_, mask } => {
+        e.command(304);
+        e.u32(face);
+        e.u32(func);
+        e.i32(ref_);
+        e.u32(mask);
+      }
+      GlCall::StencilMask { mask } => {
+        e.command(305);
+        e.u32(mask);
+      }
+      GlCall::StencilMaskSeparate { face, mask } => {
+        e.command(306);
+        e.u32(face);
+        e.u32(mask);
+      }
+      GlCall::StencilOp { fail, zfail, zpass } => {
+        e.command(307);
+        e.u32(fail);
+        e.u32(zfail);
+        e.u32(zpass);
+      }
+      GlCall::StencilOpSeparate { face, sfail, dpfail, dppass } => {
+        e.command(308);
+        e.u32(face);
+        e.u32(sfail);
+        e.u32(dpfail);
+        e.u32(dppass);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::TexBuffer { target, internalformat, buffer } => {
+        e.command(309);
+        e.u32(target);
+        e.u32(internalformat);
+        e.u32(buffer);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::TexBufferRange {
+        target,
+        internalformat,
+        buffer,
+        offset,
+        size,
+      } => {
+        e.command(310);
+        e.u32(target);
+        e.u32(internalformat);
+        e.u32(buffer);
+        e.i64(offset as i64);
+        e.i64(size as i64);
+      }
+      GlCall::TexImage2D {
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        border,
+        format,
+        type_,
+        pixels,
+      } => {
+        e.command(311);
+        e.u32(target);
+        e.i32(level);
+        e.i32(internalformat);
+        e.i32(width);
+        e.i32(height);
+        e.i32(border);
+        e.u32(format);
+        e.u32(type_);
+        e.pixels(pixels, format, type_, width, height, 1);
+      }
+      GlCall::TexImage3D {
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        depth,
+        border,
+        format,
+        type_,
+        pixels,
+      } => {
+        e.command(312);
+        e.u32(target);
+        e.i32(level);
+        e.i32(internalformat);
+        e.i32(width);
+        e.i32(height);
+        e.i32(depth);
+        e.i32(border);
+        e.u32(format);
+        e.u32(type_);
+        e.pixels(pixels, format, type_, width, height, depth);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::TexParameterIiv { target, pname, params } => {
+        e.command(313);
+        e.u32(target);
+        e.u32(pname);
+        e.array(params, parameter_len(pname));
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::TexParameterIuiv { target, pname, params } => {
+        e.command(314);
+        e.u32(target);
+        e.u32(pname);
+        e.array(params, parameter_len(pname));
+      }
+      GlCall::TexParameterf { target, pname, param } => {
+        e.command(315);
+        e.u32(target);
+        e.u32(pname);
+        e.f32(param);
+      }
+      GlCall::TexParameterfv { target, pname, params } => {
+        e.command(316);
+        e.u32(target);
+        e.u32(pname);
+        e.array(params, parameter_len(pname));
+      }
+      GlCall::TexParameteri { target, pname, param } => {
+        e.command(317);
+        e.u32(target);
+        e.u32(pname);
+        e.i32(param);
+      }
+      GlCall::TexParameteriv { target, pname, params } => {
+        e.command(318);
+        e.u32(target);
+        e.u32(pname);
+        e.array(params, parameter_len(pname));
+      }
+      GlCall::TexStorage2D {
+        target,
+        levels,
+        internalformat,
+        width,
+        height,
+      } => {
+        e.command(319);
+        e.u32(target);
+        e.i32(levels);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::TexStorage2DMultisample {
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        fixedsamplelocations,
+      } => {
+        e.command(320);
+        e.u32(target);
+        e.i32(samples);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+        e.u8(fixedsamplelocations);
+      }
+      GlCall::TexStorage3D {
+        target,
+        levels,
+        internalformat,
+        width,
+        height,
+        depth,
+      } => {
+        e.command(321);
+        e.u32(target);
+        e.i32(levels);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+        e.i32(depth);
+      }
+      #[cfg(feature = "gles32")]
+      GlCall::TexStorage3DMultisample {
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        depth,
+        fixedsamplelocations,
+      } => {
+        e.command(322);
+        e.u32(target);
+        e.i32(samples);
+        e.u32(internalformat);
+        e.i32(width);
+        e.i32(height);
+        e.i32(depth);
+        e.u8(fixedsamplelocations);
+      }
+      GlCall::TexSubImage2D {
+        target,
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        format,
+        type_,
+        pixels,
+      } => {
+        e.command(323);
+        e.u32(target);
+        e.i32(level);
+        e.i32(xoffset);
+        e.i32(yoffset);
+        e.i32(width);
+        e.i32(height);
+        e.u32(format);
+        e.u32(type_);
+        e.pixels(pixels, format, type_, width, height, 1);
+      }
+      GlCall::TexSubImage3D {
+        target,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        type_,
+        pixels,
+      } => {
+        e.command(324);
+        e.u32(target);
+        e.i32(level);
+        e.i32(xoffset);
+        e.i32(yoffset);
+        e.i32(zoffset);
+        e.i32(width);
+        e.i32(height);
+        e.i32(depth);
+        e.u32(format);
+        e.u32(type_);
+        e.pixels(pixels, format, type_, width, height, depth);
+      }
+      GlCall::TransformFeedbackVaryings {
+        program,
+        count,
+        varyings,
+        bufferMode,
+      } => {
+        e.command(325);
+        e.u32(program);
+        e.i32(count);
+        e.strings(varyings, count, null());
+        e.u32(bufferMode);
+      }
+      GlCall::Uniform1f { location, v0 } => {
+        e.command(326);
+        e.i32(location);
+        e.f32(v0);
+      }
+      GlCall::Uniform1fv { location, count, value } => {
+        e.command(327);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize);
+      }
+      GlCall::Uniform1i { location, v0 } => {
+        e.command(328);
+        e.i32(location);
+        e.i32(v0);
+      }
+      GlCall::Uniform1iv { location, count, value } => {
+        e.command(329);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize);
+      }
+      GlCall::Uniform1ui { location, v0 } => {
+        e.command(330);
+        e.i32(location);
+        e.u32(v0);
+      }
+      GlCall::Uniform1uiv { location, count, value } => {
+        e.command(331);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize);
+      }
+      GlCall::Uniform2f { location, v0, v1 } => {
+        e.command(332);
+        e.i32(location);
+        e.f32(v0);
+        e.f32(v1);
+      }
+      GlCall::Uniform2fv { location, count, value } => {
+        e.command(333);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 2);
+      }
+      GlCall::Uniform2i { location, v0, v1 } => {
+        e.command(334);
+        e.i32(location);
+        e.i32(v0);
+        e.i32(v1);
+      }
+      GlCall::Uniform2iv { location, count, value } => {
+        e.command(335);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 2);
+      }
+      GlCall::Uniform2ui { location, v0, v1 } => {
+        e.command(336);
+        e.i32(location);
+        e.u32(v0);
+        e.u32(v1);
+      }
+      GlCall::Uniform2uiv { location, count, value } => {
+        e.command(337);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 2);
+      }
+      GlCall::Uniform3f { location, v0, v1, v2 } => {
+        e.command(338);
+        e.i32(location);
+        e.f32(v0);
+        e.f32(v1);
+        e.f32(v2);
+      }
+      GlCall::Uniform3fv { location, count, value } => {
+        e.command(339);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 3);
+      }
+      GlCall::Uniform3i { location, v0, v1, v2 } => {
+        e.command(340);
+        e.i32(location);
+        e.i32(v0);
+        e.i32(v1);
+        e.i32(v2);
+      }
+      GlCall::Uniform3iv { location, count, value } => {
+        e.command(341);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 3);
+      }
+      GlCall::Uniform3ui { location, v0, v1, v2 } => {
+        e.command(342);
+        e.i32(location);
+        e.u32(v0);
+        e.u32(v1);
+        e.u32(v2);
+      }
+      GlCall::Uniform3uiv { location, count, value } => {
+        e.command(343);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 3);
+      }
+      GlCall::Uniform4f { location, v0, v1, v2, v3 } => {
+        e.command(344);
+        e.i32(location);
+        e.f32(v0);
+        e.f32(v1);
+        e.f32(v2);
+        e.f32(v3);
+      }
+      GlCall::Uniform4fv { location, count, value } => {
+        e.command(345);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      GlCall::Uniform4i { location, v0, v1, v2, v3 } => {
+        e.command(346);
+        e.i32(location);
+        e.i32(v0);
+        e.i32(v1);
+        e.i32(v2);
+        e.i32(v3);
+      }
+      GlCall::Uniform4iv { location, count, value } => {
+        e.command(347);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      GlCall::Uniform4ui { location, v0, v1, v2, v3 } => {
+        e.command(348);
+        e.i32(location);
+        e.u32(v0);
+        e.u32(v1);
+        e.u32(v2);
+        e.u32(v3);
+      }
+      GlCall::Uniform4uiv { location, count, value } => {
+        e.command(349);
+        e.i32(location);
+        e.i32(count);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      GlCall::UniformBlockBinding {
+        program,
+        uniformBlockIndex,
+        uniformBlockBinding,
+      } => {
+        e.command(350);
+        e.u32(program);
+        e.u32(uniformBlockIndex);
+        e.u32(uniformBlockBinding);
+      }
+      GlCall::UniformMatrix2fv { location, count, transpose, value } => {
+        e.command(351);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 4);
+      }
+      GlCall::UniformMatrix2x3fv { location, count, transpose, value } => {
+        e.command(352);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 6);
+      }
+      GlCall::UniformMatrix2x4fv { location, count, transpose, value } => {
+        e.command(353);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 8);
+      }
+      GlCall::UniformMatrix3fv { location, count, transpose, value } => {
+        e.command(354);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 9);
+      }
+      GlCall::UniformMatrix3x2fv { location, count, transpose, value } => {
+        e.command(355);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 6);
+      }
+      GlCall::UniformMatrix3x4fv { location, count, transpose, value } => {
+        e.command(356);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 12);
+      }
+      GlCall::UniformMatrix4fv { location, count, transpose, value } => {
+        e.command(357);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 16);
+      }
+      GlCall::UniformMatrix4x2fv { location, count, transpose, value } => {
+        e.command(358);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 8);
+      }
+      GlCall::UniformMatrix4x3fv { location, count, transpose, value } => {
+        e.command(359);
+        e.i32(location);
+        e.i32(count);
+        e.u8(transpose);
+        e.array(value, count.max(0) as usize * 12);
+      }
+      GlCall::UnmapBuffer { target, ret } => {
+        e.command(360);
+        e.u32(target);
+        e.u8(ret);
+      }
+      GlCall::UseProgram { program } => {
+        e.command(361);
+        e.u32(program);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::UseProgramStages { pipeline, stages, program } => {
+        e.command(362);
+        e.u32(pipeline);
+        e.u32(stages);
+        e.u32(program);
+      }
+      GlCall::ValidateProgram { program } => {
+        e.command(363);
+        e.u32(program);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::ValidateProgramPipeline { pipeline } => {
+        e.command(364);
+        e.u32(pipeline);
+      }
+      GlCall::VertexAttrib1f { index, x } => {
+        e.command(365);
+        e.u32(index);
+        e.f32(x);
+      }
+      GlCall::VertexAttrib1fv { index, v } => {
+        e.command(366);
+        e.u32(index);
+        e.array(v, 1);
+      }
+      GlCall::VertexAttrib2f { index, x, y } => {
+        e.command(367);
+        e.u32(index);
+        e.f32(x);
+        e.f32(y);
+      }
+      GlCall::VertexAttrib2fv { index, v } => {
+        e.command(368);
+        e.u32(index);
+        e.array(v, 2);
+      }
+      GlCall::VertexAttrib3f { index, x, y, z } => {
+        e.command(369);
+        e.u32(index);
+        e.f32(x);
+        e.f32(y);
+        e.f32(z);
+      }
+      GlCall::VertexAttrib3fv { index, v } => {
+        e.command(370);
+        e.u32(index);
+        e.array(v, 3);
+      }
+      GlCall::VertexAttrib4f { index, x, y, z, w } => {
+        e.command(371);
+        e.u32(index);
+        e.f32(x);
+        e.f32(y);
+        e.f32(z);
+        e.f32(w);
+      }
+      GlCall::VertexAttrib4fv { index, v } => {
+        e.command(372);
+        e.u32(index);
+        e.array(v, 4);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::VertexAttribBinding { attribindex, bindingindex } => {
+        e.command(373);
+        e.u32(attribindex);
+        e.u32(bindingindex);
+      }
+      GlCall::VertexAttribDivisor { index, divisor } => {
+        e.command(374);
+        e.u32(index);
+        e.u32(divisor);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::VertexAttribFormat {
+        attribindex,
+        size,
+        type_,
+        normalized,
+        relativeoffset,
+      } => {
+        e.command(375);
+        e.u32(attribindex);
+        e.i32(size);
+        e.u32(type_);
+        e.u8(normalized);
+        e.u32(relativeoffset);
+      }
+      GlCall::VertexAttribI4i { index, x, y, z, w } => {
+        e.command(376);
+        e.u32(index);
+        e.i32(x);
+        e.i32(y);
+        e.i32(z);
+        e.i32(w);
+      }
+      GlCall::VertexAttribI4iv { index, v } => {
+        e.command(377);
+        e.u32(index);
+        e.array(v, 4);
+      }
+      GlCall::VertexAttribI4ui { index, x, y, z, w } => {
+        e.command(378);
+        e.u32(index);
+        e.u32(x);
+        e.u32(y);
+        e.u32(z);
+        e.u32(w);
+      }
+      GlCall::VertexAttribI4uiv { index, v } => {
+        e.command(379);
+        e.u32(index);
+        e.array(v, 4);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::VertexAttribIFormat {
+        attribindex,
+        size,
+        type_,
+        relativeoffset,
+      } => {
+        e.command(380);
+        e.u32(attribindex);
+        e.i32(size);
+        e.u32(type_);
+        e.u32(relativeoffset);
+      }
+      GlCall::VertexAttribIPointer { index, size, type_, stride, pointer } => {
+        e.command(381);
+        e.u32(index);
+        e.i32(size);
+        e.u32(type_);
+        e.i32(stride);
+        e.address(pointer);
+      }
+      GlCall::VertexAttribPointer {
+        index,
+        size,
+        type_,
+        normalized,
+        stride,
+        pointer,
+      } => {
+        e.command(382);
+        e.u32(index);
+        e.i32(size);
+        e.u32(type_);
+        e.u8(normalized);
+        e.i32(stride);
+        e.address(pointer);
+      }
+      #[cfg(feature = "gles31")]
+      GlCall::VertexBindingDivisor { bindingindex, divisor } => {
+        e.command(383);
+        e.u32(bindingindex);
+        e.u32(divisor);
+      }
+      GlCall::Viewport { x, y, width, height } => {
+        e.command(384);
+        e.i32(x);
+        e.i32(y);
+        e.i32(width);
+        e.i32(height);
+      }
+      GlCall::WaitSync { sync, flags, timeout } => {
+        e.command(385);
+        e.u64(sync as usize as u64);
+        e.u32(flags);
+        e.u64(timeout);
+      }
+    }
+  }
+}
+
+/// Decodes the call with the given index from `d` and makes it with `gl`.
+#[cfg(feature = "struct_loader")]
+#[allow(unused_mut, unused_variables, clippy::let_unit_value)]
+pub(super) unsafe fn replay_call(
+  st: &mut ReplayState, gl: &GlFns, index: usize, d: &mut Decoder,
+) -> Result<(), TraceError> {
+  match index {
+    #[cfg(feature = "gles31")]
+    0 => {
+      let pipeline = st.names.get(NameKind::ProgramPipeline, d.u32()?);
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      gl.ActiveShaderProgram(pipeline, program);
+    }
+    1 => {
+      let texture = d.u32()?;
+      gl.ActiveTexture(texture);
+    }
+    2 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      gl.AttachShader(program, shader);
+    }
+    3 => {
+      let target = d.u32()?;
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      gl.BeginQuery(target, id);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    4 => {
+      let target = d.u32()?;
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      gl.BeginQueryEXT(target, id);
+    }
+    5 => {
+      let primitiveMode = d.u32()?;
+      gl.BeginTransformFeedback(primitiveMode);
+    }
+    6 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let index = d.u32()?;
+      let mut name_arg = d.pointer()?;
+      let name = name_arg.as_ptr::<GLchar>();
+      gl.BindAttribLocation(program, index, name);
+    }
+    7 => {
+      let target = d.u32()?;
+      let buffer = st.names.get(NameKind::Buffer, d.u32()?);
+      gl.BindBuffer(target, buffer);
+    }
+    8 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      let buffer = st.names.get(NameKind::Buffer, d.u32()?);
+      gl.BindBufferBase(target, index, buffer);
+    }
+    9 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      let buffer = st.names.get(NameKind::Buffer, d.u32()?);
+      let offset = d.i64()? as isize;
+      let size = d.i64()? as isize;
+      gl.BindBufferRange(target, index, buffer, offset, size);
+    }
+    10 => {
+      let target = d.u32()?;
+      let framebuffer = st.names.get(NameKind::Framebuffer, d.u32()?);
+      gl.BindFramebuffer(target, framebuffer);
+    }
+    #[cfg(feature = "gles31")]
+    11 => {
+      let unit = d.u32()?;
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      let level = d.i32()?;
+      let layered = d.u8()?;
+      let layer = d.i32()?;
+      let access = d.u32()?;
+      let format = d.u32()?;
+      gl.BindImageTexture(unit, texture, level, layered, layer, access, format);
+    }
+    #[cfg(feature = "gles31")]
+    12 => {
+      let pipeline = st.names.get(NameKind::ProgramPipeline, d.u32()?);
+      gl.BindProgramPipeline(pipeline);
+    }
+    13 => {
+      let target = d.u32()?;
+      let renderbuffer = st.names.get(NameKind::Renderbuffer, d.u32()?);
+      gl.BindRenderbuffer(target, renderbuffer);
+    }
+    14 => {
+      let unit = d.u32()?;
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      gl.BindSampler(unit, sampler);
+    }
+    15 => {
+      let target = d.u32()?;
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      gl.BindTexture(target, texture);
+    }
+    16 => {
+      let target = d.u32()?;
+      let id = st.names.get(NameKind::TransformFeedback, d.u32()?);
+      gl.BindTransformFeedback(target, id);
+    }
+    17 => {
+      let array = st.names.get(NameKind::VertexArray, d.u32()?);
+      gl.BindVertexArray(array);
+    }
+    #[cfg(feature = "gles31")]
+    18 => {
+      let bindingindex = d.u32()?;
+      let buffer = st.names.get(NameKind::Buffer, d.u32()?);
+      let offset = d.i64()? as isize;
+      let stride = d.i32()?;
+      gl.BindVertexBuffer(bindingindex, buffer, offset, stride);
+    }
+    #[cfg(feature = "gles32")]
+    19 => {
+      gl.BlendBarrier();
+    }
+    20 => {
+      let red = d.f32()?;
+      let green = d.f32()?;
+      let blue = d.f32()?;
+      let alpha = d.f32()?;
+      gl.BlendColor(red, green, blue, alpha);
+    }
+    21 => {
+      let mode = d.u32()?;
+      gl.BlendEquation(mode);
+    }
+    22 => {
+      let modeRGB = d.u32()?;
+      let modeAlpha = d.u32()?;
+      gl.BlendEquationSeparate(modeRGB, modeAlpha);
+    }
+    #[cfg(feature = "gles32")]
+    23 => {
+      let buf = d.u32()?;
+      let modeRGB = d.u32()?;
+      let modeAlpha = d.u32()?;
+      gl.BlendEquationSeparatei(buf, modeRGB, modeAlpha);
+    }
+    #[cfg(feature = "gles32")]
+    24 => {
+      let buf = d.u32()?;
+      let mode = d.u32()?;
+      gl.BlendEquationi(buf, mode);
+    }
+    25 => {
+      let sfactor = d.u32()?;
+      let dfactor = d.u32()?;
+      gl.BlendFunc(sfactor, dfactor);
+    }
+    26 => {
+      let sfactorRGB = d.u32()?;
+      let dfactorRGB = d.u32()?;
+      let sfactorAlpha = d.u32()?;
+      let dfactorAlpha = d.u32()?;
+      gl.BlendFuncSeparate(sfactorRGB, dfactorRGB, sfactorAlpha, dfactorAlpha);
+    }
+    #[cfg(feature = "gles32")]
+    27 => {
+      let buf = d.u32()?;
+      let srcRGB = d.u32()?;
+      let dstRGB = d.u32()?;
+      let srcAlpha = d.u32()?;
+      let dstAlpha = d.u32()?;
+      gl.BlendFuncSeparatei(buf, srcRGB, dstRGB, srcAlpha, dstAlpha);
+    }
+    #[cfg(feature = "gles32")]
+    28 => {
+      let buf = d.u32()?;
+      let src = d.u32()?;
+      let dst = d.u32()?;
+      gl.BlendFunci(buf, src, dst);
+    }
+    29 => {
+      let srcX0 = d.i32()?;
+      let srcY0 = d.i32()?;
+      let srcX1 = d.i32()?;
+      let srcY1 = d.i32()?;
+      let dstX0 = d.i32()?;
+      let dstY0 = d.i32()?;
+      let dstX1 = d.i32()?;
+      let dstY1 = d.i32()?;
+      let mask = d.u32()?;
+      let filter = d.u32()?;
+      gl.BlitFramebuffer(
+        srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, filter,
+      );
+    }
+    30 => {
+      let target = d.u32()?;
+      let size = d.i64()? as isize;
+      let mut data_arg = d.pointer()?;
+      let usage = d.u32()?;
+      let data = data_arg.as_ptr::<c_void>();
+      gl.BufferData(target, size, data, usage);
+    }
+    #[cfg(feature = "GL_EXT_buffer_storage")]
+    31 => {
+      let target = d.u32()?;
+      let size = d.i64()? as isize;
+      let mut data_arg = d.pointer()?;
+      let flags = d.u32()?;
+      let data = data_arg.as_ptr::<c_void>();
+      gl.BufferStorageEXT(target, size, data, flags);
+    }
+    32 => {
+      let target = d.u32()?;
+      let offset = d.i64()? as isize;
+      let size = d.i64()? as isize;
+      let mut data_arg = d.pointer()?;
+      let data = data_arg.as_ptr::<c_void>();
+      gl.BufferSubData(target, offset, size, data);
+    }
+    33 => {
+      let target = d.u32()?;
+      let out = gl.CheckFramebufferStatus(target);
+      d.u32()?;
+      let _ = out;
+    }
+    34 => {
+      let mask = d.u32()?;
+      gl.Clear(mask);
+    }
+    35 => {
+      let buffer = d.u32()?;
+      let drawbuffer = d.i32()?;
+      let depth = d.f32()?;
+      let stencil = d.i32()?;
+      gl.ClearBufferfi(buffer, drawbuffer, depth, stencil);
+    }
+    36 => {
+      let buffer = d.u32()?;
+      let drawbuffer = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ClearBufferfv(buffer, drawbuffer, value);
+    }
+    37 => {
+      let buffer = d.u32()?;
+      let drawbuffer = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.ClearBufferiv(buffer, drawbuffer, value);
+    }
+    38 => {
+      let buffer = d.u32()?;
+      let drawbuffer = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.ClearBufferuiv(buffer, drawbuffer, value);
+    }
+    39 => {
+      let red = d.f32()?;
+      let green = d.f32()?;
+      let blue = d.f32()?;
+      let alpha = d.f32()?;
+      gl.ClearColor(red, green, blue, alpha);
+    }
+    40 => {
+      let d = d.f32()?;
+      gl.ClearDepthf(d);
+    }
+    41 => {
+      let s = d.i32()?;
+      gl.ClearStencil(s);
+    }
+    42 => {
+      let sync = st.sync(d.u64()?);
+      let flags = d.u32()?;
+      let timeout = d.u64()?;
+      let out = gl.ClientWaitSync(sync, flags, timeout);
+      d.u32()?;
+      let _ = out;
+    }
+    43 => {
+      let red = d.u8()?;
+      let green = d.u8()?;
+      let blue = d.u8()?;
+      let alpha = d.u8()?;
+      gl.ColorMask(red, green, blue, alpha);
+    }
+    #[cfg(feature = "gles32")]
+    44 => {
+      let index = d.u32()?;
+      let r = d.u8()?;
+      let g = d.u8()?;
+      let b = d.u8()?;
+      let a = d.u8()?;
+      gl.ColorMaski(index, r, g, b, a);
+    }
+    45 => {
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      gl.CompileShader(shader);
+    }
+    46 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let border = d.i32()?;
+      let imageSize = d.i32()?;
+      let mut data_arg = d.pointer()?;
+      let data = data_arg.as_ptr::<c_void>();
+      gl.CompressedTexImage2D(
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        border,
+        imageSize,
+        data,
+      );
+    }
+    47 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let depth = d.i32()?;
+      let border = d.i32()?;
+      let imageSize = d.i32()?;
+      let mut data_arg = d.pointer()?;
+      let data = data_arg.as_ptr::<c_void>();
+      gl.CompressedTexImage3D(
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        depth,
+        border,
+        imageSize,
+        data,
+      );
+    }
+    48 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let xoffset = d.i32()?;
+      let yoffset = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let format = d.u32()?;
+      let imageSize = d.i32()?;
+      let mut data_arg = d.pointer()?;
+      let data = data_arg.as_ptr::<c_void>();
+      gl.CompressedTexSubImage2D(
+        target, level, xoffset, yoffset, width, height, format, imageSize, data,
+      );
+    }
+    49 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let xoffset = d.i32()?;
+      let yoffset = d.i32()?;
+      let zoffset = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let depth = d.i32()?;
+      let format = d.u32()?;
+      let imageSize = d.i32()?;
+      let mut data_arg = d.pointer()?;
+      let data = data_arg.as_ptr::<c_void>();
+      gl.CompressedTexSubImage3D(
+        target, level, xoffset, yoffset, zoffset, width, height, depth, format,
+        imageSize, data,
+      );
+    }
+    50 => {
+      let readTarget = d.u32()?;
+      let writeTarget = d.u32()?;
+      let readOffset = d.i64()? as isize;
+      let writeOffset = d.i64()? as isize;
+      let size = d.i64()? as isize;
+      gl.CopyBufferSubData(
+        readTarget,
+        writeTarget,
+        readOffset,
+        writeOffset,
+        size,
+      );
+    }
+    #[cfg(feature = "gles32")]
+    51 => {
+      let srcName = d.u32()?;
+      let srcTarget = d.u32()?;
+      let srcLevel = d.i32()?;
+      let srcX = d.i32()?;
+      let srcY = d.i32()?;
+      let srcZ = d.i32()?;
+      let dstName = d.u32()?;
+      let dstTarget = d.u32()?;
+      let dstLevel = d.i32()?;
+      let dstX = d.i32()?;
+      let dstY = d.i32()?;
+      let dstZ = d.i32()?;
+      let srcWidth = d.i32()?;
+      let srcHeight = d.i32()?;
+      let srcDepth = d.i32()?;
+      gl.CopyImageSubData(
+        srcName, srcTarget, srcLevel, srcX, srcY, srcZ, dstName, dstTarget,
+        dstLevel, dstX, dstY, dstZ, srcWidth, srcHeight, srcDepth,
+      );
+    }
+    52 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let internalformat = d.u32()?;
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let border = d.i32()?;
+      gl.CopyTexImage2D(
+        target,
+        level,
+        internalformat,
+        x,
+        y,
+        width,
+        height,
+        border,
+      );
+    }
+    53 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let xoffset = d.i32()?;
+      let yoffset = d.i32()?;
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.CopyTexSubImage2D(
+        target, level, xoffset, yoffset, x, y, width, height,
+      );
+    }
+    54 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let xoffset = d.i32()?;
+      let yoffset = d.i32()?;
+      let zoffset = d.i32()?;
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.CopyTexSubImage3D(
+        target, level, xoffset, yoffset, zoffset, x, y, width, height,
+      );
+    }
+    55 => {
+      let out = gl.CreateProgram();
+      st.names.insert(NameKind::Program, d.u32()?, out);
+    }
+    56 => {
+      let type_ = d.u32()?;
+      let out = gl.CreateShader(type_);
+      st.names.insert(NameKind::Program, d.u32()?, out);
+    }
+    #[cfg(feature = "gles31")]
+    57 => {
+      let type_ = d.u32()?;
+      let count = d.i32()?;
+      let mut strings_arg = d.pointer()?;
+      let strings_strings = strings_arg.strings();
+      let strings = strings_strings.as_ptr();
+      let out = gl.CreateShaderProgramv(type_, count, strings);
+      st.names.insert(NameKind::Program, d.u32()?, out);
+    }
+    58 => {
+      let mode = d.u32()?;
+      gl.CullFace(mode);
+    }
+    #[cfg(feature = "gles32")]
+    59 => {
+      d.u64()?;
+      let callback = None;
+      let mut userParam_arg = d.pointer()?;
+      let _ = userParam_arg;
+      let userParam = null();
+      gl.DebugMessageCallback(callback, userParam);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    60 => {
+      d.u64()?;
+      let callback = None;
+      let mut userParam_arg = d.pointer()?;
+      let _ = userParam_arg;
+      let userParam = null();
+      gl.DebugMessageCallbackKHR(callback, userParam);
+    }
+    #[cfg(feature = "gles32")]
+    61 => {
+      let source = d.u32()?;
+      let type_ = d.u32()?;
+      let severity = d.u32()?;
+      let count = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      let enabled = d.u8()?;
+      let ids = ids_arg.as_ptr::<GLuint>();
+      gl.DebugMessageControl(source, type_, severity, count, ids, enabled);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    62 => {
+      let source = d.u32()?;
+      let type_ = d.u32()?;
+      let severity = d.u32()?;
+      let count = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      let enabled = d.u8()?;
+      let ids = ids_arg.as_ptr::<GLuint>();
+      gl.DebugMessageControlKHR(source, type_, severity, count, ids, enabled);
+    }
+    #[cfg(feature = "gles32")]
+    63 => {
+      let source = d.u32()?;
+      let type_ = d.u32()?;
+      let id = d.u32()?;
+      let severity = d.u32()?;
+      let length = d.i32()?;
+      let mut buf_arg = d.pointer()?;
+      let buf = buf_arg.as_ptr::<GLchar>();
+      gl.DebugMessageInsert(source, type_, id, severity, length, buf);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    64 => {
+      let source = d.u32()?;
+      let type_ = d.u32()?;
+      let id = d.u32()?;
+      let severity = d.u32()?;
+      let length = d.i32()?;
+      let mut buf_arg = d.pointer()?;
+      let buf = buf_arg.as_ptr::<GLchar>();
+      gl.DebugMessageInsertKHR(source, type_, id, severity, length, buf);
+    }
+    65 => {
+      let n = d.i32()?;
+      let mut buffers_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::Buffer, &mut buffers_arg);
+      let buffers = buffers_arg.as_ptr::<GLuint>();
+      gl.DeleteBuffers(n, buffers);
+    }
+    66 => {
+      let n = d.i32()?;
+      let mut framebuffers_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::Framebuffer, &mut framebuffers_arg);
+      let framebuffers = framebuffers_arg.as_ptr::<GLuint>();
+      gl.DeleteFramebuffers(n, framebuffers);
+    }
+    67 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      gl.DeleteProgram(program);
+    }
+    #[cfg(feature = "gles31")]
+    68 => {
+      let n = d.i32()?;
+      let mut pipelines_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::ProgramPipeline, &mut pipelines_arg);
+      let pipelines = pipelines_arg.as_ptr::<GLuint>();
+      gl.DeleteProgramPipelines(n, pipelines);
+    }
+    69 => {
+      let n = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::Query, &mut ids_arg);
+      let ids = ids_arg.as_ptr::<GLuint>();
+      gl.DeleteQueries(n, ids);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    70 => {
+      let n = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::Query, &mut ids_arg);
+      let ids = ids_arg.as_ptr::<GLuint>();
+      gl.DeleteQueriesEXT(n, ids);
+    }
+    71 => {
+      let n = d.i32()?;
+      let mut renderbuffers_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::Renderbuffer, &mut renderbuffers_arg);
+      let renderbuffers = renderbuffers_arg.as_ptr::<GLuint>();
+      gl.DeleteRenderbuffers(n, renderbuffers);
+    }
+    72 => {
+      let count = d.i32()?;
+      let mut samplers_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::Sampler, &mut samplers_arg);
+      let samplers = samplers_arg.as_ptr::<GLuint>();
+      gl.DeleteSamplers(count, samplers);
+    }
+    73 => {
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      gl.DeleteShader(shader);
+    }
+    74 => {
+      let sync = st.sync(d.u64()?);
+      gl.DeleteSync(sync);
+    }
+    75 => {
+      let n = d.i32()?;
+      let mut textures_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::Texture, &mut textures_arg);
+      let textures = textures_arg.as_ptr::<GLuint>();
+      gl.DeleteTextures(n, textures);
+    }
+    76 => {
+      let n = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::TransformFeedback, &mut ids_arg);
+      let ids = ids_arg.as_ptr::<GLuint>();
+      gl.DeleteTransformFeedbacks(n, ids);
+    }
+    77 => {
+      let n = d.i32()?;
+      let mut arrays_arg = d.pointer()?;
+      st.names.remap_payload(NameKind::VertexArray, &mut arrays_arg);
+      let arrays = arrays_arg.as_ptr::<GLuint>();
+      gl.DeleteVertexArrays(n, arrays);
+    }
+    78 => {
+      let func = d.u32()?;
+      gl.DepthFunc(func);
+    }
+    79 => {
+      let flag = d.u8()?;
+      gl.DepthMask(flag);
+    }
+    80 => {
+      let n = d.f32()?;
+      let f = d.f32()?;
+      gl.DepthRangef(n, f);
+    }
+    81 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      gl.DetachShader(program, shader);
+    }
+    82 => {
+      let cap = d.u32()?;
+      gl.Disable(cap);
+    }
+    83 => {
+      let index = d.u32()?;
+      gl.DisableVertexAttribArray(index);
+    }
+    #[cfg(feature = "gles32")]
+    84 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      gl.Disablei(target, index);
+    }
+    #[cfg(feature = "gles31")]
+    85 => {
+      let num_groups_x = d.u32()?;
+      let num_groups_y = d.u32()?;
+      let num_groups_z = d.u32()?;
+      gl.DispatchCompute(num_groups_x, num_groups_y, num_groups_z);
+    }
+    #[cfg(feature = "gles31")]
+    86 => {
+      let indirect = d.i64()? as isize;
+      gl.DispatchComputeIndirect(indirect);
+    }
+    87 => {
+      let mode = d.u32()?;
+      let first = d.i32()?;
+      let count = d.i32()?;
+      gl.DrawArrays(mode, first, count);
+    }
+    #[cfg(feature = "gles31")]
+    88 => {
+      let mode = d.u32()?;
+      let mut indirect_arg = d.pointer()?;
+      let indirect = indirect_arg.as_ptr::<c_void>();
+      gl.DrawArraysIndirect(mode, indirect);
+    }
+    89 => {
+      let mode = d.u32()?;
+      let first = d.i32()?;
+      let count = d.i32()?;
+      let instancecount = d.i32()?;
+      gl.DrawArraysInstanced(mode, first, count, instancecount);
+    }
+    90 => {
+      let n = d.i32()?;
+      let mut bufs_arg = d.pointer()?;
+      let bufs = bufs_arg.as_ptr::<GLenum>();
+      gl.DrawBuffers(n, bufs);
+    }
+    91 => {
+      let mode = d.u32()?;
+      let count = d.i32()?;
+      let type_ = d.u32()?;
+      let mut indices_arg = d.pointer()?;
+      let indices = indices_arg.as_ptr::<c_void>();
+      gl.DrawElements(mode, count, type_, indices);
+    }
+    #[cfg(feature = "gles32")]
+    92 => {
+      let mode = d.u32()?;
+      let count = d.i32()?;
+      let type_ = d.u32()?;
+      let mut indices_arg = d.pointer()?;
+      let basevertex = d.i32()?;
+      let indices = indices_arg.as_ptr::<c_void>();
+      gl.DrawElementsBaseVertex(mode, count, type_, indices, basevertex);
+    }
+    #[cfg(feature = "gles31")]
+    93 => {
+      let mode = d.u32()?;
+      let type_ = d.u32()?;
+      let mut indirect_arg = d.pointer()?;
+      let indirect = indirect_arg.as_ptr::<c_void>();
+      gl.DrawElementsIndirect(mode, type_, indirect);
+    }
+    94 => {
+      let mode = d.u32()?;
+      let count = d.i32()?;
+      let type_ = d.u32()?;
+      let mut indices_arg = d.pointer()?;
+      let instancecount = d.i32()?;
+      let indices = indices_arg.as_ptr::<c_void>();
+      gl.DrawElementsInstanced(mode, count, type_, indices, instancecount);
+    }
+    #[cfg(feature = "gles32")]
+    95 => {
+      let mode = d.u32()?;
+      let count = d.i32()?;
+      let type_ = d.u32()?;
+      let mut indices_arg = d.pointer()?;
+      let instancecount = d.i32()?;
+      let basevertex = d.i32()?;
+      let indices = indices_arg.as_ptr::<c_void>();
+      gl.DrawElementsInstancedBaseVertex(
+        mode,
+        count,
+        type_,
+        indices,
+        instancecount,
+        basevertex,
+      );
+    }
+    96 => {
+      let mode = d.u32()?;
+      let start = d.u32()?;
+      let end = d.u32()?;
+      let count = d.i32()?;
+      let type_ = d.u32()?;
+      let mut indices_arg = d.pointer()?;
+      let indices = indices_arg.as_ptr::<c_void>();
+      gl.DrawRangeElements(mode, start, end, count, type_, indices);
+    }
+    #[cfg(feature = "gles32")]
+    97 => {
+      let mode = d.u32()?;
+      let start = d.u32()?;
+      let end = d.u32()?;
+      let count = d.i32()?;
+      let type_ = d.u32()?;
+      let mut indices_arg = d.pointer()?;
+      let basevertex = d.i32()?;
+      let indices = indices_arg.as_ptr::<c_void>();
+      gl.DrawRangeElementsBaseVertex(
+        mode, start, end, count, type_, indices, basevertex,
+      );
+    }
+    #[cfg(feature = "GL_OES_EGL_image")]
+    98 => {
+      let target = d.u32()?;
+      let image = d.u64()? as usize as GLeglImageOES;
+      gl.EGLImageTargetRenderbufferStorageOES(target, image);
+    }
+    #[cfg(feature = "GL_OES_EGL_image")]
+    99 => {
+      let target = d.u32()?;
+      let image = d.u64()? as usize as GLeglImageOES;
+      gl.EGLImageTargetTexture2DOES(target, image);
+    }
+    100 => {
+      let cap = d.u32()?;
+      gl.Enable(cap);
+    }
+    101 => {
+      let index = d.u32()?;
+      gl.EnableVertexAttribArray(index);
+    }
+    #[cfg(feature = "gles32")]
+    102 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      gl.Enablei(target, index);
+    }
+    103 => {
+      let target = d.u32()?;
+      gl.EndQuery(target);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    104 => {
+      let target = d.u32()?;
+      gl.EndQueryEXT(target);
+    }
+    105 => {
+      gl.EndTransformFeedback();
+    }
+    106 => {
+      let condition = d.u32()?;
+      let flags = d.u32()?;
+      let out = gl.FenceSync(condition, flags);
+      st.syncs.insert(d.u64()?, out);
+    }
+    107 => {
+      gl.Finish();
+    }
+    108 => {
+      gl.Flush();
+    }
+    109 => {
+      let target = d.u32()?;
+      let offset = d.i64()? as isize;
+      let length = d.i64()? as isize;
+      let mapped_data = d.pointer()?;
+      st.write_mapped(target, offset, &mapped_data);
+      gl.FlushMappedBufferRange(target, offset, length);
+    }
+    #[cfg(feature = "gles31")]
+    110 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let param = d.i32()?;
+      gl.FramebufferParameteri(target, pname, param);
+    }
+    111 => {
+      let target = d.u32()?;
+      let attachment = d.u32()?;
+      let renderbuffertarget = d.u32()?;
+      let renderbuffer = st.names.get(NameKind::Renderbuffer, d.u32()?);
+      gl.FramebufferRenderbuffer(
+        target,
+        attachment,
+        renderbuffertarget,
+        renderbuffer,
+      );
+    }
+    #[cfg(feature = "gles32")]
+    112 => {
+      let target = d.u32()?;
+      let attachment = d.u32()?;
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      let level = d.i32()?;
+      gl.FramebufferTexture(target, attachment, texture, level);
+    }
+    113 => {
+      let target = d.u32()?;
+      let attachment = d.u32()?;
+      let textarget = d.u32()?;
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      let level = d.i32()?;
+      gl.FramebufferTexture2D(target, attachment, textarget, texture, level);
+    }
+    #[cfg(feature = "GL_EXT_multisampled_render_to_texture")]
+    114 => {
+      let target = d.u32()?;
+      let attachment = d.u32()?;
+      let textarget = d.u32()?;
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      let level = d.i32()?;
+      let samples = d.i32()?;
+      gl.FramebufferTexture2DMultisampleEXT(
+        target, attachment, textarget, texture, level, samples,
+      );
+    }
+    115 => {
+      let target = d.u32()?;
+      let attachment = d.u32()?;
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      let level = d.i32()?;
+      let layer = d.i32()?;
+      gl.FramebufferTextureLayer(target, attachment, texture, level, layer);
+    }
+    #[cfg(feature = "GL_OVR_multiview")]
+    116 => {
+      let target = d.u32()?;
+      let attachment = d.u32()?;
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      let level = d.i32()?;
+      let baseViewIndex = d.i32()?;
+      let numViews = d.i32()?;
+      gl.FramebufferTextureMultiviewOVR(
+        target,
+        attachment,
+        texture,
+        level,
+        baseViewIndex,
+        numViews,
+      );
+    }
+    117 => {
+      let mode = d.u32()?;
+      gl.FrontFace(mode);
+    }
+    118 => {
+      let n = d.i32()?;
+      let mut buffers_arg = d.pointer()?;
+      let mut buffers_out =
+        Scratch::new(&buffers_arg, n.max(0) as usize * size_of::<GLuint>());
+      let buffers = buffers_out.as_mut_ptr::<GLuint>();
+      gl.GenBuffers(n, buffers);
+      st.names.insert_all(NameKind::Buffer, &buffers_arg, buffers, n);
+    }
+    119 => {
+      let n = d.i32()?;
+      let mut framebuffers_arg = d.pointer()?;
+      let mut framebuffers_out = Scratch::new(
+        &framebuffers_arg,
+        n.max(0) as usize * size_of::<GLuint>(),
+      );
+      let framebuffers = framebuffers_out.as_mut_ptr::<GLuint>();
+      gl.GenFramebuffers(n, framebuffers);
+      st.names.insert_all(
+        NameKind::Framebuffer,
+        &framebuffers_arg,
+        framebuffers,
+        n,
+      );
+    }
+    #[cfg(feature = "gles31")]
+    120 => {
+      let n = d.i32()?;
+      let mut pipelines_arg = d.pointer()?;
+      let mut pipelines_out =
+        Scratch::new(&pipelines_arg, n.max(0) as usize * size_of::<GLuint>());
+      let pipelines = pipelines_out.as_mut_ptr::<GLuint>();
+      gl.GenProgramPipelines(n, pipelines);
+      st.names.insert_all(
+        NameKind::ProgramPipeline,
+        &pipelines_arg,
+        pipelines,
+        n,
+      );
+    }
+    121 => {
+      let n = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      let mut ids_out =
+        Scratch::new(&ids_arg, n.max(0) as usize * size_of::<GLuint>());
+      let ids = ids_out.as_mut_ptr::<GLuint>();
+      gl.GenQueries(n, ids);
+      st.names.insert_all(NameKind::Query, &ids_arg, ids, n);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    122 => {
+      let n = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      let mut ids_out =
+        Scratch::new(&ids_arg, n.max(0) as usize * size_of::<GLuint>());
+      let ids = ids_out.as_mut_ptr::<GLuint>();
+      gl.GenQueriesEXT(n, ids);
+      st.names.insert_all(NameKind::Query, &ids_arg, ids, n);
+    }
+    123 => {
+      let n = d.i32()?;
+      let mut renderbuffers_arg = d.pointer()?;
+      let mut renderbuffers_out = Scratch::new(
+        &renderbuffers_arg,
+        n.max(0) as usize * size_of::<GLuint>(),
+      );
+      let renderbuffers = renderbuffers_out.as_mut_ptr::<GLuint>();
+      gl.GenRenderbuffers(n, renderbuffers);
+      st.names.insert_all(
+        NameKind::Renderbuffer,
+        &renderbuffers_arg,
+        renderbuffers,
+        n,
+      );
+    }
+    124 => {
+      let count = d.i32()?;
+      let mut samplers_arg = d.pointer()?;
+      let mut samplers_out = Scratch::new(
+        &samplers_arg,
+        count.max(0) as usize * size_of::<GLuint>(),
+      );
+      let samplers = samplers_out.as_mut_ptr::<GLuint>();
+      gl.GenSamplers(count, samplers);
+      st.names.insert_all(NameKind::Sampler, &samplers_arg, samplers, count);
+    }
+    125 => {
+      let n = d.i32()?;
+      let mut textures_arg = d.pointer()?;
+      let mut textures_out =
+        Scratch::new(&textures_arg, n.max(0) as usize * size_of::<GLuint>());
+      let textures = textures_out.as_mut_ptr::<GLuint>();
+      gl.GenTextures(n, textures);
+      st.names.insert_all(NameKind::Texture, &textures_arg, textures, n);
+    }
+    126 => {
+      let n = d.i32()?;
+      let mut ids_arg = d.pointer()?;
+      let mut ids_out =
+        Scratch::new(&ids_arg, n.max(0) as usize * size_of::<GLuint>());
+      let ids = ids_out.as_mut_ptr::<GLuint>();
+      gl.GenTransformFeedbacks(n, ids);
+      st.names.insert_all(NameKind::TransformFeedback, &ids_arg, ids, n);
+    }
+    127 => {
+      let n = d.i32()?;
+      let mut arrays_arg = d.pointer()?;
+      let mut arrays_out =
+        Scratch::new(&arrays_arg, n.max(0) as usize * size_of::<GLuint>());
+      let arrays = arrays_out.as_mut_ptr::<GLuint>();
+      gl.GenVertexArrays(n, arrays);
+      st.names.insert_all(NameKind::VertexArray, &arrays_arg, arrays, n);
+    }
+    128 => {
+      let target = d.u32()?;
+      gl.GenerateMipmap(target);
+    }
+    129 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let index = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut size_arg = d.pointer()?;
+      let mut type__arg = d.pointer()?;
+      let mut name_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut size_out = Scratch::new(&size_arg, OUT_SCRATCH_BYTES);
+      let size = size_out.as_mut_ptr::<GLint>();
+      let mut type__out = Scratch::new(&type__arg, OUT_SCRATCH_BYTES);
+      let type_ = type__out.as_mut_ptr::<GLenum>();
+      let mut name_out =
+        Scratch::new(&name_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let name = name_out.as_mut_ptr::<GLchar>();
+      gl.GetActiveAttrib(program, index, bufSize, length, size, type_, name);
+    }
+    130 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let index = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut size_arg = d.pointer()?;
+      let mut type__arg = d.pointer()?;
+      let mut name_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut size_out = Scratch::new(&size_arg, OUT_SCRATCH_BYTES);
+      let size = size_out.as_mut_ptr::<GLint>();
+      let mut type__out = Scratch::new(&type__arg, OUT_SCRATCH_BYTES);
+      let type_ = type__out.as_mut_ptr::<GLenum>();
+      let mut name_out =
+        Scratch::new(&name_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let name = name_out.as_mut_ptr::<GLchar>();
+      gl.GetActiveUniform(program, index, bufSize, length, size, type_, name);
+    }
+    131 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let uniformBlockIndex = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut uniformBlockName_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut uniformBlockName_out = Scratch::new(
+        &uniformBlockName_arg,
+        bufSize.max(0) as usize * size_of::<GLchar>(),
+      );
+      let uniformBlockName = uniformBlockName_out.as_mut_ptr::<GLchar>();
+      gl.GetActiveUniformBlockName(
+        program,
+        uniformBlockIndex,
+        bufSize,
+        length,
+        uniformBlockName,
+      );
+    }
+    132 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let uniformBlockIndex = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetActiveUniformBlockiv(program, uniformBlockIndex, pname, params);
+    }
+    133 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let uniformCount = d.i32()?;
+      let mut uniformIndices_arg = d.pointer()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let uniformIndices = uniformIndices_arg.as_ptr::<GLuint>();
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetActiveUniformsiv(
+        program,
+        uniformCount,
+        uniformIndices,
+        pname,
+        params,
+      );
+    }
+    134 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let maxCount = d.i32()?;
+      let mut count_arg = d.pointer()?;
+      let mut shaders_arg = d.pointer()?;
+      let mut count_out = Scratch::new(&count_arg, OUT_SCRATCH_BYTES);
+      let count = count_out.as_mut_ptr::<GLsizei>();
+      let mut shaders_out = Scratch::new(
+        &shaders_arg,
+        maxCount.max(0) as usize * size_of::<GLuint>(),
+      );
+      let shaders = shaders_out.as_mut_ptr::<GLuint>();
+      gl.GetAttachedShaders(program, maxCount, count, shaders);
+    }
+    135 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let mut name_arg = d.pointer()?;
+      let name = name_arg.as_ptr::<GLchar>();
+      let out = gl.GetAttribLocation(program, name);
+      d.i32()?;
+      let _ = out;
+    }
+    #[cfg(feature = "gles31")]
+    136 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out = Scratch::new(&data_arg, OUT_SCRATCH_BYTES);
+      let data = data_out.as_mut_ptr::<GLboolean>();
+      gl.GetBooleani_v(target, index, data);
+    }
+    137 => {
+      let pname = d.u32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out = Scratch::new(&data_arg, OUT_SCRATCH_BYTES);
+      let data = data_out.as_mut_ptr::<GLboolean>();
+      gl.GetBooleanv(pname, data);
+    }
+    138 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint64>();
+      gl.GetBufferParameteri64v(target, pname, params);
+    }
+    139 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetBufferParameteriv(target, pname, params);
+    }
+    140 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<*mut c_void>();
+      gl.GetBufferPointerv(target, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    141 => {
+      let count = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut sources_arg = d.pointer()?;
+      let mut types_arg = d.pointer()?;
+      let mut ids_arg = d.pointer()?;
+      let mut severities_arg = d.pointer()?;
+      let mut lengths_arg = d.pointer()?;
+      let mut messageLog_arg = d.pointer()?;
+      let mut sources_out =
+        Scratch::new(&sources_arg, count as usize * size_of::<GLenum>());
+      let sources = sources_out.as_mut_ptr::<GLenum>();
+      let mut types_out =
+        Scratch::new(&types_arg, count as usize * size_of::<GLenum>());
+      let types = types_out.as_mut_ptr::<GLenum>();
+      let mut ids_out =
+        Scratch::new(&ids_arg, count as usize * size_of::<GLuint>());
+      let ids = ids_out.as_mut_ptr::<GLuint>();
+      let mut severities_out =
+        Scratch::new(&severities_arg, count as usize * size_of::<GLenum>());
+      let severities = severities_out.as_mut_ptr::<GLenum>();
+      let mut lengths_out =
+        Scratch::new(&lengths_arg, count as usize * size_of::<GLsizei>());
+      let lengths = lengths_out.as_mut_ptr::<GLsizei>();
+      let mut messageLog_out = Scratch::new(
+        &messageLog_arg,
+        bufSize.max(0) as usize * size_of::<GLchar>(),
+      );
+      let messageLog = messageLog_out.as_mut_ptr::<GLchar>();
+      let out = gl.GetDebugMessageLog(
+        count, bufSize, sources, types, ids, severities, lengths, messageLog,
+      );
+      d.u32()?;
+      let _ = out;
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    142 => {
+      let count = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut sources_arg = d.pointer()?;
+      let mut types_arg = d.pointer()?;
+      let mut ids_arg = d.pointer()?;
+      let mut severities_arg = d.pointer()?;
+      let mut lengths_arg = d.pointer()?;
+      let mut messageLog_arg = d.pointer()?;
+      let mut sources_out =
+        Scratch::new(&sources_arg, count as usize * size_of::<GLenum>());
+      let sources = sources_out.as_mut_ptr::<GLenum>();
+      let mut types_out =
+        Scratch::new(&types_arg, count as usize * size_of::<GLenum>());
+      let types = types_out.as_mut_ptr::<GLenum>();
+      let mut ids_out =
+        Scratch::new(&ids_arg, count as usize * size_of::<GLuint>());
+      let ids = ids_out.as_mut_ptr::<GLuint>();
+      let mut severities_out =
+        Scratch::new(&severities_arg, count as usize * size_of::<GLenum>());
+      let severities = severities_out.as_mut_ptr::<GLenum>();
+      let mut lengths_out =
+        Scratch::new(&lengths_arg, count as usize * size_of::<GLsizei>());
+      let lengths = lengths_out.as_mut_ptr::<GLsizei>();
+      let mut messageLog_out = Scratch::new(
+        &messageLog_arg,
+        bufSize.max(0) as usize * size_of::<GLchar>(),
+      );
+      let messageLog = messageLog_out.as_mut_ptr::<GLchar>();
+      let out = gl.GetDebugMessageLogKHR(
+        count, bufSize, sources, types, ids, severities, lengths, messageLog,
+      );
+      d.u32()?;
+      let _ = out;
+    }
+    143 => {
+      let out = gl.GetError();
+      d.u32()?;
+      let _ = out;
+    }
+    144 => {
+      let pname = d.u32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out = Scratch::new(&data_arg, OUT_SCRATCH_BYTES);
+      let data = data_out.as_mut_ptr::<GLfloat>();
+      gl.GetFloatv(pname, data);
+    }
+    145 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let mut name_arg = d.pointer()?;
+      let name = name_arg.as_ptr::<GLchar>();
+      let out = gl.GetFragDataLocation(program, name);
+      d.i32()?;
+      let _ = out;
+    }
+    146 => {
+      let target = d.u32()?;
+      let attachment = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetFramebufferAttachmentParameteriv(target, attachment, pname, params);
+    }
+    #[cfg(feature = "gles31")]
+    147 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetFramebufferParameteriv(target, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    148 => {
+      let out = gl.GetGraphicsResetStatus();
+      d.u32()?;
+      let _ = out;
+    }
+    149 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out = Scratch::new(&data_arg, OUT_SCRATCH_BYTES);
+      let data = data_out.as_mut_ptr::<GLint64>();
+      gl.GetInteger64i_v(target, index, data);
+    }
+    150 => {
+      let pname = d.u32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out = Scratch::new(&data_arg, OUT_SCRATCH_BYTES);
+      let data = data_out.as_mut_ptr::<GLint64>();
+      gl.GetInteger64v(pname, data);
+    }
+    151 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out = Scratch::new(&data_arg, OUT_SCRATCH_BYTES);
+      let data = data_out.as_mut_ptr::<GLint>();
+      gl.GetIntegeri_v(target, index, data);
+    }
+    152 => {
+      let pname = d.u32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out = Scratch::new(&data_arg, OUT_SCRATCH_BYTES);
+      let data = data_out.as_mut_ptr::<GLint>();
+      gl.GetIntegerv(pname, data);
+    }
+    153 => {
+      let target = d.u32()?;
+      let internalformat = d.u32()?;
+      let pname = d.u32()?;
+      let count = d.i32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out =
+        Scratch::new(&params_arg, count.max(0) as usize * size_of::<GLint>());
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetInternalformativ(target, internalformat, pname, count, params);
+    }
+    #[cfg(feature = "gles31")]
+    154 => {
+      let pname = d.u32()?;
+      let index = d.u32()?;
+      let mut val_arg = d.pointer()?;
+      let mut val_out = Scratch::new(&val_arg, OUT_SCRATCH_BYTES);
+      let val = val_out.as_mut_ptr::<GLfloat>();
+      gl.GetMultisamplefv(pname, index, val);
+    }
+    #[cfg(feature = "gles32")]
+    155 => {
+      let identifier = d.u32()?;
+      let name = st.names.get_for_identifier(identifier, d.u32()?);
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut label_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut label_out =
+        Scratch::new(&label_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let label = label_out.as_mut_ptr::<GLchar>();
+      gl.GetObjectLabel(identifier, name, bufSize, length, label);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    156 => {
+      let identifier = d.u32()?;
+      let name = st.names.get_for_identifier(identifier, d.u32()?);
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut label_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut label_out =
+        Scratch::new(&label_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let label = label_out.as_mut_ptr::<GLchar>();
+      gl.GetObjectLabelKHR(identifier, name, bufSize, length, label);
+    }
+    #[cfg(feature = "gles32")]
+    157 => {
+      let mut ptr_arg = d.pointer()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut label_arg = d.pointer()?;
+      let ptr = st.sync(ptr_arg.address()) as *const c_void;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut label_out =
+        Scratch::new(&label_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let label = label_out.as_mut_ptr::<GLchar>();
+      gl.GetObjectPtrLabel(ptr, bufSize, length, label);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    158 => {
+      let mut ptr_arg = d.pointer()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut label_arg = d.pointer()?;
+      let ptr = st.sync(ptr_arg.address()) as *const c_void;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut label_out =
+        Scratch::new(&label_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let label = label_out.as_mut_ptr::<GLchar>();
+      gl.GetObjectPtrLabelKHR(ptr, bufSize, length, label);
+    }
+    #[cfg(feature = "gles32")]
+    159 => {
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<*mut c_void>();
+      gl.GetPointerv(pname, params);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    160 => {
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<*mut c_void>();
+      gl.GetPointervKHR(pname, params);
+    }
+    161 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut binaryFormat_arg = d.pointer()?;
+      let mut binary_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut binaryFormat_out =
+        Scratch::new(&binaryFormat_arg, OUT_SCRATCH_BYTES);
+      let binaryFormat = binaryFormat_out.as_mut_ptr::<GLenum>();
+      let mut binary_out = Scratch::new(
+        &binary_arg,
+        bufSize.max(0) as usize * size_of::<c_void>(),
+      );
+      let binary = binary_out.as_mut_ptr::<c_void>();
+      gl.GetProgramBinary(program, bufSize, length, binaryFormat, binary);
+    }
+    162 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut infoLog_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut infoLog_out = Scratch::new(
+        &infoLog_arg,
+        bufSize.max(0) as usize * size_of::<GLchar>(),
+      );
+      let infoLog = infoLog_out.as_mut_ptr::<GLchar>();
+      gl.GetProgramInfoLog(program, bufSize, length, infoLog);
+    }
+    #[cfg(feature = "gles31")]
+    163 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let programInterface = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetProgramInterfaceiv(program, programInterface, pname, params);
+    }
+    #[cfg(feature = "gles31")]
+    164 => {
+      let pipeline = st.names.get(NameKind::ProgramPipeline, d.u32()?);
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut infoLog_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut infoLog_out = Scratch::new(
+        &infoLog_arg,
+        bufSize.max(0) as usize * size_of::<GLchar>(),
+      );
+      let infoLog = infoLog_out.as_mut_ptr::<GLchar>();
+      gl.GetProgramPipelineInfoLog(pipeline, bufSize, length, infoLog);
+    }
+    #[cfg(feature = "gles31")]
+    165 => {
+      let pipeline = st.names.get(NameKind::ProgramPipeline, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetProgramPipelineiv(pipeline, pname, params);
+    }
+    #[cfg(feature = "gles31")]
+    166 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let programInterface = d.u32()?;
+      let mut name_arg = d.pointer()?;
+      let name = name_arg.as_ptr::<GLchar>();
+      let out = gl.GetProgramResourceIndex(program, programInterface, name);
+      d.u32()?;
+      let _ = out;
+    }
+    #[cfg(feature = "gles31")]
+    167 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let programInterface = d.u32()?;
+      let mut name_arg = d.pointer()?;
+      let name = name_arg.as_ptr::<GLchar>();
+      let out = gl.GetProgramResourceLocation(program, programInterface, name);
+      d.i32()?;
+      let _ = out;
+    }
+    #[cfg(feature = "gles31")]
+    168 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let programInterface = d.u32()?;
+      let index = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut name_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut name_out =
+        Scratch::new(&name_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let name = name_out.as_mut_ptr::<GLchar>();
+      gl.GetProgramResourceName(
+        program,
+        programInterface,
+        index,
+        bufSize,
+        length,
+        name,
+      );
+    }
+    #[cfg(feature = "gles31")]
+    169 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let programInterface = d.u32()?;
+      let index = d.u32()?;
+      let propCount = d.i32()?;
+      let mut props_arg = d.pointer()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut params_arg = d.pointer()?;
+      let props = props_arg.as_ptr::<GLenum>();
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut params_out =
+        Scratch::new(&params_arg, bufSize.max(0) as usize * size_of::<GLint>());
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetProgramResourceiv(
+        program,
+        programInterface,
+        index,
+        propCount,
+        props,
+        bufSize,
+        length,
+        params,
+      );
+    }
+    170 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetProgramiv(program, pname, params);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    171 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint64>();
+      gl.GetQueryObjecti64vEXT(id, pname, params);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    172 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetQueryObjectivEXT(id, pname, params);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    173 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLuint64>();
+      gl.GetQueryObjectui64vEXT(id, pname, params);
+    }
+    174 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLuint>();
+      gl.GetQueryObjectuiv(id, pname, params);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    175 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLuint>();
+      gl.GetQueryObjectuivEXT(id, pname, params);
+    }
+    176 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetQueryiv(target, pname, params);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    177 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetQueryivEXT(target, pname, params);
+    }
+    178 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetRenderbufferParameteriv(target, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    179 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetSamplerParameterIiv(sampler, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    180 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLuint>();
+      gl.GetSamplerParameterIuiv(sampler, pname, params);
+    }
+    181 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLfloat>();
+      gl.GetSamplerParameterfv(sampler, pname, params);
+    }
+    182 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetSamplerParameteriv(sampler, pname, params);
+    }
+    183 => {
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut infoLog_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut infoLog_out = Scratch::new(
+        &infoLog_arg,
+        bufSize.max(0) as usize * size_of::<GLchar>(),
+      );
+      let infoLog = infoLog_out.as_mut_ptr::<GLchar>();
+      gl.GetShaderInfoLog(shader, bufSize, length, infoLog);
+    }
+    184 => {
+      let shadertype = d.u32()?;
+      let precisiontype = d.u32()?;
+      let mut range_arg = d.pointer()?;
+      let mut precision_arg = d.pointer()?;
+      let mut range_out = Scratch::new(&range_arg, OUT_SCRATCH_BYTES);
+      let range = range_out.as_mut_ptr::<GLint>();
+      let mut precision_out = Scratch::new(&precision_arg, OUT_SCRATCH_BYTES);
+      let precision = precision_out.as_mut_ptr::<GLint>();
+      gl.GetShaderPrecisionFormat(shadertype, precisiontype, range, precision);
+    }
+    185 => {
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut source_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut source_out = Scratch::new(
+        &source_arg,
+        bufSize.max(0) as usize * size_of::<GLchar>(),
+      );
+      let source = source_out.as_mut_ptr::<GLchar>();
+      gl.GetShaderSource(shader, bufSize, length, source);
+    }
+    186 => {
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetShaderiv(shader, pname, params);
+    }
+    187 => {
+      let name = d.u32()?;
+      let out = gl.GetString(name);
+      d.u64()?;
+      let _ = out;
+    }
+    188 => {
+      let name = d.u32()?;
+      let index = d.u32()?;
+      let out = gl.GetStringi(name, index);
+      d.u64()?;
+      let _ = out;
+    }
+    189 => {
+      let sync = st.sync(d.u64()?);
+      let pname = d.u32()?;
+      let count = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut values_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut values_out =
+        Scratch::new(&values_arg, count.max(0) as usize * size_of::<GLint>());
+      let values = values_out.as_mut_ptr::<GLint>();
+      gl.GetSynciv(sync, pname, count, length, values);
+    }
+    #[cfg(feature = "gles31")]
+    190 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLfloat>();
+      gl.GetTexLevelParameterfv(target, level, pname, params);
+    }
+    #[cfg(feature = "gles31")]
+    191 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetTexLevelParameteriv(target, level, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    192 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetTexParameterIiv(target, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    193 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLuint>();
+      gl.GetTexParameterIuiv(target, pname, params);
+    }
+    194 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLfloat>();
+      gl.GetTexParameterfv(target, pname, params);
+    }
+    195 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetTexParameteriv(target, pname, params);
+    }
+    196 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let index = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut length_arg = d.pointer()?;
+      let mut size_arg = d.pointer()?;
+      let mut type__arg = d.pointer()?;
+      let mut name_arg = d.pointer()?;
+      let mut length_out = Scratch::new(&length_arg, OUT_SCRATCH_BYTES);
+      let length = length_out.as_mut_ptr::<GLsizei>();
+      let mut size_out = Scratch::new(&size_arg, OUT_SCRATCH_BYTES);
+      let size = size_out.as_mut_ptr::<GLsizei>();
+      let mut type__out = Scratch::new(&type__arg, OUT_SCRATCH_BYTES);
+      let type_ = type__out.as_mut_ptr::<GLenum>();
+      let mut name_out =
+        Scratch::new(&name_arg, bufSize.max(0) as usize * size_of::<GLchar>());
+      let name = name_out.as_mut_ptr::<GLchar>();
+      gl.GetTransformFeedbackVarying(
+        program, index, bufSize, length, size, type_, name,
+      );
+    }
+    197 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let mut uniformBlockName_arg = d.pointer()?;
+      let uniformBlockName = uniformBlockName_arg.as_ptr::<GLchar>();
+      let out = gl.GetUniformBlockIndex(program, uniformBlockName);
+      d.u32()?;
+      let _ = out;
+    }
+    198 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let uniformCount = d.i32()?;
+      let mut uniformNames_arg = d.pointer()?;
+      let mut uniformIndices_arg = d.pointer()?;
+      let uniformNames_strings = uniformNames_arg.strings();
+      let uniformNames = uniformNames_strings.as_ptr();
+      let mut uniformIndices_out =
+        Scratch::new(&uniformIndices_arg, OUT_SCRATCH_BYTES);
+      let uniformIndices = uniformIndices_out.as_mut_ptr::<GLuint>();
+      gl.GetUniformIndices(program, uniformCount, uniformNames, uniformIndices);
+    }
+    199 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let mut name_arg = d.pointer()?;
+      let name = name_arg.as_ptr::<GLchar>();
+      let out = gl.GetUniformLocation(program, name);
+      d.i32()?;
+      let _ = out;
+    }
+    200 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLfloat>();
+      gl.GetUniformfv(program, location, params);
+    }
+    201 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetUniformiv(program, location, params);
+    }
+    202 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLuint>();
+      gl.GetUniformuiv(program, location, params);
+    }
+    203 => {
+      let index = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetVertexAttribIiv(index, pname, params);
+    }
+    204 => {
+      let index = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLuint>();
+      gl.GetVertexAttribIuiv(index, pname, params);
+    }
+    205 => {
+      let index = d.u32()?;
+      let pname = d.u32()?;
+      let mut pointer_arg = d.pointer()?;
+      let mut pointer_out = Scratch::new(&pointer_arg, OUT_SCRATCH_BYTES);
+      let pointer = pointer_out.as_mut_ptr::<*mut c_void>();
+      gl.GetVertexAttribPointerv(index, pname, pointer);
+    }
+    206 => {
+      let index = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLfloat>();
+      gl.GetVertexAttribfv(index, pname, params);
+    }
+    207 => {
+      let index = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(&params_arg, OUT_SCRATCH_BYTES);
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetVertexAttribiv(index, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    208 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let bufSize = d.i32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(
+        &params_arg,
+        bufSize.max(0) as usize * size_of::<GLfloat>(),
+      );
+      let params = params_out.as_mut_ptr::<GLfloat>();
+      gl.GetnUniformfv(program, location, bufSize, params);
+    }
+    #[cfg(feature = "gles32")]
+    209 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let bufSize = d.i32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out =
+        Scratch::new(&params_arg, bufSize.max(0) as usize * size_of::<GLint>());
+      let params = params_out.as_mut_ptr::<GLint>();
+      gl.GetnUniformiv(program, location, bufSize, params);
+    }
+    #[cfg(feature = "gles32")]
+    210 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let bufSize = d.i32()?;
+      let mut params_arg = d.pointer()?;
+      let mut params_out = Scratch::new(
+        &params_arg,
+        bufSize.max(0) as usize * size_of::<GLuint>(),
+      );
+      let params = params_out.as_mut_ptr::<GLuint>();
+      gl.GetnUniformuiv(program, location, bufSize, params);
+    }
+    211 => {
+      let target = d.u32()?;
+      let mode = d.u32()?;
+      gl.Hint(target, mode);
+    }
+    212 => {
+      let target = d.u32()?;
+      let numAttachments = d.i32()?;
+      let mut attachments_arg = d.pointer()?;
+      let attachments = attachments_arg.as_ptr::<GLenum>();
+      gl.InvalidateFramebuffer(target, numAttachments, attachments);
+    }
+    213 => {
+      let target = d.u32()?;
+      let numAttachments = d.i32()?;
+      let mut attachments_arg = d.pointer()?;
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let attachments = attachments_arg.as_ptr::<GLenum>();
+      gl.InvalidateSubFramebuffer(
+        target,
+        numAttachments,
+        attachments,
+        x,
+        y,
+        width,
+        height,
+      );
+    }
+    214 => {
+      let buffer = st.names.get(NameKind::Buffer, d.u32()?);
+      let out = gl.IsBuffer(buffer);
+      d.u8()?;
+      let _ = out;
+    }
+    215 => {
+      let cap = d.u32()?;
+      let out = gl.IsEnabled(cap);
+      d.u8()?;
+      let _ = out;
+    }
+    #[cfg(feature = "gles32")]
+    216 => {
+      let target = d.u32()?;
+      let index = d.u32()?;
+      let out = gl.IsEnabledi(target, index);
+      d.u8()?;
+      let _ = out;
+    }
+    217 => {
+      let framebuffer = st.names.get(NameKind::Framebuffer, d.u32()?);
+      let out = gl.IsFramebuffer(framebuffer);
+      d.u8()?;
+      let _ = out;
+    }
+    218 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let out = gl.IsProgram(program);
+      d.u8()?;
+      let _ = out;
+    }
+    #[cfg(feature = "gles31")]
+    219 => {
+      let pipeline = st.names.get(NameKind::ProgramPipeline, d.u32()?);
+      let out = gl.IsProgramPipeline(pipeline);
+      d.u8()?;
+      let _ = out;
+    }
+    220 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let out = gl.IsQuery(id);
+      d.u8()?;
+      let _ = out;
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    221 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let out = gl.IsQueryEXT(id);
+      d.u8()?;
+      let _ = out;
+    }
+    222 => {
+      let renderbuffer = st.names.get(NameKind::Renderbuffer, d.u32()?);
+      let out = gl.IsRenderbuffer(renderbuffer);
+      d.u8()?;
+      let _ = out;
+    }
+    223 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let out = gl.IsSampler(sampler);
+      d.u8()?;
+      let _ = out;
+    }
+    224 => {
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      let out = gl.IsShader(shader);
+      d.u8()?;
+      let _ = out;
+    }
+    225 => {
+      let sync = st.sync(d.u64()?);
+      let out = gl.IsSync(sync);
+      d.u8()?;
+      let _ = out;
+    }
+    226 => {
+      let texture = st.names.get(NameKind::Texture, d.u32()?);
+      let out = gl.IsTexture(texture);
+      d.u8()?;
+      let _ = out;
+    }
+    227 => {
+      let id = st.names.get(NameKind::TransformFeedback, d.u32()?);
+      let out = gl.IsTransformFeedback(id);
+      d.u8()?;
+      let _ = out;
+    }
+    228 => {
+      let array = st.names.get(NameKind::VertexArray, d.u32()?);
+      let out = gl.IsVertexArray(array);
+      d.u8()?;
+      let _ = out;
+    }
+    229 => {
+      let width = d.f32()?;
+      gl.LineWidth(width);
+    }
+    230 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      gl.LinkProgram(program);
+    }
+    231 => {
+      let target = d.u32()?;
+      let offset = d.i64()? as isize;
+      let length = d.i64()? as isize;
+      let access = d.u32()?;
+      let out = gl.MapBufferRange(target, offset, length, access);
+      d.u64()?;
+      st.mapped.insert(target, out);
+    }
+    #[cfg(feature = "gles31")]
+    232 => {
+      let barriers = d.u32()?;
+      gl.MemoryBarrier(barriers);
+    }
+    #[cfg(feature = "gles31")]
+    233 => {
+      let barriers = d.u32()?;
+      gl.MemoryBarrierByRegion(barriers);
+    }
+    #[cfg(feature = "gles32")]
+    234 => {
+      let value = d.f32()?;
+      gl.MinSampleShading(value);
+    }
+    #[cfg(feature = "gles32")]
+    235 => {
+      let identifier = d.u32()?;
+      let name = st.names.get_for_identifier(identifier, d.u32()?);
+      let length = d.i32()?;
+      let mut label_arg = d.pointer()?;
+      let label = label_arg.as_ptr::<GLchar>();
+      gl.ObjectLabel(identifier, name, length, label);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    236 => {
+      let identifier = d.u32()?;
+      let name = st.names.get_for_identifier(identifier, d.u32()?);
+      let length = d.i32()?;
+      let mut label_arg = d.pointer()?;
+      let label = label_arg.as_ptr::<GLchar>();
+      gl.ObjectLabelKHR(identifier, name, length, label);
+    }
+    #[cfg(feature = "gles32")]
+    237 => {
+      let mut ptr_arg = d.pointer()?;
+      let length = d.i32()?;
+      let mut label_arg = d.pointer()?;
+      let ptr = st.sync(ptr_arg.address()) as *const c_void;
+      let label = label_arg.as_ptr::<GLchar>();
+      gl.ObjectPtrLabel(ptr, length, label);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    238 => {
+      let mut ptr_arg = d.pointer()?;
+      let length = d.i32()?;
+      let mut label_arg = d.pointer()?;
+      let ptr = st.sync(ptr_arg.address()) as *const c_void;
+      let label = label_arg.as_ptr::<GLchar>();
+      gl.ObjectPtrLabelKHR(ptr, length, label);
+    }
+    #[cfg(feature = "gles32")]
+    239 => {
+      let pname = d.u32()?;
+      let value = d.i32()?;
+      gl.PatchParameteri(pname, value);
+    }
+    240 => {
+      gl.PauseTransformFeedback();
+    }
+    241 => {
+      let pname = d.u32()?;
+      let param = d.i32()?;
+      gl.PixelStorei(pname, param);
+    }
+    242 => {
+      let factor = d.f32()?;
+      let units = d.f32()?;
+      gl.PolygonOffset(factor, units);
+    }
+    #[cfg(feature = "gles32")]
+    243 => {
+      gl.PopDebugGroup();
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    244 => {
+      gl.PopDebugGroupKHR();
+    }
+    #[cfg(feature = "gles32")]
+    245 => {
+      let minX = d.f32()?;
+      let minY = d.f32()?;
+      let minZ = d.f32()?;
+      let minW = d.f32()?;
+      let maxX = d.f32()?;
+      let maxY = d.f32()?;
+      let maxZ = d.f32()?;
+      let maxW = d.f32()?;
+      gl.PrimitiveBoundingBox(minX, minY, minZ, minW, maxX, maxY, maxZ, maxW);
+    }
+    246 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let binaryFormat = d.u32()?;
+      let mut binary_arg = d.pointer()?;
+      let length = d.i32()?;
+      let binary = binary_arg.as_ptr::<c_void>();
+      gl.ProgramBinary(program, binaryFormat, binary, length);
+    }
+    247 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let pname = d.u32()?;
+      let value = d.i32()?;
+      gl.ProgramParameteri(program, pname, value);
+    }
+    #[cfg(feature = "gles31")]
+    248 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      gl.ProgramUniform1f(program, location, v0);
+    }
+    #[cfg(feature = "gles31")]
+    249 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniform1fv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    250 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      gl.ProgramUniform1i(program, location, v0);
+    }
+    #[cfg(feature = "gles31")]
+    251 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.ProgramUniform1iv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    252 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      gl.ProgramUniform1ui(program, location, v0);
+    }
+    #[cfg(feature = "gles31")]
+    253 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.ProgramUniform1uiv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    254 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      let v1 = d.f32()?;
+      gl.ProgramUniform2f(program, location, v0, v1);
+    }
+    #[cfg(feature = "gles31")]
+    255 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniform2fv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    256 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      let v1 = d.i32()?;
+      gl.ProgramUniform2i(program, location, v0, v1);
+    }
+    #[cfg(feature = "gles31")]
+    257 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.ProgramUniform2iv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    258 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      let v1 = d.u32()?;
+      gl.ProgramUniform2ui(program, location, v0, v1);
+    }
+    #[cfg(feature = "gles31")]
+    259 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.ProgramUniform2uiv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    260 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      let v1 = d.f32()?;
+      let v2 = d.f32()?;
+      gl.ProgramUniform3f(program, location, v0, v1, v2);
+    }
+    #[cfg(feature = "gles31")]
+    261 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniform3fv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    262 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      let v1 = d.i32()?;
+      let v2 = d.i32()?;
+      gl.ProgramUniform3i(program, location, v0, v1, v2);
+    }
+    #[cfg(feature = "gles31")]
+    263 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.ProgramUniform3iv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    264 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      let v1 = d.u32()?;
+      let v2 = d.u32()?;
+      gl.ProgramUniform3ui(program, location, v0, v1, v2);
+    }
+    #[cfg(feature = "gles31")]
+    265 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.ProgramUniform3uiv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    266 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      let v1 = d.f32()?;
+      let v2 = d.f32()?;
+      let v3 = d.f32()?;
+      gl.ProgramUniform4f(program, location, v0, v1, v2, v3);
+    }
+    #[cfg(feature = "gles31")]
+    267 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniform4fv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    268 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      let v1 = d.i32()?;
+      let v2 = d.i32()?;
+      let v3 = d.i32()?;
+      gl.ProgramUniform4i(program, location, v0, v1, v2, v3);
+    }
+    #[cfg(feature = "gles31")]
+    269 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.ProgramUniform4iv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    270 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      let v1 = d.u32()?;
+      let v2 = d.u32()?;
+      let v3 = d.u32()?;
+      gl.ProgramUniform4ui(program, location, v0, v1, v2, v3);
+    }
+    #[cfg(feature = "gles31")]
+    271 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.ProgramUniform4uiv(program, location, count, value);
+    }
+    #[cfg(feature = "gles31")]
+    272 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix2fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    273 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix2x3fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    274 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix2x4fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    275 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix3fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    276 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix3x2fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    277 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix3x4fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    278 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix4fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    279 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix4x2fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles31")]
+    280 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.ProgramUniformMatrix4x3fv(program, location, count, transpose, value);
+    }
+    #[cfg(feature = "gles32")]
+    281 => {
+      let source = d.u32()?;
+      let id = d.u32()?;
+      let length = d.i32()?;
+      let mut message_arg = d.pointer()?;
+      let message = message_arg.as_ptr::<GLchar>();
+      gl.PushDebugGroup(source, id, length, message);
+    }
+    #[cfg(feature = "GL_KHR_debug")]
+    282 => {
+      let source = d.u32()?;
+      let id = d.u32()?;
+      let length = d.i32()?;
+      let mut message_arg = d.pointer()?;
+      let message = message_arg.as_ptr::<GLchar>();
+      gl.PushDebugGroupKHR(source, id, length, message);
+    }
+    #[cfg(feature = "GL_EXT_disjoint_timer_query")]
+    283 => {
+      let id = st.names.get(NameKind::Query, d.u32()?);
+      let target = d.u32()?;
+      gl.QueryCounterEXT(id, target);
+    }
+    284 => {
+      let src = d.u32()?;
+      gl.ReadBuffer(src);
+    }
+    285 => {
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let format = d.u32()?;
+      let type_ = d.u32()?;
+      let mut pixels_arg = d.pointer()?;
+      let mut pixels_out = Scratch::new(&pixels_arg, OUT_SCRATCH_BYTES);
+      let pixels = pixels_out.as_mut_ptr::<c_void>();
+      gl.ReadPixels(x, y, width, height, format, type_, pixels);
+    }
+    #[cfg(feature = "gles32")]
+    286 => {
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let format = d.u32()?;
+      let type_ = d.u32()?;
+      let bufSize = d.i32()?;
+      let mut data_arg = d.pointer()?;
+      let mut data_out =
+        Scratch::new(&data_arg, bufSize.max(0) as usize * size_of::<c_void>());
+      let data = data_out.as_mut_ptr::<c_void>();
+      gl.ReadnPixels(x, y, width, height, format, type_, bufSize, data);
+    }
+    287 => {
+      gl.ReleaseShaderCompiler();
+    }
+    288 => {
+      let target = d.u32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.RenderbufferStorage(target, internalformat, width, height);
+    }
+    289 => {
+      let target = d.u32()?;
+      let samples = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.RenderbufferStorageMultisample(
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+      );
+    }
+    #[cfg(feature = "GL_EXT_multisampled_render_to_texture")]
+    290 => {
+      let target = d.u32()?;
+      let samples = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.RenderbufferStorageMultisampleEXT(
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+      );
+    }
+    291 => {
+      gl.ResumeTransformFeedback();
+    }
+    292 => {
+      let value = d.f32()?;
+      let invert = d.u8()?;
+      gl.SampleCoverage(value, invert);
+    }
+    #[cfg(feature = "gles31")]
+    293 => {
+      let maskNumber = d.u32()?;
+      let mask = d.u32()?;
+      gl.SampleMaski(maskNumber, mask);
+    }
+    #[cfg(feature = "gles32")]
+    294 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut param_arg = d.pointer()?;
+      let param = param_arg.as_ptr::<GLint>();
+      gl.SamplerParameterIiv(sampler, pname, param);
+    }
+    #[cfg(feature = "gles32")]
+    295 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut param_arg = d.pointer()?;
+      let param = param_arg.as_ptr::<GLuint>();
+      gl.SamplerParameterIuiv(sampler, pname, param);
+    }
+    296 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let param = d.f32()?;
+      gl.SamplerParameterf(sampler, pname, param);
+    }
+    297 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut param_arg = d.pointer()?;
+      let param = param_arg.as_ptr::<GLfloat>();
+      gl.SamplerParameterfv(sampler, pname, param);
+    }
+    298 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let param = d.i32()?;
+      gl.SamplerParameteri(sampler, pname, param);
+    }
+    299 => {
+      let sampler = st.names.get(NameKind::Sampler, d.u32()?);
+      let pname = d.u32()?;
+      let mut param_arg = d.pointer()?;
+      let param = param_arg.as_ptr::<GLint>();
+      gl.SamplerParameteriv(sampler, pname, param);
+    }
+    300 => {
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.Scissor(x, y, width, height);
+    }
+    301 => {
+      let count = d.i32()?;
+      let mut shaders_arg = d.pointer()?;
+      let binaryformat = d.u32()?;
+      let mut binary_arg = d.pointer()?;
+      let length = d.i32()?;
+      st.names.remap_payload(NameKind::Program, &mut shaders_arg);
+      let shaders = shaders_arg.as_ptr::<GLuint>();
+      let binary = binary_arg.as_ptr::<c_void>();
+      gl.ShaderBinary(count, shaders, binaryformat, binary, length);
+    }
+    302 => {
+      let shader = st.names.get(NameKind::Program, d.u32()?);
+      let count = d.i32()?;
+      let mut string_arg = d.pointer()?;
+      let mut length_arg = d.pointer()?;
+      let string_strings = string_arg.strings();
+      let string = string_strings.as_ptr();
+      let _ = length_arg;
+      let length = null();
+      gl.ShaderSource(shader, count, string, length);
+    }
+    303 => {
+      let func = d.u32()?;
+      let ref_ = d.i32()?;
+      let mask = d.u32()?;
+      gl.StencilFunc(func, ref_, mask);
+    }
+    304 => {
+      let face = d.u32()?;
+      let func = d.u32()?;
+      let ref_ = d.i32()?;
+      let mask = d.u32()?;
+      gl.StencilFuncSeparate(face, func, ref_, mask);
+    }
+    305 => {
+      let mask = d.u32()?;
+      gl.StencilMask(mask);
+    }
+    306 => {
+      let face = d.u32()?;
+      let mask = d.u32()?;
+      gl.StencilMaskSeparate(face, mask);
+    }
+    307 => {
+      let fail = d.u32()?;
+      let zfail = d.u32()?;
+      let zpass = d.u32()?;
+      gl.StencilOp(fail, zfail, zpass);
+    }
+    308 => {
+      let face = d.u32()?;
+      let sfail = d.u32()?;
+      let dpfail = d.u32()?;
+      let dppass = d.u32()?;
+      gl.StencilOpSeparate(face, sfail, dpfail, dppass);
+    }
+    #[cfg(feature = "gles32")]
+    309 => {
+      let target = d.u32()?;
+      let internalformat = d.u32()?;
+      let buffer = st.names.get(NameKind::Buffer, d.u32()?);
+      gl.TexBuffer(target, internalformat, buffer);
+    }
+    #[cfg(feature = "gles32")]
+    310 => {
+      let target = d.u32()?;
+      let internalformat = d.u32()?;
+      let buffer = st.names.get(NameKind::Buffer, d.u32()?);
+      let offset = d.i64()? as isize;
+      let size = d.i64()? as isize;
+      gl.TexBufferRange(target, internalformat, buffer, offset, size);
+    }
+    311 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let internalformat = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let border = d.i32()?;
+      let format = d.u32()?;
+      let type_ = d.u32()?;
+      let mut pixels_arg = d.pointer()?;
+      let pixels = pixels_arg.as_ptr::<c_void>();
+      gl.TexImage2D(
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        border,
+        format,
+        type_,
+        pixels,
+      );
+    }
+    312 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let internalformat = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let depth = d.i32()?;
+      let border = d.i32()?;
+      let format = d.u32()?;
+      let type_ = d.u32()?;
+      let mut pixels_arg = d.pointer()?;
+      let pixels = pixels_arg.as_ptr::<c_void>();
+      gl.TexImage3D(
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        depth,
+        border,
+        format,
+        type_,
+        pixels,
+      );
+    }
+    #[cfg(feature = "gles32")]
+    313 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let params = params_arg.as_ptr::<GLint>();
+      gl.TexParameterIiv(target, pname, params);
+    }
+    #[cfg(feature = "gles32")]
+    314 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let params = params_arg.as_ptr::<GLuint>();
+      gl.TexParameterIuiv(target, pname, params);
+    }
+    315 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let param = d.f32()?;
+      gl.TexParameterf(target, pname, param);
+    }
+    316 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let params = params_arg.as_ptr::<GLfloat>();
+      gl.TexParameterfv(target, pname, params);
+    }
+    317 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let param = d.i32()?;
+      gl.TexParameteri(target, pname, param);
+    }
+    318 => {
+      let target = d.u32()?;
+      let pname = d.u32()?;
+      let mut params_arg = d.pointer()?;
+      let params = params_arg.as_ptr::<GLint>();
+      gl.TexParameteriv(target, pname, params);
+    }
+    319 => {
+      let target = d.u32()?;
+      let levels = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.TexStorage2D(target, levels, internalformat, width, height);
+    }
+    #[cfg(feature = "gles31")]
+    320 => {
+      let target = d.u32()?;
+      let samples = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let fixedsamplelocations = d.u8()?;
+      gl.TexStorage2DMultisample(
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        fixedsamplelocations,
+      );
+    }
+    321 => {
+      let target = d.u32()?;
+      let levels = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let depth = d.i32()?;
+      gl.TexStorage3D(target, levels, internalformat, width, height, depth);
+    }
+    #[cfg(feature = "gles32")]
+    322 => {
+      let target = d.u32()?;
+      let samples = d.i32()?;
+      let internalformat = d.u32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let depth = d.i32()?;
+      let fixedsamplelocations = d.u8()?;
+      gl.TexStorage3DMultisample(
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        depth,
+        fixedsamplelocations,
+      );
+    }
+    323 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let xoffset = d.i32()?;
+      let yoffset = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let format = d.u32()?;
+      let type_ = d.u32()?;
+      let mut pixels_arg = d.pointer()?;
+      let pixels = pixels_arg.as_ptr::<c_void>();
+      gl.TexSubImage2D(
+        target, level, xoffset, yoffset, width, height, format, type_, pixels,
+      );
+    }
+    324 => {
+      let target = d.u32()?;
+      let level = d.i32()?;
+      let xoffset = d.i32()?;
+      let yoffset = d.i32()?;
+      let zoffset = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      let depth = d.i32()?;
+      let format = d.u32()?;
+      let type_ = d.u32()?;
+      let mut pixels_arg = d.pointer()?;
+      let pixels = pixels_arg.as_ptr::<c_void>();
+      gl.TexSubImage3D(
+        target, level, xoffset, yoffset, zoffset, width, height, depth, format,
+        type_, pixels,
+      );
+    }
+    325 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let count = d.i32()?;
+      let mut varyings_arg = d.pointer()?;
+      let bufferMode = d.u32()?;
+      let varyings_strings = varyings_arg.strings();
+      let varyings = varyings_strings.as_ptr();
+      gl.TransformFeedbackVaryings(program, count, varyings, bufferMode);
+    }
+    326 => {
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      gl.Uniform1f(location, v0);
+    }
+    327 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.Uniform1fv(location, count, value);
+    }
+    328 => {
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      gl.Uniform1i(location, v0);
+    }
+    329 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.Uniform1iv(location, count, value);
+    }
+    330 => {
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      gl.Uniform1ui(location, v0);
+    }
+    331 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.Uniform1uiv(location, count, value);
+    }
+    332 => {
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      let v1 = d.f32()?;
+      gl.Uniform2f(location, v0, v1);
+    }
+    333 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.Uniform2fv(location, count, value);
+    }
+    334 => {
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      let v1 = d.i32()?;
+      gl.Uniform2i(location, v0, v1);
+    }
+    335 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.Uniform2iv(location, count, value);
+    }
+    336 => {
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      let v1 = d.u32()?;
+      gl.Uniform2ui(location, v0, v1);
+    }
+    337 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.Uniform2uiv(location, count, value);
+    }
+    338 => {
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      let v1 = d.f32()?;
+      let v2 = d.f32()?;
+      gl.Uniform3f(location, v0, v1, v2);
+    }
+    339 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.Uniform3fv(location, count, value);
+    }
+    340 => {
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      let v1 = d.i32()?;
+      let v2 = d.i32()?;
+      gl.Uniform3i(location, v0, v1, v2);
+    }
+    341 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.Uniform3iv(location, count, value);
+    }
+    342 => {
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      let v1 = d.u32()?;
+      let v2 = d.u32()?;
+      gl.Uniform3ui(location, v0, v1, v2);
+    }
+    343 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.Uniform3uiv(location, count, value);
+    }
+    344 => {
+      let location = d.i32()?;
+      let v0 = d.f32()?;
+      let v1 = d.f32()?;
+      let v2 = d.f32()?;
+      let v3 = d.f32()?;
+      gl.Uniform4f(location, v0, v1, v2, v3);
+    }
+    345 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.Uniform4fv(location, count, value);
+    }
+    346 => {
+      let location = d.i32()?;
+      let v0 = d.i32()?;
+      let v1 = d.i32()?;
+      let v2 = d.i32()?;
+      let v3 = d.i32()?;
+      gl.Uniform4i(location, v0, v1, v2, v3);
+    }
+    347 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLint>();
+      gl.Uniform4iv(location, count, value);
+    }
+    348 => {
+      let location = d.i32()?;
+      let v0 = d.u32()?;
+      let v1 = d.u32()?;
+      let v2 = d.u32()?;
+      let v3 = d.u32()?;
+      gl.Uniform4ui(location, v0, v1, v2, v3);
+    }
+    349 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLuint>();
+      gl.Uniform4uiv(location, count, value);
+    }
+    350 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      let uniformBlockIndex = d.u32()?;
+      let uniformBlockBinding = d.u32()?;
+      gl.UniformBlockBinding(program, uniformBlockIndex, uniformBlockBinding);
+    }
+    351 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix2fv(location, count, transpose, value);
+    }
+    352 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix2x3fv(location, count, transpose, value);
+    }
+    353 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix2x4fv(location, count, transpose, value);
+    }
+    354 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix3fv(location, count, transpose, value);
+    }
+    355 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix3x2fv(location, count, transpose, value);
+    }
+    356 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix3x4fv(location, count, transpose, value);
+    }
+    357 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix4fv(location, count, transpose, value);
+    }
+    358 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix4x2fv(location, count, transpose, value);
+    }
+    359 => {
+      let location = d.i32()?;
+      let count = d.i32()?;
+      let transpose = d.u8()?;
+      let mut value_arg = d.pointer()?;
+      let value = value_arg.as_ptr::<GLfloat>();
+      gl.UniformMatrix4x3fv(location, count, transpose, value);
+    }
+    360 => {
+      let target = d.u32()?;
+      let out = gl.UnmapBuffer(target);
+      d.u8()?;
+      let _ = out;
+      st.mapped.remove(&target);
+    }
+    361 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      gl.UseProgram(program);
+    }
+    #[cfg(feature = "gles31")]
+    362 => {
+      let pipeline = st.names.get(NameKind::ProgramPipeline, d.u32()?);
+      let stages = d.u32()?;
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      gl.UseProgramStages(pipeline, stages, program);
+    }
+    363 => {
+      let program = st.names.get(NameKind::Program, d.u32()?);
+      gl.ValidateProgram(program);
+    }
+    #[cfg(feature = "gles31")]
+    364 => {
+      let pipeline = st.names.get(NameKind::ProgramPipeline, d.u32()?);
+      gl.ValidateProgramPipeline(pipeline);
+    }
+    365 => {
+      let index = d.u32()?;
+      let x = d.f32()?;
+      gl.VertexAttrib1f(index, x);
+    }
+    366 => {
+      let index = d.u32()?;
+      let mut v_arg = d.pointer()?;
+      let v = v_arg.as_ptr::<GLfloat>();
+      gl.VertexAttrib1fv(index, v);
+    }
+    367 => {
+      let index = d.u32()?;
+      let x = d.f32()?;
+      let y = d.f32()?;
+      gl.VertexAttrib2f(index, x, y);
+    }
+    368 => {
+      let index = d.u32()?;
+      let mut v_arg = d.pointer()?;
+      let v = v_arg.as_ptr::<GLfloat>();
+      gl.VertexAttrib2fv(index, v);
+    }
+    369 => {
+      let index = d.u32()?;
+      let x = d.f32()?;
+      let y = d.f32()?;
+      let z = d.f32()?;
+      gl.VertexAttrib3f(index, x, y, z);
+    }
+    370 => {
+      let index = d.u32()?;
+      let mut v_arg = d.pointer()?;
+      let v = v_arg.as_ptr::<GLfloat>();
+      gl.VertexAttrib3fv(index, v);
+    }
+    371 => {
+      let index = d.u32()?;
+      let x = d.f32()?;
+      let y = d.f32()?;
+      let z = d.f32()?;
+      let w = d.f32()?;
+      gl.VertexAttrib4f(index, x, y, z, w);
+    }
+    372 => {
+      let index = d.u32()?;
+      let mut v_arg = d.pointer()?;
+      let v = v_arg.as_ptr::<GLfloat>();
+      gl.VertexAttrib4fv(index, v);
+    }
+    #[cfg(feature = "gles31")]
+    373 => {
+      let attribindex = d.u32()?;
+      let bindingindex = d.u32()?;
+      gl.VertexAttribBinding(attribindex, bindingindex);
+    }
+    374 => {
+      let index = d.u32()?;
+      let divisor = d.u32()?;
+      gl.VertexAttribDivisor(index, divisor);
+    }
+    #[cfg(feature = "gles31")]
+    375 => {
+      let attribindex = d.u32()?;
+      let size = d.i32()?;
+      let type_ = d.u32()?;
+      let normalized = d.u8()?;
+      let relativeoffset = d.u32()?;
+      gl.VertexAttribFormat(
+        attribindex,
+        size,
+        type_,
+        normalized,
+        relativeoffset,
+      );
+    }
+    376 => {
+      let index = d.u32()?;
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let z = d.i32()?;
+      let w = d.i32()?;
+      gl.VertexAttribI4i(index, x, y, z, w);
+    }
+    377 => {
+      let index = d.u32()?;
+      let mut v_arg = d.pointer()?;
+      let v = v_arg.as_ptr::<GLint>();
+      gl.VertexAttribI4iv(index, v);
+    }
+    378 => {
+      let index = d.u32()?;
+      let x = d.u32()?;
+      let y = d.u32()?;
+      let z = d.u32()?;
+      let w = d.u32()?;
+      gl.VertexAttribI4ui(index, x, y, z, w);
+    }
+    379 => {
+      let index = d.u32()?;
+      let mut v_arg = d.pointer()?;
+      let v = v_arg.as_ptr::<GLuint>();
+      gl.VertexAttribI4uiv(index, v);
+    }
+    #[cfg(feature = "gles31")]
+    380 => {
+      let attribindex = d.u32()?;
+      let size = d.i32()?;
+      let type_ = d.u32()?;
+      let relativeoffset = d.u32()?;
+      gl.VertexAttribIFormat(attribindex, size, type_, relativeoffset);
+    }
+    381 => {
+      let index = d.u32()?;
+      let size = d.i32()?;
+      let type_ = d.u32()?;
+      let stride = d.i32()?;
+      let mut pointer_arg = d.pointer()?;
+      let pointer = pointer_arg.as_ptr::<c_void>();
+      gl.VertexAttribIPointer(index, size, type_, stride, pointer);
+    }
+    382 => {
+      let index = d.u32()?;
+      let size = d.i32()?;
+      let type_ = d.u32()?;
+      let normalized = d.u8()?;
+      let stride = d.i32()?;
+      let mut pointer_arg = d.pointer()?;
+      let pointer = pointer_arg.as_ptr::<c_void>();
+      gl.VertexAttribPointer(index, size, type_, normalized, stride, pointer);
+    }
+    #[cfg(feature = "gles31")]
+    383 => {
+      let bindingindex = d.u32()?;
+      let divisor = d.u32()?;
+      gl.VertexBindingDivisor(bindingindex, divisor);
+    }
+    384 => {
+      let x = d.i32()?;
+      let y = d.i32()?;
+      let width = d.i32()?;
+      let height = d.i32()?;
+      gl.Viewport(x, y, width, height);
+    }
+    385 => {
+      let sync = st.sync(d.u64()?);
+      let flags = d.u32()?;
+      let timeout = d.u64()?;
+      gl.WaitSync(sync, flags, timeout);
+    }
+    _ => {
+      return Err(TraceError::UnsupportedCommand(LOADABLE_COMMANDS[index].name))
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,540 @@
+//! A binary trace file format, for capturing GL calls and replaying them.
+//!
+//! A [`TraceWriter`] is a [`CallSink`] that writes every call it sees into a
+//! compact binary file. Unlike a [`GlCall`], which only holds pointer
+//! addresses, the trace also holds the data behind pointers: buffer data,
+//! texture pixels, shader sources, uniform values, labels, and so on. The
+//! sizes of the data are worked out from the call's other arguments, the same
+//! way that GL itself works them out.
+//!
+//! Later, a [`Replayer`] can read the trace and make all of the calls again
+//! with any [`GlFns`], such as one on a different machine. Object names
+//! (buffers, textures, programs, sync objects, and so on) are translated
+//! during the replay, so it doesn't matter if the replaying context hands out
+//! different names than the recording context did.
+//!
+//! ```no_run
+//! # #[cfg(all(feature = "struct_loader", feature = "mock"))]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> { unsafe {
+//! # let get_proc_address = |name| gles30::mock::get_proc_address(name);
+//! use gles30::{trace_file::*, *};
+//! use std::{fs::File, io::BufWriter};
+//! // record
+//! let mut gl = GlFns::load_with(get_proc_address);
+//! let file = BufWriter::new(File::create("frame.gltrace")?);
+//! gl.set_call_sink(TraceWriter::new(file)?);
+//! // ... draw things ...
+//! gl.clear_call_sink(); // flushes the file
+//!
+//! // replay
+//! let gl = GlFns::load_with(get_proc_address);
+//! let count = replay(&gl, File::open("frame.gltrace")?)?;
+//! println!("replayed {} calls", count);
+//! # } Ok(()) }
+//! # #[cfg(not(all(feature = "struct_loader", feature = "mock")))]
+//! # fn main() {}
+//! ```
+//!
+//! ## Limitations
+//! * Data behind a `glVertexAttribPointer` pointer (a "client side" vertex
+//!   array) isn't captured, because there's no way to know how much of it
+//!   will be read. Use a buffer object for vertex data.
+//! * Writes into a buffer mapped with `glMapBufferRange` are only captured
+//!   when they're flushed with `glFlushMappedBufferRange` (that is, when the
+//!   buffer was mapped with `GL_MAP_FLUSH_EXPLICIT_BIT`).
+//! * Uniform locations aren't translated. This is fine when replaying with
+//!   the same driver, but other drivers might pick other locations.
+//! * The debug message callback can't be captured, so replaying
+//!   `glDebugMessageCallbackKHR` always clears the callback.
+//!
+//! ## Format
+//! All values are little-endian. A trace starts with the 8 bytes
+//! `GLES30TR`, a `u32` format version, then the list of command names: a
+//! `u16` count followed by that many names, each a `u8` length and then the
+//! bytes of the name.
+//!
+//! After that there's one record per call: the `u16` index of the command in
+//! the name list, the `u32` size of the record's body, and then the body.
+//! The body has each argument in order, and then the return value if there
+//! is one. Integers and floats are written at their own size, except that
+//! `GLintptr` and `GLsizeiptr` are always 8 bytes. Pointers (and `GLsync`
+//! values) are written as a `u64` address, followed by the `u32` size of the
+//! data that the pointer points to and then that data. When the data isn't
+//! captured the size is `u32::MAX` and there's no data.
+
+use super::*;
+use crate::record::CallSink;
+use core::{fmt, mem::size_of, ptr::null};
+use std::{
+  collections::HashMap,
+  ffi::CStr,
+  io::{self, Write},
+  string::String,
+  vec::Vec,
+};
+
+mod calls;
+#[cfg(feature = "struct_loader")]
+mod replay;
+#[cfg(feature = "struct_loader")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "struct_loader")))]
+pub use replay::*;
+
+const MAGIC: &[u8; 8] = b"GLES30TR";
+const VERSION: u32 = 1;
+/// The size written for a pointer with no data.
+const NO_PAYLOAD: u32 = core::u32::MAX;
+/// When replaying, how many bytes are given to output pointers that don't have
+/// a size in their arguments.
+#[cfg(feature = "struct_loader")]
+const OUT_SCRATCH_BYTES: usize = 4096;
+
+/// An error from reading or replaying a trace.
+#[derive(Debug)]
+pub enum TraceError {
+  /// Reading the trace failed.
+  Io(io::Error),
+  /// The data doesn't start like a trace.
+  NotATrace,
+  /// The trace uses a newer format version.
+  UnsupportedVersion(u32),
+  /// The trace has a command that this crate doesn't know about.
+  UnknownCommand(String),
+  /// The trace has a command that needs a cargo feature that isn't enabled.
+  ///
+  /// The record has been skipped, so you can continue the replay if you want.
+  UnsupportedCommand(&'static str),
+  /// The trace ends in the middle of a record.
+  Truncated,
+}
+impl fmt::Display for TraceError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      TraceError::Io(e) => write!(f, "trace IO error: {}", e),
+      TraceError::NotATrace => write!(f, "not a GL trace"),
+      TraceError::UnsupportedVersion(v) => {
+        write!(f, "unsupported trace format version {}", v)
+      }
+      TraceError::UnknownCommand(name) => {
+        write!(f, "unknown command in trace: {}", name)
+      }
+      TraceError::UnsupportedCommand(name) => {
+        write!(f, "command needs a cargo feature that isn't enabled: {}", name)
+      }
+      TraceError::Truncated => write!(f, "trace ends in the middle of a call"),
+    }
+  }
+}
+impl std::error::Error for TraceError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      TraceError::Io(e) => Some(e),
+      _ => None,
+    }
+  }
+}
+impl From<io::Error> for TraceError {
+  fn from(e: io::Error) -> Self {
+    if e.kind() == io::ErrorKind::UnexpectedEof {
+      TraceError::Truncated
+    } else {
+      TraceError::Io(e)
+    }
+  }
+}
+
+// // // // //
+// Writing
+// // // // //
+
+/// A [`CallSink`] that writes calls into a trace.
+///
+/// The trace header is written when the writer is made, and each call is
+/// written as it's recorded. You'll generally want to use a buffered writer
+/// such as [`BufWriter`](std::io::BufWriter).
+///
+/// If writing fails then the writer stops writing, and the error is kept so
+/// that [`finish`](TraceWriter::finish) can return it. To get the writer
+/// back out of a loader after recording, wrap it in an
+/// `Arc<Mutex<TraceWriter<W>>>` and keep a clone of the `Arc`.
+///
+/// Writing a call reads the data behind the pointers in it, so making a
+/// writer is `unsafe`: see [`new`](TraceWriter::new).
+pub struct TraceWriter<W: Write> {
+  out: W,
+  encoder: Encoder,
+  error: Option<io::Error>,
+}
+impl<W: Write> TraceWriter<W> {
+  /// Makes a writer, writing the trace header to `out`.
+  ///
+  /// ## Safety
+  /// * The writer must only be given calls by a loader that it's the call
+  ///   sink of. Those calls are recorded right after they're made, so the
+  ///   pointers in them are still valid to read from.
+  /// * You must not call [`record`](CallSink::record) on the writer (or on
+  ///   anything wrapping it) yourself, unless every pointer in the call is
+  ///   valid to read, as with [`write_call`](TraceWriter::write_call).
+  pub unsafe fn new(mut out: W) -> io::Result<Self> {
+    out.write_all(MAGIC)?;
+    out.write_all(&VERSION.to_le_bytes())?;
+    out.write_all(&(COMMAND_COUNT as u16).to_le_bytes())?;
+    for command in LOADABLE_COMMANDS.iter() {
+      out.write_all(&[command.name.len() as u8])?;
+      out.write_all(command.name.as_bytes())?;
+    }
+    Ok(Self { out, encoder: Encoder::default(), error: None })
+  }
+
+  /// The error that stopped the writer, if any.
+  pub fn error(&self) -> Option<&io::Error> {
+    self.error.as_ref()
+  }
+
+  /// Flushes the writer and gives back the output.
+  ///
+  /// If an earlier write failed, you get that error.
+  pub fn finish(mut self) -> io::Result<W> {
+    if let Some(e) = self.error.take() {
+      return Err(e);
+    }
+    self.out.flush()?;
+    Ok(self.out)
+  }
+
+  /// Writes a call into the trace.
+  ///
+  /// ## Safety
+  /// Any pointers in the call must still be valid to read from.
+  pub unsafe fn write_call(&mut self, call: GlCall) {
+    if self.error.is_some() {
+      return;
+    }
+    let e = &mut self.encoder;
+    e.buf.clear();
+    e.encode(call);
+    e.observe(call);
+    let mut header = [0_u8; 6];
+    header[..2].copy_from_slice(&e.index.to_le_bytes());
+    header[2..].copy_from_slice(&(e.buf.len() as u32).to_le_bytes());
+    let out = &mut self.out;
+    let result = out.write_all(&header).and_then(|_| out.write_all(&e.buf));
+    if let Err(err) = result {
+      self.error = Some(err);
+    }
+  }
+}
+impl<W: Write + Send> CallSink for TraceWriter<W> {
+  fn record(&mut self, call: GlCall) {
+    // Safety: the caller of `new` promised that calls only come from a
+    // loader recording them right after they're made, while the pointers
+    // in them are still valid.
+    unsafe { self.write_call(call) }
+  }
+}
+impl<W: Write> fmt::Debug for TraceWriter<W> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("TraceWriter").field("error", &self.error).finish()
+  }
+}
+
+/// The GL state that a writer needs to know what data is behind a pointer.
+#[derive(Debug)]
+struct WriterState {
+  vertex_array: GLuint,
+  element_buffers: HashMap<GLuint, GLuint>,
+  unpack_buffer: GLuint,
+  unpack_alignment: GLint,
+  unpack_row_length: GLint,
+  unpack_image_height: GLint,
+  unpack_skip_pixels: GLint,
+  unpack_skip_rows: GLint,
+  unpack_skip_images: GLint,
+  mapped: HashMap<GLenum, usize>,
+}
+impl Default for WriterState {
+  fn default() -> Self {
+    Self {
+      vertex_array: 0,
+      element_buffers: HashMap::new(),
+      unpack_buffer: 0,
+      unpack_alignment: 4,
+      unpack_row_length: 0,
+      unpack_image_height: 0,
+      unpack_skip_pixels: 0,
+      unpack_skip_rows: 0,
+      unpack_skip_images: 0,
+      mapped: HashMap::new(),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct Encoder {
+  index: u16,
+  buf: Vec<u8>,
+  state: WriterState,
+}
+impl Encoder {
+  fn command(&mut self, index: usize) {
+    self.index = index as u16;
+  }
+  fn u8(&mut self, v: u8) {
+    self.buf.push(v)
+  }
+  fn u32(&mut self, v: u32) {
+    self.buf.extend_from_slice(&v.to_le_bytes())
+  }
+  fn i32(&mut self, v: i32) {
+    self.buf.extend_from_slice(&v.to_le_bytes())
+  }
+  fn f32(&mut self, v: f32) {
+    self.u32(v.to_bits())
+  }
+  fn u64(&mut self, v: u64) {
+    self.buf.extend_from_slice(&v.to_le_bytes())
+  }
+  fn i64(&mut self, v: i64) {
+    self.buf.extend_from_slice(&v.to_le_bytes())
+  }
+
+  /// A pointer without its data.
+  fn address<T: ?Sized>(&mut self, p: *const T) {
+    self.u64(p as *const u8 as usize as u64);
+    self.u32(NO_PAYLOAD);
+  }
+
+  fn payload(&mut self, address: usize, data: &[u8]) {
+    self.u64(address as u64);
+    self.u32(data.len() as u32);
+    self.buf.extend_from_slice(data);
+  }
+
+  /// A pointer to `len` bytes.
+  unsafe fn bytes(&mut self, p: *const c_void, len: usize) {
+    if p.is_null() {
+      self.address(p)
+    } else {
+      let data = core::slice::from_raw_parts(p as *const u8, len);
+      self.payload(p as usize, data)
+    }
+  }
+
+  /// A pointer to `count` values.
+  unsafe fn array<T>(&mut self, p: *const T, count: usize) {
+    self.bytes(p as *const c_void, count * size_of::<T>())
+  }
+
+  /// A string that's null-terminated if `length` is negative.
+  ///
+  /// A null is always written after the string's bytes.
+  unsafe fn c_str(&mut self, p: *const GLchar, length: GLsizei) {
+    if p.is_null() {
+      return self.address(p);
+    }
+    if length < 0 {
+      let data = CStr::from_ptr(p).to_bytes_with_nul();
+      self.payload(p as usize, data)
+    } else {
+      let mut data =
+        core::slice::from_raw_parts(p as *const u8, length as usize).to_vec();
+      data.push(0);
+      self.payload(p as usize, &data)
+    }
+  }
+
+  /// An array of strings, each written with a null after it.
+  unsafe fn strings(
+    &mut self, p: *const *const GLchar, count: GLsizei, lengths: *const GLint,
+  ) {
+    if p.is_null() {
+      return self.address(p);
+    }
+    let mut data = Vec::new();
+    for i in 0..count.max(0) as usize {
+      let s = *p.add(i);
+      let length = if lengths.is_null() { -1 } else { *lengths.add(i) };
+      if length < 0 {
+        data.extend_from_slice(CStr::from_ptr(s).to_bytes());
+      } else {
+        data.extend_from_slice(core::slice::from_raw_parts(
+          s as *const u8,
+          length as usize,
+        ));
+      }
+      data.push(0);
+    }
+    self.payload(p as usize, &data)
+  }
+
+  /// Index data for a draw, which is an offset when a buffer is bound.
+  unsafe fn indices(&mut self, p: *const c_void, count: GLsizei, ty: GLenum) {
+    let s = &self.state;
+    let bound = s.element_buffers.get(&s.vertex_array).cloned().unwrap_or(0);
+    if bound != 0 {
+      self.address(p)
+    } else {
+      let size = match ty {
+        GL_UNSIGNED_BYTE => 1,
+        GL_UNSIGNED_SHORT => 2,
+        _ => 4,
+      };
+      self.bytes(p, count.max(0) as usize * size)
+    }
+  }
+
+  /// Pixel data for an upload, which is an offset when a buffer is bound.
+  unsafe fn pixels(
+    &mut self, p: *const c_void, format: GLenum, ty: GLenum, width: GLsizei,
+    height: GLsizei, depth: GLsizei,
+  ) {
+    if self.state.unpack_buffer != 0 {
+      return self.address(p);
+    }
+    let size = self.unpack_size(format, ty, width, height, depth);
+    self.bytes(p, size)
+  }
+
+  /// Compressed data for an upload, which is an offset when a buffer is
+  /// bound.
+  unsafe fn unpack_bytes(&mut self, p: *const c_void, size: GLsizei) {
+    if self.state.unpack_buffer != 0 {
+      self.address(p)
+    } else {
+      self.bytes(p, size.max(0) as usize)
+    }
+  }
+
+  /// The contents of a flushed range of a mapped buffer.
+  unsafe fn mapped_range(
+    &mut self, target: GLenum, offset: GLintptr, length: GLsizeiptr,
+  ) {
+    match self.state.mapped.get(&target) {
+      Some(&base) if offset >= 0 && length >= 0 => {
+        let p = (base as *const u8).offset(offset);
+        self.bytes(p as *const c_void, length as usize)
+      }
+      _ => self.address(null::<c_void>()),
+    }
+  }
+
+  /// How many bytes of client memory an upload reads, following the unpack
+  /// pixel store settings.
+  fn unpack_size(
+    &self, format: GLenum, ty: GLenum, width: GLsizei, height: GLsizei,
+    depth: GLsizei,
+  ) -> usize {
+    if width <= 0 || height <= 0 || depth <= 0 {
+      return 0;
+    }
+    let s = &self.state;
+    let pixel = pixel_size(format, ty);
+    let row_pixels =
+      if s.unpack_row_length > 0 { s.unpack_row_length } else { width };
+    let align = s.unpack_alignment.max(1) as usize;
+    let row = (row_pixels as usize * pixel + align - 1) / align * align;
+    let image_rows =
+      if s.unpack_image_height > 0 { s.unpack_image_height } else { height };
+    let image = image_rows as usize * row;
+    let skip_images = if depth > 1 { s.unpack_skip_images as usize } else { 0 };
+    (skip_images + depth as usize - 1) * image
+      + (s.unpack_skip_rows + height - 1) as usize * row
+      + (s.unpack_skip_pixels + width) as usize * pixel
+  }
+
+  /// Updates the state after a call was written.
+  unsafe fn observe(&mut self, call: GlCall) {
+    let s = &mut self.state;
+    match call {
+      GlCall::BindBuffer { target: GL_ELEMENT_ARRAY_BUFFER, buffer } => {
+        s.element_buffers.insert(s.vertex_array, buffer);
+      }
+      GlCall::BindBuffer { target: GL_PIXEL_UNPACK_BUFFER, buffer } => {
+        s.unpack_buffer = buffer;
+      }
+      GlCall::BindVertexArray { array } => s.vertex_array = array,
+      GlCall::DeleteBuffers { n, buffers } if !buffers.is_null() => {
+        for name in core::slice::from_raw_parts(buffers, n.max(0) as usize) {
+          if s.unpack_buffer == *name {
+            s.unpack_buffer = 0;
+          }
+          for bound in s.element_buffers.values_mut() {
+            if bound == name {
+              *bound = 0;
+            }
+          }
+        }
+      }
+      GlCall::DeleteVertexArrays { n, arrays } if !arrays.is_null() => {
+        for name in core::slice::from_raw_parts(arrays, n.max(0) as usize) {
+          s.element_buffers.remove(name);
+          if s.vertex_array == *name {
+            s.vertex_array = 0;
+          }
+        }
+      }
+      GlCall::PixelStorei { pname, param } => match pname {
+        GL_UNPACK_ALIGNMENT => s.unpack_alignment = param,
+        GL_UNPACK_ROW_LENGTH => s.unpack_row_length = param,
+        GL_UNPACK_IMAGE_HEIGHT => s.unpack_image_height = param,
+        GL_UNPACK_SKIP_PIXELS => s.unpack_skip_pixels = param,
+        GL_UNPACK_SKIP_ROWS => s.unpack_skip_rows = param,
+        GL_UNPACK_SKIP_IMAGES => s.unpack_skip_images = param,
+        _ => (),
+      },
+      GlCall::MapBufferRange { target, ret, .. } if !ret.is_null() => {
+        s.mapped.insert(target, ret as usize);
+      }
+      GlCall::UnmapBuffer { target, .. } => {
+        s.mapped.remove(&target);
+      }
+      _ => (),
+    }
+  }
+}
+
+/// The size of one pixel of client data.
+fn pixel_size(format: GLenum, ty: GLenum) -> usize {
+  match ty {
+    GL_UNSIGNED_SHORT_5_6_5
+    | GL_UNSIGNED_SHORT_4_4_4_4
+    | GL_UNSIGNED_SHORT_5_5_5_1 => return 2,
+    GL_UNSIGNED_INT_2_10_10_10_REV
+    | GL_UNSIGNED_INT_10F_11F_11F_REV
+    | GL_UNSIGNED_INT_5_9_9_9_REV
+    | GL_UNSIGNED_INT_24_8 => return 4,
+    GL_FLOAT_32_UNSIGNED_INT_24_8_REV => return 8,
+    _ => (),
+  }
+  let components = match format {
+    GL_RG | GL_RG_INTEGER | GL_LUMINANCE_ALPHA => 2,
+    GL_RGB | GL_RGB_INTEGER => 3,
+    GL_RGBA | GL_RGBA_INTEGER => 4,
+    _ => 1,
+  };
+  let component = match ty {
+    GL_UNSIGNED_BYTE | GL_BYTE => 1,
+    GL_UNSIGNED_SHORT | GL_SHORT | GL_HALF_FLOAT => 2,
+    _ => 4,
+  };
+  components * component
+}
+
+/// How many values a `glClearBuffer*v` call reads.
+fn clear_buffer_len(buffer: GLenum) -> usize {
+  if buffer == GL_COLOR {
+    4
+  } else {
+    1
+  }
+}
+
+/// How many values a `glTexParameter*v` or `glSamplerParameter*v` call reads.
+fn parameter_len(pname: GLenum) -> usize {
+  // `GL_TEXTURE_BORDER_COLOR`, which is only in GLES 3.2.
+  if pname == 0x1004 {
+    4
+  } else {
+    1
+  }
+}
@@ -0,0 +1,377 @@
+//! Reading traces back and replaying them.
+
+use super::*;
+use core::ptr::copy_nonoverlapping;
+use std::{ffi::CString, format, io::Read, vec};
+
+/// Reads values out of a record's body.
+#[derive(Debug)]
+pub(super) struct Decoder<'a> {
+  bytes: &'a [u8],
+}
+impl<'a> Decoder<'a> {
+  fn take(&mut self, n: usize) -> Result<&'a [u8], TraceError> {
+    if self.bytes.len() < n {
+      return Err(TraceError::Truncated);
+    }
+    let (out, rest) = self.bytes.split_at(n);
+    self.bytes = rest;
+    Ok(out)
+  }
+  fn array4(&mut self) -> Result<[u8; 4], TraceError> {
+    let mut out = [0; 4];
+    out.copy_from_slice(self.take(4)?);
+    Ok(out)
+  }
+  fn array8(&mut self) -> Result<[u8; 8], TraceError> {
+    let mut out = [0; 8];
+    out.copy_from_slice(self.take(8)?);
+    Ok(out)
+  }
+  pub(super) fn u8(&mut self) -> Result<u8, TraceError> {
+    Ok(self.take(1)?[0])
+  }
+  pub(super) fn u32(&mut self) -> Result<u32, TraceError> {
+    self.array4().map(u32::from_le_bytes)
+  }
+  pub(super) fn i32(&mut self) -> Result<i32, TraceError> {
+    self.array4().map(i32::from_le_bytes)
+  }
+  pub(super) fn f32(&mut self) -> Result<f32, TraceError> {
+    self.u32().map(f32::from_bits)
+  }
+  pub(super) fn u64(&mut self) -> Result<u64, TraceError> {
+    self.array8().map(u64::from_le_bytes)
+  }
+  pub(super) fn i64(&mut self) -> Result<i64, TraceError> {
+    self.array8().map(i64::from_le_bytes)
+  }
+  pub(super) fn pointer(&mut self) -> Result<Pointer, TraceError> {
+    let address = self.u64()?;
+    let len = self.u32()?;
+    let payload = if len == NO_PAYLOAD {
+      None
+    } else {
+      let data = self.take(len as usize)?;
+      // store the data in `u64` values so that it's aligned for any GL type.
+      let mut words = vec![0_u64; (data.len() + 7) / 8];
+      unsafe {
+        copy_nonoverlapping(
+          data.as_ptr(),
+          words.as_mut_slice().as_mut_ptr() as *mut u8,
+          data.len(),
+        )
+      };
+      Some((words, data.len()))
+    };
+    Ok(Pointer { address, payload })
+  }
+}
+
+/// A pointer argument read from a trace, along with its data if it has any.
+#[derive(Debug)]
+pub(super) struct Pointer {
+  address: u64,
+  payload: Option<(Vec<u64>, usize)>,
+}
+impl Pointer {
+  // only used by the KHR_debug commands
+  #[cfg_attr(not(feature = "GL_KHR_debug"), allow(dead_code))]
+  pub(super) fn address(&self) -> u64 {
+    self.address
+  }
+
+  /// The pointer to pass when replaying: the replay's copy of the data, or
+  /// the original address when there's no data (because it's null or an
+  /// offset into a buffer object).
+  pub(super) fn as_ptr<T>(&self) -> *const T {
+    match &self.payload {
+      Some((words, _)) => words.as_slice().as_ptr() as *const T,
+      None => self.address as usize as *const T,
+    }
+  }
+
+  fn bytes(&self) -> &[u8] {
+    match &self.payload {
+      Some((words, len)) => unsafe {
+        core::slice::from_raw_parts(
+          words.as_slice().as_ptr() as *const u8,
+          *len,
+        )
+      },
+      None => &[],
+    }
+  }
+
+  pub(super) fn u32s_mut(&mut self) -> &mut [u32] {
+    match &mut self.payload {
+      Some((words, len)) => unsafe {
+        core::slice::from_raw_parts_mut(
+          words.as_mut_slice().as_mut_ptr() as *mut u32,
+          *len / 4,
+        )
+      },
+      None => &mut [],
+    }
+  }
+
+  /// Splits the data into null-terminated strings.
+  pub(super) fn strings(&self) -> Strings {
+    if self.payload.is_none() {
+      return Strings { _strings: Vec::new(), pointers: None };
+    }
+    let strings: Vec<CString> = self
+      .bytes()
+      .split(|&b| b == 0)
+      .map(|s| CString::new(s).unwrap())
+      .collect();
+    // the data ends with a null, so there's an extra empty string at the end.
+    let count = strings.len().saturating_sub(1);
+    let pointers = strings[..count].iter().map(|s| s.as_ptr()).collect();
+    Strings { _strings: strings, pointers: Some(pointers) }
+  }
+}
+
+#[derive(Debug)]
+pub(super) struct Strings {
+  _strings: Vec<CString>,
+  pointers: Option<Vec<*const GLchar>>,
+}
+impl Strings {
+  pub(super) fn as_ptr(&self) -> *const *const GLchar {
+    self.pointers.as_ref().map_or(null(), |p| p.as_slice().as_ptr())
+  }
+}
+
+/// Memory for GL to write output values into during a replay.
+#[derive(Debug)]
+pub(super) struct Scratch(Option<Vec<u64>>);
+impl Scratch {
+  /// Memory for `bytes` bytes, or null if the traced pointer was null.
+  pub(super) fn new(traced: &Pointer, bytes: usize) -> Self {
+    if traced.address == 0 {
+      Self(None)
+    } else {
+      Self(Some(vec![0; (bytes + 7) / 8 + 1]))
+    }
+  }
+  pub(super) fn as_mut_ptr<T>(&mut self) -> *mut T {
+    self
+      .0
+      .as_mut()
+      .map_or(null_mut(), |v| v.as_mut_slice().as_mut_ptr() as *mut T)
+  }
+}
+
+/// The kinds of object name that get translated during a replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum NameKind {
+  Buffer,
+  Texture,
+  Framebuffer,
+  Renderbuffer,
+  Sampler,
+  Query,
+  TransformFeedback,
+  VertexArray,
+  ProgramPipeline,
+  /// Shaders and programs share their names.
+  Program,
+}
+
+/// Maps traced object names to replayed object names.
+#[derive(Debug, Default)]
+pub(super) struct NameMap(HashMap<(NameKind, GLuint), GLuint>);
+impl NameMap {
+  pub(super) fn get(&self, kind: NameKind, traced: GLuint) -> GLuint {
+    self.0.get(&(kind, traced)).cloned().unwrap_or(traced)
+  }
+
+  /// Maps the `name` argument of the object label commands.
+  // only used by the KHR_debug commands
+  #[cfg_attr(not(feature = "GL_KHR_debug"), allow(dead_code))]
+  pub(super) fn get_for_identifier(
+    &self, identifier: GLenum, traced: GLuint,
+  ) -> GLuint {
+    let kind = match identifier {
+      0x82E0 => NameKind::Buffer,
+      0x82E1 | 0x82E2 => NameKind::Program,
+      0x82E3 => NameKind::Query,
+      0x82E4 => NameKind::ProgramPipeline,
+      0x82E6 => NameKind::Sampler,
+      0x8074 => NameKind::VertexArray,
+      GL_TEXTURE => NameKind::Texture,
+      GL_FRAMEBUFFER => NameKind::Framebuffer,
+      GL_RENDERBUFFER => NameKind::Renderbuffer,
+      GL_TRANSFORM_FEEDBACK => NameKind::TransformFeedback,
+      _ => return traced,
+    };
+    self.get(kind, traced)
+  }
+
+  pub(super) fn insert(
+    &mut self, kind: NameKind, traced: GLuint, replayed: GLuint,
+  ) {
+    self.0.insert((kind, traced), replayed);
+  }
+
+  /// Maps the names that a `glGen*` call gave in the trace to the names that
+  /// it gave in the replay.
+  pub(super) unsafe fn insert_all(
+    &mut self, kind: NameKind, traced: &Pointer, replayed: *const GLuint,
+    n: GLsizei,
+  ) {
+    if replayed.is_null() {
+      return;
+    }
+    let traced = traced.bytes().chunks_exact(4);
+    for (i, t) in traced.take(n.max(0) as usize).enumerate() {
+      let t = u32::from_le_bytes([t[0], t[1], t[2], t[3]]);
+      self.insert(kind, t, *replayed.add(i));
+    }
+  }
+
+  /// Maps an array of names in place.
+  pub(super) fn remap_payload(&self, kind: NameKind, p: &mut Pointer) {
+    for name in p.u32s_mut() {
+      *name = self.get(kind, *name);
+    }
+  }
+}
+
+/// Everything that a replay keeps track of between calls.
+#[derive(Debug, Default)]
+pub(super) struct ReplayState {
+  pub(super) names: NameMap,
+  pub(super) syncs: HashMap<u64, GLsync>,
+  pub(super) mapped: HashMap<GLenum, *mut c_void>,
+}
+impl ReplayState {
+  pub(super) fn sync(&self, traced: u64) -> GLsync {
+    self.syncs.get(&traced).cloned().unwrap_or(traced as usize as GLsync)
+  }
+
+  /// Copies traced data into the replay's mapping of a buffer.
+  pub(super) unsafe fn write_mapped(
+    &self, target: GLenum, offset: GLintptr, data: &Pointer,
+  ) {
+    match self.mapped.get(&target) {
+      Some(&base) if !base.is_null() && offset >= 0 => {
+        let bytes = data.bytes();
+        let dst = (base as *mut u8).offset(offset);
+        copy_nonoverlapping(bytes.as_ptr(), dst, bytes.len())
+      }
+      _ => (),
+    }
+  }
+}
+
+/// Replays a trace, one call at a time.
+///
+/// The trace header is read when the replayer is made.
+pub struct Replayer<R: Read> {
+  reader: R,
+  /// For each command in the trace's name list, its index in ours.
+  commands: Vec<Result<usize, String>>,
+  state: ReplayState,
+  body: Vec<u8>,
+}
+impl<R: Read> Replayer<R> {
+  /// Makes a replayer, reading the trace header from `reader`.
+  pub fn new(mut reader: R) -> Result<Self, TraceError> {
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic).map_err(|_| TraceError::NotATrace)?;
+    if &magic != MAGIC {
+      return Err(TraceError::NotATrace);
+    }
+    let mut word = [0; 4];
+    reader.read_exact(&mut word)?;
+    let version = u32::from_le_bytes(word);
+    if version != VERSION {
+      return Err(TraceError::UnsupportedVersion(version));
+    }
+    let mut count = [0; 2];
+    reader.read_exact(&mut count)?;
+    let mut commands = Vec::new();
+    for _ in 0..u16::from_le_bytes(count) {
+      let mut len = [0; 1];
+      reader.read_exact(&mut len)?;
+      let mut name = vec![0; len[0] as usize];
+      reader.read_exact(&mut name)?;
+      let name = String::from_utf8_lossy(&name).into_owned();
+      commands.push(
+        LOADABLE_COMMANDS.iter().position(|c| c.name == name).ok_or(name),
+      );
+    }
+    Ok(Self {
+      reader,
+      commands,
+      state: ReplayState::default(),
+      body: Vec::new(),
+    })
+  }
+
+  /// Replays the next call in the trace.
+  ///
+  /// Gives the name of the command that was called, or `None` at the end of
+  /// the trace.
+  ///
+  /// ## Safety
+  /// The calls are made exactly as they're described by the trace, so this
+  /// is as safe as the traced program was.
+  pub unsafe fn replay_next(
+    &mut self, gl: &GlFns,
+  ) -> Result<Option<&'static str>, TraceError> {
+    let mut header = [0_u8; 6];
+    let mut filled = 0;
+    while filled < header.len() {
+      match self.reader.read(&mut header[filled..]) {
+        Ok(0) if filled == 0 => return Ok(None),
+        Ok(0) => return Err(TraceError::Truncated),
+        Ok(n) => filled += n,
+        Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+        Err(e) => return Err(e.into()),
+      }
+    }
+    let id = u16::from_le_bytes([header[0], header[1]]) as usize;
+    let len =
+      u32::from_le_bytes([header[2], header[3], header[4], header[5]]) as usize;
+    self.body.resize(len, 0);
+    self.reader.read_exact(&mut self.body)?;
+    let index = match self.commands.get(id) {
+      Some(Ok(index)) => *index,
+      Some(Err(name)) => return Err(TraceError::UnknownCommand(name.clone())),
+      None => return Err(TraceError::UnknownCommand(format!("#{}", id))),
+    };
+    let mut d = Decoder { bytes: &self.body };
+    super::calls::replay_call(&mut self.state, gl, index, &mut d)?;
+    Ok(Some(LOADABLE_COMMANDS[index].name))
+  }
+
+  /// Replays the rest of the trace, giving the number of calls made.
+  ///
+  /// ## Safety
+  /// See [`replay_next`](Replayer::replay_next).
+  pub unsafe fn replay_all(&mut self, gl: &GlFns) -> Result<usize, TraceError> {
+    let mut count = 0;
+    while self.replay_next(gl)?.is_some() {
+      count += 1;
+    }
+    Ok(count)
+  }
+}
+impl<R: Read> fmt::Debug for Replayer<R> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Replayer").finish()
+  }
+}
+
+/// Replays a whole trace with `gl`, giving the number of calls made.
+///
+/// ## Safety
+/// See [`Replayer::replay_next`].
+pub unsafe fn replay<R: Read>(
+  gl: &GlFns, reader: R,
+) -> Result<usize, TraceError> {
+  Replayer::new(reader)?.replay_all(gl)
+}
@@ -0,0 +1,56 @@
+//! Tests for parsing version strings.
+
+use gles30::capabilities::Version;
+
+#[test]
+fn driver_version_strings() {
+  let table = [
+    // GL_VERSION
+    ("OpenGL ES 3.2 Mesa 23.2.1", true, 3, 2, "Mesa 23.2.1"),
+    (
+      "OpenGL ES 3.2 V@0502.0 (GIT@5eaa426211, I07ee46fc66, 1633700387) (Date:10/08/21)",
+      true,
+      3,
+      2,
+      "V@0502.0 (GIT@5eaa426211, I07ee46fc66, 1633700387) (Date:10/08/21)",
+    ),
+    (
+      "OpenGL ES 3.2 v1.r38p1-01eac0.0c6d9c4f8b7e2d9cfbe7cb1d0efd8d6b",
+      true,
+      3,
+      2,
+      "v1.r38p1-01eac0.0c6d9c4f8b7e2d9cfbe7cb1d0efd8d6b",
+    ),
+    (
+      "OpenGL ES 3.0.0 (ANGLE 2.1.0.4a9ac3bc3e08)",
+      true,
+      3,
+      0,
+      "(ANGLE 2.1.0.4a9ac3bc3e08)",
+    ),
+    ("OpenGL ES-CM 1.1", true, 1, 1, ""),
+    ("4.6.0 NVIDIA 535.54.03", false, 4, 6, "NVIDIA 535.54.03"),
+    // GL_SHADING_LANGUAGE_VERSION
+    ("OpenGL ES GLSL ES 3.20", true, 3, 20, ""),
+    (
+      "OpenGL ES GLSL ES 3.00 (ANGLE 2.1.0.4a9ac3bc3e08)",
+      true,
+      3,
+      0,
+      "(ANGLE 2.1.0.4a9ac3bc3e08)",
+    ),
+    ("4.60 NVIDIA", false, 4, 60, "NVIDIA"),
+  ];
+  for &(s, es, major, minor, vendor_info) in table.iter() {
+    let expected =
+      Version { es, major, minor, vendor_info: vendor_info.to_string() };
+    assert_eq!(Version::parse(s), Some(expected), "{}", s);
+  }
+}
+
+#[test]
+fn strings_without_a_version() {
+  for s in ["", "OpenGL ES", "WebGL 2.0 (OpenGL ES 3.0 Chromium)", "3"].iter() {
+    assert_eq!(Version::parse(s), None, "{:?}", s);
+  }
+}
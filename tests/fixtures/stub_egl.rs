@@ -0,0 +1,40 @@
+//! A stand-in for `libEGL`, built by `tests/native.rs`.
+//!
+//! Like an older EGL, `eglGetProcAddress` only knows about a few commands,
+//! and it gives its own version of `glGetError` so that the tests can tell
+//! which library a pointer came from.
+
+use std::{
+  ffi::CStr,
+  os::raw::{c_char, c_void},
+  ptr::null_mut,
+};
+
+#[no_mangle]
+pub unsafe extern "system" fn eglGetProcAddress(
+  name: *const c_char,
+) -> *mut c_void {
+  match CStr::from_ptr(name).to_bytes() {
+    b"glGetError" => stubGetError as *mut c_void,
+    b"glPushDebugGroupKHR" => stubPushDebugGroupKHR as *mut c_void,
+    _ => null_mut(),
+  }
+}
+
+#[no_mangle]
+pub extern "system" fn eglGetDisplay(
+  _native_display: *mut c_void,
+) -> *mut c_void {
+  null_mut()
+}
+
+#[no_mangle]
+pub extern "system" fn stubGetError() -> u32 {
+  0 // GL_NO_ERROR
+}
+
+#[no_mangle]
+pub extern "system" fn stubPushDebugGroupKHR(
+  _source: u32, _id: u32, _length: i32, _message: *const c_char,
+) {
+}
@@ -0,0 +1,38 @@
+//! A stand-in for `libGLESv2`, built by `tests/native.rs`.
+//!
+//! This only has the commands that the tests call (and that loading calls
+//! when extension features are enabled). There's no `eglGetProcAddress` here.
+
+use std::ptr::null;
+
+#[no_mangle]
+pub extern "system" fn glGetError() -> u32 {
+  0 // GL_NO_ERROR
+}
+
+#[no_mangle]
+pub extern "system" fn glGetString(name: u32) -> *const u8 {
+  match name {
+    0x1F00 => b"gles30 tests\0".as_ptr(), // GL_VENDOR
+    0x1F02 => b"OpenGL ES 3.0 stub\0".as_ptr(), // GL_VERSION
+    0x1F03 => b"\0".as_ptr(),             // GL_EXTENSIONS
+    _ => null(),
+  }
+}
+
+#[no_mangle]
+pub extern "system" fn glGetStringi(_name: u32, _index: u32) -> *const u8 {
+  null()
+}
+
+#[no_mangle]
+pub unsafe extern "system" fn glGetIntegerv(pname: u32, data: *mut i32) {
+  match pname {
+    0x821B => *data = 3,          // GL_MAJOR_VERSION
+    0x821C | 0x821D => *data = 0, // GL_MINOR_VERSION, GL_NUM_EXTENSIONS
+    _ => (),
+  }
+}
+
+#[no_mangle]
+pub extern "system" fn glClear(_mask: u32) {}
@@ -0,0 +1,96 @@
+//! Tests for the mock context.
+
+use gles30::{mock::ObjectKind, *};
+
+#[test]
+fn every_command_loads() {
+  unsafe {
+    mock::reset();
+    let (_, report) =
+      GlFns::load_with_report(|name| mock::get_proc_address(name));
+    let mut extensions: Vec<&str> =
+      report.iter().filter_map(|s| s.extension).collect();
+    extensions.sort_unstable();
+    extensions.dedup();
+    mock::set_extensions(&extensions);
+    let (_, report) =
+      GlFns::load_with_report(|name| mock::get_proc_address(name));
+    assert!(report.is_complete(), "{:?}", report);
+    assert!(GlFns::try_load_with(|name| mock::get_proc_address(name)).is_ok());
+  }
+}
+
+#[cfg(feature = "gles31")]
+#[test]
+fn program_pipelines() {
+  unsafe {
+    mock::reset();
+    let gl = GlFns::load_with(|name| mock::get_proc_address(name));
+    let mut pipelines = [0; 2];
+    gl.GenProgramPipelines(2, pipelines.as_mut_ptr());
+    assert_eq!(gl.IsProgramPipeline(pipelines[0]), GL_TRUE as GLboolean);
+    gl.BindProgramPipeline(pipelines[1]);
+    mock::with_state(|s| {
+      assert_eq!(s.live_objects(ObjectKind::ProgramPipeline), 2);
+      assert_eq!(s.program_pipeline(), pipelines[1]);
+    });
+    gl.DeleteProgramPipelines(2, pipelines.as_ptr());
+    assert_eq!(gl.IsProgramPipeline(pipelines[0]), GL_FALSE as GLboolean);
+    mock::with_state(|s| {
+      assert_eq!(s.live_objects(ObjectKind::ProgramPipeline), 0);
+      assert_eq!(s.program_pipeline(), 0);
+    });
+  }
+}
+
+#[cfg(feature = "GL_KHR_debug")]
+#[test]
+fn over_long_labels_are_rejected() {
+  use gles30::debug::{ObjectIdentifier, TextTooLong};
+  unsafe {
+    mock::reset();
+    let gl = GlFns::load_with(|name| mock::get_proc_address(name));
+    let mut buffer = 0;
+    gl.GenBuffers(1, &mut buffer);
+    let long = "x".repeat(256);
+    assert_eq!(
+      gl.label_object(ObjectIdentifier::Buffer, buffer, &long),
+      Err(TextTooLong { len: 256, max: 256 })
+    );
+    assert_eq!(
+      gl.label_object(ObjectIdentifier::Buffer, buffer, "data"),
+      Ok(())
+    );
+    assert_eq!(gl.object_label(ObjectIdentifier::Buffer, buffer), "data");
+    assert!(gl.push_debug_group(&"x".repeat(1024)).is_err());
+    assert_eq!(gl.GetError(), GL_NO_ERROR);
+  }
+}
+
+#[cfg(feature = "gles32")]
+#[test]
+fn missing_commands_are_grouped_by_version() {
+  use std::ffi::CStr;
+  let hidden = ["glDrawArrays", "glDispatchCompute", "glBlendBarrier"];
+  let err = unsafe {
+    mock::reset();
+    GlFns::try_load_with(|name| {
+      let s = CStr::from_ptr(name).to_str().unwrap();
+      if hidden.contains(&s) {
+        std::ptr::null_mut()
+      } else {
+        mock::get_proc_address(name)
+      }
+    })
+  }
+  .unwrap_err();
+  assert_eq!(err.versions().collect::<Vec<_>>(), [(3, 0), (3, 1), (3, 2)]);
+  assert_eq!(err.missing_in((3, 1)).collect::<Vec<_>>(), ["glDispatchCompute"]);
+  let status = err.report().iter().find(|s| s.name == "glBlendBarrier");
+  assert_eq!(status.map(|s| (s.version, s.loaded)), Some(((3, 2), false)));
+  assert_eq!(
+    err.to_string(),
+    "failed to load 3 core GL commands; GLES 3.0: glDrawArrays; \
+     GLES 3.1: glDispatchCompute; GLES 3.2: glBlendBarrier"
+  );
+}
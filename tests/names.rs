@@ -0,0 +1,50 @@
+//! Tests for the batch name helpers, against the mock context.
+
+use gles30::{mock, names::NamePool, objects::*, *};
+
+fn live(kind: mock::ObjectKind) -> usize {
+  mock::with_state(|s| s.live_objects(kind))
+}
+
+#[test]
+fn gen_and_delete_arrays() {
+  mock::reset();
+  unsafe {
+    let gl = GlFns::load_with(|name| mock::get_proc_address(name));
+    let [a, b, c] = gl.gen_buffers::<3>().unwrap();
+    assert!(a.name() != b.name() && b.name() != c.name());
+    assert_eq!(live(mock::ObjectKind::Buffer), 3);
+    gl.delete_buffers(vec![a, b, c]);
+    assert_eq!(live(mock::ObjectKind::Buffer), 0);
+
+    let mut slots: [Option<Texture>; 4] = Default::default();
+    gl.gen_textures_into(&mut slots);
+    assert!(slots.iter().all(Option::is_some));
+    assert_eq!(live(mock::ObjectKind::Texture), 4);
+    gl.delete_textures(slots.iter_mut().filter_map(Option::take));
+    assert_eq!(live(mock::ObjectKind::Texture), 0);
+  }
+}
+
+#[test]
+fn pool_generates_a_chunk_at_a_time() {
+  mock::reset();
+  unsafe {
+    let gl = GlFns::load_with(|name| mock::get_proc_address(name));
+    let mut pool = NamePool::<VertexArray>::new(8);
+    assert_eq!(pool.unused(), 0);
+    let first = pool.alloc(&gl).unwrap();
+    let second = pool.alloc(&gl).unwrap();
+    assert!(first.name() < second.name());
+    assert_eq!(pool.unused(), 6);
+    assert_eq!(live(mock::ObjectKind::VertexArray), 8);
+
+    pool.reserve(&gl, 10);
+    assert_eq!(pool.unused(), 14);
+    pool.delete_unused(&gl);
+    assert_eq!(pool.unused(), 0);
+    assert_eq!(live(mock::ObjectKind::VertexArray), 2);
+    gl.delete_vertex_arrays(vec![first, second]);
+    assert_eq!(live(mock::ObjectKind::VertexArray), 0);
+  }
+}
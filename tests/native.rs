@@ -0,0 +1,117 @@
+//! Tests for the `native` loader, using stub EGL and GLES libraries that are
+//! built from `tests/fixtures` when the tests start.
+
+#![cfg(unix)]
+
+use gles30::{native::*, *};
+use std::{
+  env::consts::{DLL_PREFIX, DLL_SUFFIX},
+  ffi::{CStr, CString},
+  path::PathBuf,
+  process::Command,
+  ptr::null,
+  sync::Once,
+};
+
+/// Builds a fixture into a shared library (once per test run), and gives the
+/// path of the library.
+fn stub(name: &str) -> PathBuf {
+  static BUILD: Once = Once::new();
+  let dir = std::env::temp_dir().join("gles30-native-stubs");
+  let lib =
+    |name: &str| dir.join(format!("{}{}{}", DLL_PREFIX, name, DLL_SUFFIX));
+  BUILD.call_once(|| {
+    std::fs::create_dir_all(&dir).expect("couldn't make the stub directory");
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    for name in &["stub_egl", "stub_gles"] {
+      let source =
+        format!("{}/tests/fixtures/{}.rs", env!("CARGO_MANIFEST_DIR"), name);
+      let status = Command::new(&rustc)
+        .args(&["--crate-type", "cdylib", "--edition", "2018", "-o"])
+        .arg(lib(name))
+        .arg(source)
+        .status()
+        .expect("couldn't run rustc");
+      assert!(status.success(), "couldn't build {}", name);
+    }
+  });
+  lib(name)
+}
+
+fn open_stubs() -> NativeGl {
+  NativeGl::open_paths(stub("stub_egl"), stub("stub_gles")).unwrap()
+}
+
+fn lookup(native: &NativeGl, name: &str) -> *mut std::os::raw::c_void {
+  let name = CString::new(name).unwrap();
+  unsafe { native.get_proc_address(name.as_ptr()) }
+}
+
+fn symbol(lib: &Library, name: &str) -> *mut std::os::raw::c_void {
+  lib.symbol(&CString::new(name).unwrap())
+}
+
+#[test]
+fn egl_get_proc_address_is_asked_first() {
+  let native = open_stubs();
+  let egl_get_error = symbol(native.egl(), "stubGetError");
+  assert!(!egl_get_error.is_null());
+  assert_eq!(lookup(&native, "glGetError"), egl_get_error);
+  assert_ne!(
+    lookup(&native, "glGetError"),
+    symbol(native.gles(), "glGetError")
+  );
+  assert_eq!(
+    lookup(&native, "glPushDebugGroupKHR"),
+    symbol(native.egl(), "stubPushDebugGroupKHR")
+  );
+}
+
+#[test]
+fn falls_back_to_the_gles_library_then_the_egl_library() {
+  let native = open_stubs();
+  let get_string = symbol(native.gles(), "glGetString");
+  assert!(!get_string.is_null());
+  assert_eq!(lookup(&native, "glGetString"), get_string);
+  let get_display = symbol(native.egl(), "eglGetDisplay");
+  assert!(!get_display.is_null());
+  assert_eq!(lookup(&native, "eglGetDisplay"), get_display);
+}
+
+#[test]
+fn unknown_and_null_names_give_null() {
+  let native = open_stubs();
+  assert!(lookup(&native, "glNotARealCommand").is_null());
+  assert!(unsafe { native.get_proc_address(null()) }.is_null());
+}
+
+#[test]
+fn loads_a_gl_fns() {
+  let native = open_stubs();
+  let (gl, report) =
+    unsafe { GlFns::load_with_report(|name| native.get_proc_address(name)) };
+  let loaded: Vec<_> = report.loaded().collect();
+  for name in &["glGetError", "glGetString", "glGetIntegerv", "glClear"] {
+    assert!(loaded.contains(name), "{} wasn't loaded", name);
+  }
+  assert!(!loaded.contains(&"glDrawArrays"));
+  let version = unsafe { CStr::from_ptr(gl.GetString(GL_VERSION) as *const _) };
+  assert_eq!(version.to_str(), Ok("OpenGL ES 3.0 stub"));
+  assert_eq!(unsafe { gl.GetError() }, GL_NO_ERROR);
+}
+
+#[test]
+fn missing_libraries_and_symbols_are_errors() {
+  let missing = stub("stub_egl").with_file_name("no_such_library.so");
+  let err = NativeGl::open_paths(&missing, stub("stub_gles")).unwrap_err();
+  assert_eq!(err.name, missing.to_string_lossy());
+  assert!(!err.message.is_empty());
+
+  // the GLES stub has no `eglGetProcAddress`
+  let gles = || Library::open(stub("stub_gles")).unwrap();
+  let err = NativeGl::from_libraries(gles(), gles()).unwrap_err();
+  assert_eq!(err.name, "eglGetProcAddress");
+
+  let err = Library::open_first(&[]).unwrap_err();
+  assert_eq!(err.message, "no library names given");
+}
@@ -0,0 +1,110 @@
+//! Tests for recording calls, made against the mock context.
+
+use gles30::{record::*, *};
+use std::sync::{Arc, Mutex};
+
+unsafe fn mock_gl() -> GlFns {
+  mock::reset();
+  GlFns::load_with(|name| mock::get_proc_address(name))
+}
+
+#[test]
+fn records_arguments_and_return_values() {
+  unsafe {
+    let mut gl = mock_gl();
+    let log = CallLog::new();
+    gl.set_call_sink(log.clone());
+    let mut buffer = 0;
+    gl.GenBuffers(1, &mut buffer);
+    gl.BindBuffer(GL_ARRAY_BUFFER, buffer);
+    let data = [1_u8, 2, 3, 4];
+    gl.BufferData(
+      GL_ARRAY_BUFFER,
+      4,
+      data.as_ptr() as *const _,
+      GL_STATIC_DRAW,
+    );
+    let shader = gl.CreateShader(GL_VERTEX_SHADER);
+    gl.ClearColor(0.25, 0.5, 0.75, 1.0);
+    gl.DrawArrays(GL_TRIANGLES, 0, 3);
+    assert_eq!(gl.GetError(), GL_NO_ERROR);
+    assert_ne!(buffer, 0);
+    assert_ne!(shader, 0);
+    assert_eq!(
+      log.take(),
+      &[
+        GlCall::GenBuffers { n: 1, buffers: &mut buffer },
+        GlCall::BindBuffer { target: GL_ARRAY_BUFFER, buffer },
+        GlCall::BufferData {
+          target: GL_ARRAY_BUFFER,
+          size: 4,
+          data: data.as_ptr() as *const _,
+          usage: GL_STATIC_DRAW,
+        },
+        GlCall::CreateShader { type_: GL_VERTEX_SHADER, ret: shader },
+        GlCall::ClearColor { red: 0.25, green: 0.5, blue: 0.75, alpha: 1.0 },
+        GlCall::DrawArrays { mode: GL_TRIANGLES, first: 0, count: 3 },
+        GlCall::GetError { ret: GL_NO_ERROR },
+      ]
+    );
+    assert!(log.is_empty());
+  }
+}
+
+#[test]
+fn sinks_can_be_replaced_and_cleared() {
+  unsafe {
+    let mut gl = mock_gl();
+    let first = CallLog::new();
+    let second = Arc::new(Mutex::new(Vec::new()));
+    assert!(gl.set_call_sink(first.clone()).is_none());
+    gl.Flush();
+    let names = second.clone();
+    let old = gl.set_call_sink(move |call: GlCall| {
+      names.lock().unwrap().push(call.name());
+    });
+    assert!(old.is_some());
+    gl.Finish();
+    assert!(gl.clear_call_sink().is_some());
+    gl.Flush();
+    assert!(gl.clear_call_sink().is_none());
+    assert_eq!(first.take(), &[GlCall::Flush]);
+    assert_eq!(*second.lock().unwrap(), &["glFinish"]);
+  }
+}
+
+#[cfg(feature = "checked_commands")]
+#[test]
+fn checked_commands_dont_record_their_error_check() {
+  unsafe {
+    let mut gl = mock_gl();
+    let log = CallLog::new();
+    gl.set_call_sink(log.clone());
+    let err = gl.checked().BindBuffer(0x1234, 0).unwrap_err();
+    assert_eq!(err.code(), GL_INVALID_ENUM);
+    assert_eq!(log.take(), &[GlCall::BindBuffer { target: 0x1234, buffer: 0 }]);
+  }
+}
+
+#[cfg(feature = "global_loader")]
+#[test]
+fn records_global_calls() {
+  unsafe {
+    mock::reset();
+    load_global_gl_with(|name| mock::get_proc_address(name));
+    let log = CallLog::new();
+    set_global_call_sink(log.clone());
+    glViewport(0, 0, 640, 480);
+    let mut texture = 0;
+    glGenTextures(1, &mut texture);
+    clear_global_call_sink();
+    glFlush();
+    assert_eq!(
+      log.take(),
+      &[
+        GlCall::Viewport { x: 0, y: 0, width: 640, height: 480 },
+        GlCall::GenTextures { n: 1, textures: &mut texture },
+      ]
+    );
+  }
+}
@@ -0,0 +1,114 @@
+//! Tests for parsing shader info logs.
+
+use gles30::shaders::{parse_info_log, Diagnostic, Severity};
+
+fn diag(
+  file: Option<u32>, line: Option<u32>, column: Option<u32>,
+  severity: Severity, message: &str,
+) -> Diagnostic {
+  Diagnostic { file, line, column, severity, message: message.to_string() }
+}
+
+#[test]
+fn driver_log_lines() {
+  use Severity::*;
+  let table = [
+    // Mesa
+    (
+      "0:5(12): error: `foo' undeclared",
+      diag(Some(0), Some(5), Some(12), Error, "`foo' undeclared"),
+    ),
+    (
+      "0:3(1): error: syntax error, unexpected NEW_IDENTIFIER, expecting $end",
+      diag(
+        Some(0),
+        Some(3),
+        Some(1),
+        Error,
+        "syntax error, unexpected NEW_IDENTIFIER, expecting $end",
+      ),
+    ),
+    (
+      "0:10(15): warning: `color' used uninitialized",
+      diag(Some(0), Some(10), Some(15), Warning, "`color' used uninitialized"),
+    ),
+    (
+      "error: linking with uncompiled/unspecialized shader",
+      diag(None, None, None, Error, "linking with uncompiled/unspecialized shader"),
+    ),
+    // Adreno
+    (
+      "ERROR: 0:5: 'foo' : undeclared identifier",
+      diag(Some(0), Some(5), None, Error, "'foo' : undeclared identifier"),
+    ),
+    (
+      "WARNING: 0:7: 'precision' : precision qualifier ignored",
+      diag(
+        Some(0),
+        Some(7),
+        None,
+        Warning,
+        "'precision' : precision qualifier ignored",
+      ),
+    ),
+    // Mali
+    (
+      "0:4: L0002: Undeclared variable 'foo'",
+      diag(Some(0), Some(4), None, Error, "L0002: Undeclared variable 'foo'"),
+    ),
+    (
+      "ERROR: 0:12: S0001: Type mismatch in binary operation '+'",
+      diag(
+        Some(0),
+        Some(12),
+        None,
+        Error,
+        "S0001: Type mismatch in binary operation '+'",
+      ),
+    ),
+    // ANGLE
+    (
+      "ERROR: 1:12: 'texture2D' : no matching overloaded function found",
+      diag(
+        Some(1),
+        Some(12),
+        None,
+        Error,
+        "'texture2D' : no matching overloaded function found",
+      ),
+    ),
+    (
+      "WARNING: 0:3: 'GL_OES_standard_derivatives' : extension is not supported",
+      diag(
+        Some(0),
+        Some(3),
+        None,
+        Warning,
+        "'GL_OES_standard_derivatives' : extension is not supported",
+      ),
+    ),
+  ];
+  for (line, expected) in table.iter() {
+    assert_eq!(
+      parse_info_log(line),
+      core::slice::from_ref(expected),
+      "{}",
+      line
+    );
+  }
+}
+
+#[test]
+fn summary_and_blank_lines_are_skipped() {
+  let log = "ERROR: 0:5: 'foo' : undeclared identifier\n\
+             ERROR: 0:6: 'bar' : undeclared identifier\n\
+             \n\
+             ERROR: 2 compilation errors.  No code generated.\n";
+  let diagnostics = parse_info_log(log);
+  assert_eq!(diagnostics.len(), 2);
+  assert_eq!(diagnostics[1].line, Some(6));
+  assert_eq!(
+    diagnostics[1].to_string(),
+    "0:6: error: 'bar' : undeclared identifier"
+  );
+}
@@ -0,0 +1,108 @@
+//! Tests for writing and replaying traces, made against the mock context.
+
+use gles30::{trace_file::*, *};
+use std::sync::{Arc, Mutex};
+
+unsafe fn mock_gl() -> GlFns {
+  mock::reset();
+  GlFns::load_with(|name| mock::get_proc_address(name))
+}
+
+/// Records a small frame into a trace.
+unsafe fn record_frame() -> Vec<u8> {
+  let mut gl = mock_gl();
+  let writer = Arc::new(Mutex::new(TraceWriter::new(Vec::new()).unwrap()));
+  gl.set_call_sink(writer.clone());
+  let mut buffer = 0;
+  gl.GenBuffers(1, &mut buffer);
+  gl.BindBuffer(GL_ARRAY_BUFFER, buffer);
+  let data = [1_u8, 2, 3, 4, 5];
+  gl.BufferData(GL_ARRAY_BUFFER, 5, data.as_ptr() as *const _, GL_STATIC_DRAW);
+  let shader = gl.CreateShader(GL_VERTEX_SHADER);
+  let source = b"void main() {}\0";
+  let strings = [source.as_ptr() as *const GLchar];
+  gl.ShaderSource(shader, 1, strings.as_ptr(), std::ptr::null());
+  gl.ClearColor(0.1, 0.2, 0.3, 1.0);
+  let mut texture = 0;
+  gl.GenTextures(1, &mut texture);
+  gl.BindTexture(GL_TEXTURE_2D, texture);
+  let pixels = [7_u8; 3 * 2 * 2];
+  gl.TexImage2D(
+    GL_TEXTURE_2D,
+    0,
+    GL_RGB as GLint,
+    2,
+    2,
+    0,
+    GL_RGB,
+    GL_UNSIGNED_BYTE,
+    pixels.as_ptr() as *const _,
+  );
+  gl.DrawArrays(GL_TRIANGLES, 0, 3);
+  gl.clear_call_sink();
+  Arc::try_unwrap(writer).unwrap().into_inner().unwrap().finish().unwrap()
+}
+
+#[test]
+fn replay_makes_the_same_calls_with_new_names() {
+  unsafe {
+    let trace = record_frame();
+    let gl = mock_gl();
+    // take some names first, so that the replay gets different ones
+    let mut taken = [0; 3];
+    gl.GenBuffers(3, taken.as_mut_ptr());
+    gl.GenTextures(3, taken.as_mut_ptr());
+    assert_eq!(replay(&gl, &trace[..]).unwrap(), 10);
+    mock::with_state(|s| {
+      let buffer = s.bound_buffer(GL_ARRAY_BUFFER);
+      assert!(buffer > 3);
+      assert_eq!(s.buffer_data(buffer), Some(&[1_u8, 2, 3, 4, 5][..]));
+      assert_eq!(s.buffer_usage(buffer), Some(GL_STATIC_DRAW));
+      let texture = s.bound_texture(GL_TEXTURE_2D);
+      assert!(texture > 3);
+      assert_eq!(
+        s.texture_level_size(texture, GL_TEXTURE_2D, 0),
+        Some((2, 2, 1))
+      );
+      let shader = (1..10).find(|&i| s.shader_source(i).is_some()).unwrap();
+      assert_eq!(s.shader_source(shader), Some("void main() {}"));
+      assert_eq!(s.clear_color(), [0.1, 0.2, 0.3, 1.0]);
+      assert_eq!(s.draw_calls(), 1);
+    });
+  }
+}
+
+#[test]
+fn replay_next_goes_one_call_at_a_time() {
+  unsafe {
+    let trace = record_frame();
+    let gl = mock_gl();
+    let mut replayer = Replayer::new(&trace[..]).unwrap();
+    assert_eq!(replayer.replay_next(&gl).unwrap(), Some("glGenBuffers"));
+    assert_eq!(replayer.replay_next(&gl).unwrap(), Some("glBindBuffer"));
+    assert_eq!(replayer.replay_all(&gl).unwrap(), 8);
+    assert_eq!(replayer.replay_next(&gl).unwrap(), None);
+  }
+}
+
+#[test]
+fn bad_traces_are_errors() {
+  unsafe {
+    let gl = mock_gl();
+    let trace = record_frame();
+    match replay(&gl, &b"not a trace at all"[..]) {
+      Err(TraceError::NotATrace) => (),
+      other => panic!("unexpected {:?}", other),
+    }
+    let mut newer = trace.clone();
+    newer[8..12].copy_from_slice(&2_u32.to_le_bytes());
+    match replay(&gl, &newer[..]) {
+      Err(TraceError::UnsupportedVersion(2)) => (),
+      other => panic!("unexpected {:?}", other),
+    }
+    match replay(&gl, &trace[..trace.len() - 1]) {
+      Err(TraceError::Truncated) => (),
+      other => panic!("unexpected {:?}", other),
+    }
+  }
+}